@@ -0,0 +1,130 @@
+//! A group of [`CollapsingHeader`]s where at most one is open, see [`Accordion`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub(crate) struct State {
+    /// The open section, if any.
+    open: Option<Id>,
+}
+
+/// A group of [`CollapsingHeader`]s where opening one section closes the others.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// egui::Accordion::new("settings")
+///     .default_open("General")
+///     .show(ui, |accordion| {
+///         accordion.section("General", |ui| {
+///             ui.label("General settings");
+///         });
+///         accordion.section("Advanced", |ui| {
+///             ui.label("Advanced settings");
+///         });
+///     });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Accordion {
+    id_source: Id,
+    default_open: Option<Id>,
+}
+
+impl Accordion {
+    /// `id_source`: Something unique, e.g. `"settings_accordion"`.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            default_open: None,
+        }
+    }
+
+    /// The section that is open the first time the accordion is shown,
+    /// identified by its title (or the `id_source` given to [`AccordionUi::section_with_id`]).
+    /// By default, all sections start out collapsed.
+    pub fn default_open(mut self, section: impl Hash) -> Self {
+        self.default_open = Some(Id::new(section));
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut AccordionUi<'_>) -> R,
+    ) -> InnerResponse<R> {
+        let Self {
+            id_source,
+            default_open,
+        } = self;
+        let id = ui.make_persistent_id(id_source);
+        let mut state = *ui
+            .memory()
+            .id_data
+            .get_or_insert_with(id, || State { open: default_open });
+
+        let inner_response = ui.vertical(|ui| {
+            let mut accordion = AccordionUi {
+                ui,
+                id,
+                state: &mut state,
+            };
+            add_contents(&mut accordion)
+        });
+
+        ui.memory().id_data.insert(id, state);
+        inner_response
+    }
+}
+
+/// The [`Ui`] of an [`Accordion`], used to add its sections.
+pub struct AccordionUi<'a> {
+    ui: &'a mut Ui,
+    id: Id,
+    state: &'a mut State,
+}
+
+impl<'a> AccordionUi<'a> {
+    /// A section with the given title, which is also used as its id source.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn section<R>(
+        &mut self,
+        title: impl ToString,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let title = title.to_string();
+        let section = Id::new(&title);
+        self.show_section(section, CollapsingHeader::new(title), add_contents)
+    }
+
+    /// A section with an explicit id source, for when the title is not unique or changes.
+    pub fn section_with_id<R>(
+        &mut self,
+        id_source: impl Hash,
+        title: impl ToString,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let section = Id::new(id_source);
+        self.show_section(section, CollapsingHeader::new(title), add_contents)
+    }
+
+    fn show_section<R>(
+        &mut self,
+        section: Id,
+        header: CollapsingHeader,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        let is_open = self.state.open == Some(section);
+        let response = header
+            .id_source(self.id.with(section))
+            .open(Some(is_open))
+            .show(self.ui, add_contents);
+        if response.header_response.clicked() {
+            self.state.open = if is_open { None } else { Some(section) };
+            self.ui.ctx().request_repaint();
+        }
+        response
+    }
+}
@@ -52,6 +52,8 @@ pub struct Area {
     anchor: Option<(Align2, Vec2)>,
     new_pos: Option<Pos2>,
     drag_bounds: Option<Rect>,
+    constrain: bool,
+    always_on_top: bool,
 }
 
 impl Area {
@@ -66,6 +68,8 @@ impl Area {
             new_pos: None,
             anchor: None,
             drag_bounds: None,
+            constrain: true,
+            always_on_top: false,
         }
     }
 
@@ -116,6 +120,13 @@ impl Area {
         self
     }
 
+    /// Keep the area on top of the other areas of the same [`Order`],
+    /// even when the user clicks on them, e.g. for a tool palette. Default: `false`.
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.always_on_top = always_on_top;
+        self
+    }
+
     pub fn default_pos(mut self, default_pos: impl Into<Pos2>) -> Self {
         self.default_pos = Some(default_pos.into());
         self
@@ -142,9 +153,22 @@ impl Area {
     /// The offset is added to the position, so e.g. an offset of `[-5.0, 5.0]`
     /// would move the window left and down from the given anchor.
     ///
+    /// The position is recomputed every frame, so the area stays glued to
+    /// the anchor when the screen is resized or the contents change size.
     /// Anchoring also makes the window immovable.
     ///
     /// It is an error to set both an anchor and a position.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// egui::Area::new("toast")
+    ///     .anchor(egui::Align2::RIGHT_BOTTOM, [-8.0, -8.0])
+    ///     .show(ctx, |ui| {
+    ///         ui.label("File saved");
+    ///     });
+    /// ```
     pub fn anchor(mut self, align: Align2, offset: impl Into<Vec2>) -> Self {
         self.anchor = Some((align, offset.into()));
         self.movable(false)
@@ -156,6 +180,13 @@ impl Area {
         self
     }
 
+    /// Keep the area within the screen (or the [`Self::drag_bounds`]),
+    /// even if it is positioned or dragged outside of it. Default: `true`.
+    pub fn constrain(mut self, constrain: bool) -> Self {
+        self.constrain = constrain;
+        self
+    }
+
     pub(crate) fn get_pivot(&self) -> Align2 {
         if let Some((pivot, _)) = self.anchor {
             pivot
@@ -168,9 +199,12 @@ impl Area {
 pub(crate) struct Prepared {
     layer_id: LayerId,
     state: State,
+    anchored: bool,
     movable: bool,
     enabled: bool,
     drag_bounds: Option<Rect>,
+    constrain: bool,
+    always_on_top: bool,
 }
 
 impl Area {
@@ -185,6 +219,8 @@ impl Area {
             new_pos,
             anchor,
             drag_bounds,
+            constrain,
+            always_on_top,
         } = self;
 
         let layer_id = LayerId::new(order, id);
@@ -213,9 +249,12 @@ impl Area {
         Prepared {
             layer_id,
             state,
+            anchored: anchor.is_some(),
             movable,
             enabled,
             drag_bounds,
+            constrain,
+            always_on_top,
         }
     }
 
@@ -226,17 +265,18 @@ impl Area {
         prepared.end(ctx, content_ui)
     }
 
-    pub fn show_open_close_animation(&self, ctx: &CtxRef, frame: &Frame, is_open: bool) {
+    /// Paint a fading and shrinking frame where a closed area used to be.
+    ///
+    /// Returns how visible the area is, from `0.0` (hidden) to `1.0` (fully open).
+    /// When opening, the caller is responsible for fading in the contents,
+    /// e.g. with [`crate::layers::PaintList::multiply_with_opacity`].
+    pub fn show_open_close_animation(&self, ctx: &CtxRef, frame: &Frame, is_open: bool) -> f32 {
         // must be called first so animation managers know the latest state
         let visibility_factor = ctx.animate_bool(self.id.with("close_animation"), is_open);
 
-        if is_open {
-            // we actually only show close animations.
-            // when opening a window we show it right away.
-            return;
-        }
-        if visibility_factor <= 0.0 {
-            return;
+        if is_open || visibility_factor <= 0.0 {
+            // The contents are only painted by the caller when open.
+            return visibility_factor;
         }
 
         let layer_id = LayerId::new(self.order, self.id);
@@ -245,13 +285,14 @@ impl Area {
             let clip_rect = ctx.available_rect();
             let painter = Painter::new(ctx.clone(), layer_id, clip_rect);
 
-            // shrinkage: looks kinda a bad on its own
-            // let area_rect =
-            //     Rect::from_center_size(area_rect.center(), visibility_factor * area_rect.size());
+            // Only shrink a little. Shrinking all the way looks kinda bad.
+            let scale = lerp(0.9..=1.0, visibility_factor);
+            let area_rect = Rect::from_center_size(area_rect.center(), scale * area_rect.size());
 
             let frame = frame.multiply_with_opacity(visibility_factor);
             painter.add(frame.paint(area_rect));
         }
+        visibility_factor
     }
 }
 
@@ -264,8 +305,13 @@ impl Prepared {
         &mut self.state
     }
 
-    pub(crate) fn drag_bounds(&self) -> Option<Rect> {
-        self.drag_bounds
+    /// Where to keep the area, if anywhere, see [`Area::constrain`].
+    pub(crate) fn constrain_rect(&self, ctx: &Context) -> Option<Rect> {
+        if self.constrain {
+            Some(self.drag_bounds.unwrap_or_else(|| ctx.available_rect()))
+        } else {
+            None
+        }
     }
 
     pub(crate) fn content_ui(&self, ctx: &CtxRef) -> Ui {
@@ -304,12 +350,20 @@ impl Prepared {
         let Prepared {
             layer_id,
             mut state,
+            anchored,
             movable,
             enabled,
             drag_bounds,
+            constrain,
+            always_on_top,
         } = self;
 
-        state.size = content_ui.min_rect().size();
+        let size = content_ui.min_rect().size();
+        if anchored && size != state.size {
+            // The anchored position depends on the size, so lay out again at the correct position:
+            ctx.request_repaint();
+        }
+        state.size = size;
 
         let interact_id = layer_id.id.with("move");
         let sense = if movable {
@@ -332,10 +386,12 @@ impl Prepared {
             state.pos += ctx.input().pointer.delta();
         }
 
-        if let Some(bounds) = drag_bounds {
-            state.pos = ctx.constrain_window_rect_to_area(state.rect(), bounds).min;
-        } else {
-            state.pos = ctx.constrain_window_rect(state.rect()).min;
+        if constrain {
+            if let Some(bounds) = drag_bounds {
+                state.pos = ctx.constrain_window_rect_to_area(state.rect(), bounds).min;
+            } else {
+                state.pos = ctx.constrain_window_rect(state.rect()).min;
+            }
         }
 
         if (move_response.dragged() || move_response.clicked())
@@ -344,6 +400,8 @@ impl Prepared {
         {
             ctx.memory().areas.move_to_top(layer_id);
             ctx.request_repaint();
+        } else if always_on_top {
+            ctx.memory().areas.move_to_top(layer_id);
         }
         ctx.memory().areas.set_state(layer_id, state);
 
@@ -134,12 +134,40 @@ pub(crate) fn paint_icon(ui: &mut Ui, openness: f32, response: &Response) {
 /// // Short version:
 /// ui.collapsing("Heading", |ui| { ui.label("Contents"); });
 /// ```
+///
+/// With a count badge at the end of the header row, and the open state controlled from outside:
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut items = vec!["a", "b"];
+/// let mut expand_all = false;
+/// if ui.button("Expand all").clicked() {
+///     expand_all = true;
+/// }
+/// egui::CollapsingHeader::new("Items")
+///     .open(if expand_all { Some(true) } else { None })
+///     .show_with_trailing(
+///         ui,
+///         |ui| {
+///             ui.label(items.len().to_string());
+///         },
+///         |ui| {
+///             for item in &items {
+///                 ui.label(*item);
+///             }
+///         },
+///     );
+/// ```
 #[must_use = "You should call .show()"]
 pub struct CollapsingHeader {
     label: Label,
     default_open: bool,
     id_source: Id,
     enabled: bool,
+    open: Option<bool>,
+    icon: fn(&mut Ui, f32, &Response),
+    show_background: bool,
+    fill: Option<Color32>,
 }
 
 impl CollapsingHeader {
@@ -157,6 +185,10 @@ impl CollapsingHeader {
             default_open: false,
             id_source,
             enabled: true,
+            open: None,
+            icon: paint_icon,
+            show_background: true,
+            fill: None,
         }
     }
 
@@ -167,6 +199,14 @@ impl CollapsingHeader {
         self
     }
 
+    /// Set the open state this frame, e.g. for "Expand all" and "Collapse all" buttons.
+    ///
+    /// `None` (the default) leaves it to the user.
+    pub fn open(mut self, open: Option<bool>) -> Self {
+        self.open = open;
+        self
+    }
+
     /// Explicitly set the source of the `Id` of this widget, instead of using title label.
     /// This is useful if the title label is dynamic or not unique.
     pub fn id_source(mut self, id_source: impl Hash) -> Self {
@@ -181,6 +221,27 @@ impl CollapsingHeader {
         self
     }
 
+    /// Replace the triangle icon with your own painter.
+    ///
+    /// It is called with the openness (0 for closed, 1 for open, with tweening)
+    /// and a [`Response`] whose `rect` is where to paint the icon.
+    pub fn icon(mut self, icon: fn(&mut Ui, f32, &Response)) -> Self {
+        self.icon = icon;
+        self
+    }
+
+    /// If `false`, only paint the header background when hovered. Default: `true`.
+    pub fn show_background(mut self, show_background: bool) -> Self {
+        self.show_background = show_background;
+        self
+    }
+
+    /// Fill the header background with this color instead of the one from the [`Style`].
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
     /// If you set this to `false`, the `CollapsingHeader` will be grayed out and un-clickable.
     ///
     /// This is a convenience for [`Ui::set_enabled`].
@@ -197,7 +258,7 @@ struct Prepared {
 }
 
 impl CollapsingHeader {
-    fn begin(self, ui: &mut Ui) -> Prepared {
+    fn begin(self, ui: &mut Ui, add_trailing: impl FnOnce(&mut Ui)) -> Prepared {
         assert!(
             ui.layout().main_dir().is_vertical(),
             "Horizontal collapsing is unimplemented"
@@ -207,6 +268,10 @@ impl CollapsingHeader {
             default_open,
             id_source,
             enabled: _,
+            open,
+            icon,
+            show_background,
+            fill,
         } = self;
 
         label.text_style = label
@@ -229,14 +294,33 @@ impl CollapsingHeader {
         let mut desired_size = vec2(desired_width, galley.size.y + 2.0 * button_padding.y);
         desired_size = desired_size.at_least(ui.spacing().interact_size);
         let (_, rect) = ui.allocate_space(desired_size);
+        let background = ui.painter().add(Shape::Noop);
+
+        // Add the trailing widgets first, so they get the clicks instead of the header:
+        let mut trailing_ui = ui.child_ui(
+            rect.shrink2(vec2(button_padding.x, 0.0)),
+            Layout::right_to_left(),
+        );
+        add_trailing(&mut trailing_ui);
+        let trailing_rect = trailing_ui.min_rect();
+        let mut header_rect = rect;
+        if trailing_rect.width() > 0.0 {
+            header_rect.max.x = trailing_rect.left() - ui.spacing().item_spacing.x;
+        }
 
-        let mut header_response = ui.interact(rect, id, Sense::click());
+        let mut header_response = ui.interact(header_rect, id, Sense::click());
         let text_pos = pos2(
             text_pos.x,
             header_response.rect.center().y - galley.size.y / 2.0,
         );
 
         let mut state = State::from_memory_with_default_open(ui.ctx(), id, default_open);
+        if let Some(open) = open {
+            if open != state.open {
+                state.toggle(ui);
+                header_response.mark_changed();
+            }
+        }
         if header_response.clicked() {
             state.toggle(ui);
             header_response.mark_changed();
@@ -246,13 +330,17 @@ impl CollapsingHeader {
 
         let visuals = ui.style().interact(&header_response);
         let text_color = visuals.text_color();
-        ui.painter().add(Shape::Rect {
-            rect: header_response.rect.expand(visuals.expansion),
-            corner_radius: visuals.corner_radius,
-            fill: visuals.bg_fill,
-            stroke: visuals.bg_stroke,
-            // stroke: Default::default(),
-        });
+        if show_background || header_response.hovered() || header_response.has_focus() {
+            ui.painter().set(
+                background,
+                Shape::Rect {
+                    rect: rect.expand(visuals.expansion),
+                    corner_radius: visuals.corner_radius,
+                    fill: fill.unwrap_or(visuals.bg_fill),
+                    stroke: visuals.bg_stroke,
+                },
+            );
+        }
 
         {
             let (mut icon_rect, _) = ui.spacing().icon_rectangles(header_response.rect);
@@ -265,7 +353,7 @@ impl CollapsingHeader {
                 ..header_response.clone()
             };
             let openness = state.openness(ui.ctx(), id);
-            paint_icon(ui, openness, &icon_response);
+            icon(ui, openness, &icon_response);
         }
 
         ui.painter().galley(text_pos, galley, text_color);
@@ -281,6 +369,17 @@ impl CollapsingHeader {
         self,
         ui: &mut Ui,
         add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> CollapsingResponse<R> {
+        self.show_with_trailing(ui, |_| {}, add_contents)
+    }
+
+    /// Like [`Self::show`], but with `add_trailing` adding widgets to the right end of the header row,
+    /// e.g. a count badge or a delete button. They are laid out right-to-left.
+    pub fn show_with_trailing<R>(
+        self,
+        ui: &mut Ui,
+        add_trailing: impl FnOnce(&mut Ui),
+        add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> CollapsingResponse<R> {
         // Make sure contents are bellow header,
         // and make sure it is one unit (necessary for putting a `CollapsingHeader` in a grid).
@@ -291,7 +390,7 @@ impl CollapsingHeader {
                 id,
                 header_response,
                 mut state,
-            } = self.begin(ui);
+            } = self.begin(ui, add_trailing);
 
             let ret_response = state.add_contents(ui, id, |ui| {
                 ui.indent(id, |ui| {
@@ -19,14 +19,39 @@ use epaint::Shape;
 ///     }
 /// );
 /// ```
+///
+/// When the combo box has keyboard focus, space or enter opens it, and escape closes it.
 #[must_use = "You should call .show*"]
 pub struct ComboBox {
     id_source: Id,
     label: Option<Label>,
     selected_text: String,
     width: Option<f32>,
+    max_height: f32,
+    searchable: bool,
+}
+
+/// The filter of a searchable [`ComboBox`] while it is open.
+#[derive(Clone, Debug, Default)]
+struct SearchState {
+    filter: String,
+    /// Index into the matching items, chosen with the arrow keys.
+    highlighted: usize,
 }
 
+/// The keyboard state of a [`ComboBox::show_index`] list.
+#[derive(Clone, Debug, Default)]
+struct ListState {
+    /// The item moved to with the arrow keys, while the popup is open.
+    highlighted: Option<usize>,
+    /// What was typed to jump to an item.
+    type_ahead: String,
+    last_typed: f64,
+}
+
+/// Start a new type-ahead search after this many seconds without typing.
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
 impl ComboBox {
     /// Label shown next to the combo box
     pub fn from_label(label: impl Into<Label>) -> Self {
@@ -36,6 +61,8 @@ impl ComboBox {
             label: Some(label),
             selected_text: Default::default(),
             width: None,
+            max_height: MAX_COMBO_HEIGHT,
+            searchable: false,
         }
     }
 
@@ -46,6 +73,8 @@ impl ComboBox {
             label: Default::default(),
             selected_text: Default::default(),
             width: None,
+            max_height: MAX_COMBO_HEIGHT,
+            searchable: false,
         }
     }
 
@@ -55,6 +84,13 @@ impl ComboBox {
         self
     }
 
+    /// The popup scrolls when its contents are higher than this.
+    /// It is also kept smaller than the room on the screen. Default: 128.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
     /// What we show as the currently selected value
     #[allow(clippy::needless_pass_by_value)]
     pub fn selected_text(mut self, selected_text: impl ToString) -> Self {
@@ -62,13 +98,45 @@ impl ComboBox {
         self
     }
 
+    /// Put a text field at the top of the list that narrows down the items as you type.
+    /// The arrow keys move between the matching items, and enter selects one.
+    ///
+    /// Only used by [`Self::show_index`]. Default: `false`.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let fonts = ["Arial", "Courier", "Helvetica", "Times"];
+    /// let mut selected = 0;
+    /// egui::ComboBox::from_label("Font")
+    ///     .searchable(true)
+    ///     .show_index(ui, &mut selected, fonts.len(), |i| fonts[i].to_owned());
+    /// ```
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
     /// Show the combo box, with the given ui code for the menu contents.
+    ///
+    /// The arrow keys move the keyboard focus between the widgets in the menu.
     pub fn show_ui(self, ui: &mut Ui, menu_contents: impl FnOnce(&mut Ui)) -> Response {
+        self.show_with(ui, |ui, button_id, selected_text, max_height| {
+            combo_box(ui, button_id, selected_text, max_height, menu_contents)
+        })
+    }
+
+    fn show_with(
+        self,
+        ui: &mut Ui,
+        add_combo_box: impl FnOnce(&mut Ui, Id, String, f32) -> Response,
+    ) -> Response {
         let Self {
             id_source,
             label,
             selected_text,
             width,
+            max_height,
+            searchable: _,
         } = self;
 
         let button_id = ui.make_persistent_id(id_source);
@@ -77,7 +145,7 @@ impl ComboBox {
             if let Some(width) = width {
                 ui.spacing_mut().slider_width = width; // yes, this is ugly. Will remove later.
             }
-            let mut response = combo_box(ui, button_id, selected_text, menu_contents);
+            let mut response = add_combo_box(ui, button_id, selected_text, max_height);
             if let Some(label) = label {
                 response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
                 response |= ui.add(label);
@@ -106,6 +174,10 @@ impl ComboBox {
     ///     |i| alternatives[i].to_owned()
     /// );
     /// ```
+    ///
+    /// The arrow keys move between the items, and typing the start of an item jumps to it.
+    /// This also works on the closed combo box, when it has keyboard focus.
+    /// With [`Self::searchable`], the list is instead filtered by typing.
     pub fn show_index(
         self,
         ui: &mut Ui,
@@ -117,13 +189,19 @@ impl ComboBox {
 
         let mut changed = false;
 
-        let mut response = slf.show_ui(ui, |ui| {
-            for i in 0..len {
-                if ui.selectable_label(i == *selected, get(i)).clicked() {
-                    *selected = i;
-                    changed = true;
-                }
+        let searchable = slf.searchable;
+        let mut response = slf.show_with(ui, |ui, button_id, selected_text, max_height| {
+            let button_response = combo_box_button(ui, button_id, selected_text);
+            let picked = if searchable {
+                searchable_popup(ui, &button_response, *selected, len, &get, max_height)
+            } else {
+                list_popup(ui, &button_response, *selected, len, &get, max_height)
+            };
+            if let Some(index) = picked {
+                *selected = index;
+                changed = true;
             }
+            button_response
         });
 
         if changed {
@@ -131,6 +209,52 @@ impl ComboBox {
         }
         response
     }
+
+    /// Select any number of items, with a checkbox for each item.
+    ///
+    /// `selected` has one entry per item, and `get` gives the text of an item.
+    /// Unless you set [`Self::selected_text`], the button shows a summary of the selection.
+    /// Shift-click to check or uncheck everything from the last clicked item.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let toppings = ["Cheese", "Ham", "Mushrooms", "Olives"];
+    /// let mut selected = vec![true, false, false, true];
+    /// egui::ComboBox::from_label("Toppings").show_multi(ui, &mut selected, |i| {
+    ///     toppings[i].to_owned()
+    /// });
+    /// ```
+    pub fn show_multi(
+        mut self,
+        ui: &mut Ui,
+        selected: &mut [bool],
+        get: impl Fn(usize) -> String,
+    ) -> Response {
+        if self.selected_text.is_empty() {
+            self.selected_text = selection_summary(selected, &get);
+        }
+
+        let mut changed = false;
+        let mut response = self.show_with(ui, |ui, button_id, selected_text, max_height| {
+            let button_response = combo_box_button(ui, button_id, selected_text);
+            changed = multi_select_popup(ui, &button_response, selected, &get, max_height);
+            button_response
+        });
+
+        if changed {
+            response.mark_changed();
+            ui.ctx().request_repaint(); // update the summary
+        }
+        response
+    }
+}
+
+fn selection_summary(selected: &[bool], get: &dyn Fn(usize) -> String) -> String {
+    match selected.iter().filter(|&&item| item).count() {
+        0 => "None".to_owned(),
+        1 => get(selected.iter().position(|&item| item).unwrap_or_default()),
+        count => format!("{} selected", count),
+    }
 }
 
 /// A drop-down selection menu with a descriptive label.
@@ -159,7 +283,7 @@ pub fn combo_box_with_label(
     let button_id = ui.make_persistent_id(label.text());
 
     ui.horizontal(|ui| {
-        let mut response = combo_box(ui, button_id, selected, menu_contents);
+        let mut response = combo_box(ui, button_id, selected, MAX_COMBO_HEIGHT, menu_contents);
         response.widget_info(|| WidgetInfo::labeled(WidgetType::ComboBox, label.text()));
         response |= ui.add(label);
         response
@@ -167,14 +291,51 @@ pub fn combo_box_with_label(
     .inner
 }
 
-#[allow(clippy::needless_pass_by_value)]
+const MAX_COMBO_HEIGHT: f32 = 128.0;
+
 fn combo_box(
     ui: &mut Ui,
     button_id: Id,
     selected: impl ToString,
+    max_height: f32,
     menu_contents: impl FnOnce(&mut Ui),
 ) -> Response {
     let popup_id = button_id.with("popup");
+    let button_response = combo_box_button(ui, button_id, selected);
+    let max_height = fit_to_screen(ui, &button_response, max_height);
+    crate::popup::popup_below_widget(ui, popup_id, &button_response, |ui| {
+        if ui.input().key_pressed(Key::ArrowDown) {
+            ui.memory().move_focus(false);
+        } else if ui.input().key_pressed(Key::ArrowUp) {
+            ui.memory().move_focus(true);
+        }
+        ScrollArea::from_max_height(max_height).show(ui, menu_contents);
+
+        // Enter clicked a widget in the menu:
+        if ui.input().key_pressed(Key::Enter) && !button_response.has_focus() {
+            ui.memory().close_popup();
+        }
+    });
+
+    button_response
+}
+
+/// Limit the height of the popup contents to the room above or below the button,
+/// whichever side the popup ends up on.
+fn fit_to_screen(ui: &Ui, button_response: &Response, max_height: f32) -> f32 {
+    let screen = ui.ctx().input().screen_rect();
+    let rect = button_response.rect;
+    let room = (screen.bottom() - rect.bottom()).max(rect.top() - screen.top());
+    let margin = 2.0 * Frame::popup(ui.style()).margin.y;
+    max_height
+        .at_most(room - margin)
+        .at_least(ui.spacing().interact_size.y)
+}
+
+/// The button that opens and closes the popup.
+#[allow(clippy::needless_pass_by_value)]
+fn combo_box_button(ui: &mut Ui, button_id: Id, selected: impl ToString) -> Response {
+    let popup_id = button_id.with("popup");
 
     let button_active = ui.memory().is_popup_open(popup_id);
     let button_response = button_frame(ui, button_id, button_active, Sense::click(), |ui| {
@@ -207,14 +368,267 @@ fn combo_box(
     if button_response.clicked() {
         ui.memory().toggle_popup(popup_id);
     }
-    const MAX_COMBO_HEIGHT: f32 = 128.0;
-    crate::popup::popup_below_widget(ui, popup_id, &button_response, |ui| {
-        ScrollArea::from_max_height(MAX_COMBO_HEIGHT).show(ui, menu_contents)
-    });
-
     button_response
 }
 
+/// The popup of a searchable combo box. Returns the index of the item the user picked, if any.
+fn searchable_popup(
+    ui: &mut Ui,
+    button_response: &Response,
+    selected: usize,
+    len: usize,
+    get: &dyn Fn(usize) -> String,
+    max_height: f32,
+) -> Option<usize> {
+    let popup_id = button_response.id.with("popup");
+    let filter_id = button_response.id.with("filter");
+    if !ui.memory().is_popup_open(popup_id) {
+        ui.memory().id_data_temp.remove(&filter_id);
+        return None;
+    }
+
+    let just_opened = button_response.clicked();
+    let mut state = if just_opened {
+        SearchState {
+            filter: String::new(),
+            highlighted: selected,
+        }
+    } else {
+        ui.memory()
+            .id_data_temp
+            .get_or_default::<SearchState>(filter_id)
+            .clone()
+    };
+
+    let mut picked = None;
+    let max_height = fit_to_screen(ui, button_response, max_height);
+    let popup = crate::popup::Popup::new(popup_id)
+        .match_widget_width(true)
+        .clip_to_parent(true)
+        .close_on_click_outside(false)
+        .show(ui, button_response, |ui| {
+            let filter_response = ui.add(
+                TextEdit::singleline(&mut state.filter)
+                    .id(filter_id)
+                    .hint_text("Search…"),
+            );
+            if just_opened {
+                filter_response.request_focus();
+            }
+
+            let needle = state.filter.to_lowercase();
+            let matches: Vec<usize> = (0..len)
+                .filter(|&i| get(i).to_lowercase().contains(&needle))
+                .collect();
+
+            // `highlighted` is an item index when just opened, and then an index into the matches:
+            if just_opened {
+                state.highlighted = matches.iter().position(|&i| i == selected).unwrap_or(0);
+            } else if filter_response.changed() {
+                state.highlighted = 0;
+            }
+            let key_down = ui.input().num_presses(Key::ArrowDown);
+            let key_up = ui.input().num_presses(Key::ArrowUp);
+            let moved = key_down + key_up > 0;
+            state.highlighted = (state.highlighted + key_down)
+                .saturating_sub(key_up)
+                .min(matches.len().saturating_sub(1));
+
+            if ui.input().key_pressed(Key::Enter) {
+                picked = matches.get(state.highlighted).copied();
+            }
+
+            let max_height = max_height - filter_response.rect.height();
+            ScrollArea::from_max_height(max_height).show(ui, |ui| {
+                for (row, &i) in matches.iter().enumerate() {
+                    let response = ui.selectable_label(row == state.highlighted, get(i));
+                    if moved && row == state.highlighted {
+                        response.scroll_to_me(Align::Center);
+                    }
+                    if response.clicked() {
+                        picked = Some(i);
+                    }
+                }
+                if matches.is_empty() {
+                    ui.colored_label(ui.visuals().weak_text_color(), "No matches");
+                }
+            });
+        });
+
+    let popup_rect = popup.map(|popup| popup.response.rect);
+    if picked.is_some() || clicked_outside(ui, button_response, popup_rect) {
+        ui.memory().close_popup();
+    }
+    ui.memory().id_data_temp.insert(filter_id, state);
+    picked
+}
+
+/// The popup of a [`ComboBox::show_index`] list, and its keyboard handling.
+/// Returns the index of the item the user picked, if any.
+fn list_popup(
+    ui: &mut Ui,
+    button_response: &Response,
+    selected: usize,
+    len: usize,
+    get: &dyn Fn(usize) -> String,
+    max_height: f32,
+) -> Option<usize> {
+    let popup_id = button_response.id.with("popup");
+    let state_id = button_response.id.with("keyboard");
+    let mut state = ui
+        .memory()
+        .id_data_temp
+        .get_or_default::<ListState>(state_id)
+        .clone();
+    let is_open = ui.memory().is_popup_open(popup_id);
+
+    let (key_down, key_up, time) = {
+        let input = ui.input();
+        (
+            input.num_presses(Key::ArrowDown),
+            input.num_presses(Key::ArrowUp),
+            input.time,
+        )
+    };
+    // Space or enter on the focused button toggles the popup, so it also confirms:
+    let confirm = ui.input().key_pressed(Key::Enter)
+        || button_response.clicked() && ui.input().key_pressed(Key::Space);
+    let typed: String = ui
+        .input()
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            Event::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+        .collect();
+    let jump = type_ahead(&mut state, &typed, time, len, get);
+    let last = len.saturating_sub(1);
+
+    let mut picked = None;
+    match state.highlighted {
+        Some(highlighted) if confirm && highlighted < len => {
+            picked = Some(highlighted);
+            state.highlighted = None;
+            ui.memory().close_popup();
+        }
+        _ if is_open => {
+            let just_opened = state.highlighted.is_none();
+            let highlighted = jump.unwrap_or_else(|| {
+                (state.highlighted.unwrap_or(selected) + key_down)
+                    .saturating_sub(key_up)
+                    .min(last)
+            });
+            let moved = just_opened || jump.is_some() || key_down + key_up > 0;
+            state.highlighted = Some(highlighted);
+
+            let max_height = fit_to_screen(ui, button_response, max_height);
+            crate::popup::popup_below_widget(ui, popup_id, button_response, |ui| {
+                ScrollArea::from_max_height(max_height).show(ui, |ui| {
+                    for i in 0..len {
+                        let response = ui.selectable_label(i == highlighted, get(i));
+                        if moved && i == highlighted {
+                            response.scroll_to_me(Align::Center);
+                        }
+                        if response.clicked() {
+                            picked = Some(i);
+                        }
+                    }
+                });
+            });
+            if picked.is_some() {
+                state.highlighted = None;
+            }
+        }
+        _ => {
+            // Closed, e.g. with escape.
+            state.highlighted = None;
+            if button_response.has_focus() && len > 0 {
+                picked = jump.or_else(|| {
+                    if key_down + key_up > 0 {
+                        Some((selected + key_down).saturating_sub(key_up).min(last))
+                    } else {
+                        None
+                    }
+                });
+            }
+        }
+    }
+
+    ui.memory().id_data_temp.insert(state_id, state);
+    picked.filter(|&index| index != selected)
+}
+
+/// Add what was `typed` to the type-ahead search, and return the first item that starts with it.
+fn type_ahead(
+    state: &mut ListState,
+    typed: &str,
+    time: f64,
+    len: usize,
+    get: &dyn Fn(usize) -> String,
+) -> Option<usize> {
+    if time - state.last_typed > TYPE_AHEAD_TIMEOUT {
+        state.type_ahead.clear();
+    }
+    if typed.is_empty() || state.type_ahead.is_empty() && typed.trim().is_empty() {
+        return None; // e.g. the space that opened the popup
+    }
+    state.type_ahead.push_str(&typed.to_lowercase());
+    state.last_typed = time;
+    (0..len).find(|&i| get(i).to_lowercase().starts_with(&state.type_ahead))
+}
+
+/// The popup of [`ComboBox::show_multi`]. Returns `true` if the selection changed.
+fn multi_select_popup(
+    ui: &mut Ui,
+    button_response: &Response,
+    selected: &mut [bool],
+    get: &dyn Fn(usize) -> String,
+    max_height: f32,
+) -> bool {
+    let popup_id = button_response.id.with("popup");
+    let anchor_id = button_response.id.with("anchor");
+    let mut anchor = ui.memory().id_data_temp.get::<usize>(&anchor_id).copied();
+    let mut changed = false;
+
+    let max_height = fit_to_screen(ui, button_response, max_height);
+    let popup = crate::popup::Popup::new(popup_id)
+        .match_widget_width(true)
+        .clip_to_parent(true)
+        .close_on_click_outside(false) // stay open while checking items
+        .show(ui, button_response, |ui| {
+            ScrollArea::from_max_height(max_height).show(ui, |ui| {
+                for index in 0..selected.len() {
+                    let mut checked = selected[index];
+                    if ui.checkbox(&mut checked, get(index)).clicked() {
+                        let modifiers = ui.input().modifiers;
+                        list_box::select(selected, &mut anchor, index, modifiers, true);
+                        changed = true;
+                    }
+                }
+            });
+        });
+
+    let popup_rect = popup.map(|popup| popup.response.rect);
+    if clicked_outside(ui, button_response, popup_rect) {
+        ui.memory().close_popup();
+    }
+    if let Some(anchor) = anchor {
+        ui.memory().id_data_temp.insert(anchor_id, anchor);
+    }
+    changed
+}
+
+/// Was there a click outside both the combo box button and its popup?
+fn clicked_outside(ui: &Ui, button_response: &Response, popup_rect: Option<Rect>) -> bool {
+    match (popup_rect, ui.input().pointer.interact_pos()) {
+        (Some(popup_rect), Some(pointer_pos)) => {
+            button_response.clicked_elsewhere() && !popup_rect.contains(pointer_pos)
+        }
+        _ => false,
+    }
+}
+
 fn button_frame(
     ui: &mut Ui,
     id: Id,
@@ -256,7 +670,7 @@ fn button_frame(
     response
 }
 
-fn paint_icon(painter: &Painter, rect: Rect, visuals: &WidgetVisuals) {
+pub(super) fn paint_icon(painter: &Painter, rect: Rect, visuals: &WidgetVisuals) {
     let rect = Rect::from_center_size(
         rect.center(),
         vec2(rect.width() * 0.7, rect.height() * 0.45),
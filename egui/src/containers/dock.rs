@@ -0,0 +1,771 @@
+//! Dock [`Window`]s as panels and tabs, see [`DockArea`].
+
+use std::collections::{HashMap, HashSet};
+
+use crate::*;
+
+/// Which way a [`DockNode::Split`] divides its area.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum SplitDirection {
+    /// The two parts are side by side.
+    Horizontal,
+    /// The two parts are on top of each other.
+    Vertical,
+}
+
+/// A docked [`Window`], shown as a tab in a [`DockArea`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct DockTab {
+    /// The [`Id`] of the window, i.e. `Id::new(title)` unless set with [`Window::id`].
+    pub id: Id,
+    /// Shown in the tab bar.
+    pub title: String,
+}
+
+impl DockTab {
+    /// A tab for the window with the given title, see [`Window::new`].
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(title: impl ToString) -> Self {
+        let title = title.to_string();
+        Self {
+            id: Id::new(&title),
+            title,
+        }
+    }
+}
+
+/// A part of a [`DockLayout`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub enum DockNode {
+    /// Windows stacked as tabs, of which only the `active` one is shown.
+    Tabs {
+        /// The windows, in the order of the tab bar.
+        tabs: Vec<DockTab>,
+        /// The index in `tabs` of the window that is shown.
+        active: usize,
+    },
+    /// An area divided in two, where `first` gets `fraction` of the space.
+    Split {
+        /// Whether the parts are side by side or on top of each other.
+        direction: SplitDirection,
+        /// How much of the area `first` gets, from zero to one. The user can drag the line between the parts.
+        fraction: f32,
+        /// The left or top part.
+        first: Box<DockNode>,
+        /// The right or bottom part.
+        second: Box<DockNode>,
+    },
+}
+
+impl DockNode {
+    /// A single window.
+    pub fn tab(tab: DockTab) -> Self {
+        DockNode::Tabs {
+            tabs: vec![tab],
+            active: 0,
+        }
+    }
+
+    fn contains(&self, id: Id) -> bool {
+        match self {
+            DockNode::Tabs { tabs, .. } => tabs.iter().any(|tab| tab.id == id),
+            DockNode::Split { first, second, .. } => first.contains(id) || second.contains(id),
+        }
+    }
+
+    fn for_each_tab(&self, f: &mut impl FnMut(&DockTab)) {
+        match self {
+            DockNode::Tabs { tabs, .. } => tabs.iter().for_each(f),
+            DockNode::Split { first, second, .. } => {
+                first.for_each_tab(f);
+                second.for_each_tab(f);
+            }
+        }
+    }
+
+    /// Remove the tab, and any parts of the tree that become empty.
+    fn remove(self, id: Id, removed: &mut Option<DockTab>) -> Option<DockNode> {
+        match self {
+            DockNode::Tabs {
+                mut tabs,
+                mut active,
+            } => {
+                if let Some(index) = tabs.iter().position(|tab| tab.id == id) {
+                    *removed = Some(tabs.remove(index));
+                    if index < active || active >= tabs.len() {
+                        active = active.saturating_sub(1);
+                    }
+                }
+                if tabs.is_empty() {
+                    None
+                } else {
+                    Some(DockNode::Tabs { tabs, active })
+                }
+            }
+            DockNode::Split {
+                direction,
+                fraction,
+                first,
+                second,
+            } => match (first.remove(id, removed), second.remove(id, removed)) {
+                (Some(first), Some(second)) => Some(DockNode::Split {
+                    direction,
+                    fraction,
+                    first: Box::new(first),
+                    second: Box::new(second),
+                }),
+                (Some(node), None) | (None, Some(node)) => Some(node),
+                (None, None) => None,
+            },
+        }
+    }
+
+    fn node_mut(&mut self, path: &[bool]) -> &mut DockNode {
+        match (path.split_first(), self) {
+            (
+                Some((&second, rest)),
+                DockNode::Split {
+                    first, second: s, ..
+                },
+            ) => {
+                if second {
+                    s.node_mut(rest)
+                } else {
+                    first.node_mut(rest)
+                }
+            }
+            (_, node) => node,
+        }
+    }
+
+    /// The rectangles of all [`DockNode::Tabs`], and the path to get to them.
+    fn tabs_rects(&self, rect: Rect, path: &mut Vec<bool>, out: &mut Vec<(Vec<bool>, Rect)>) {
+        match self {
+            DockNode::Tabs { .. } => out.push((path.clone(), rect)),
+            DockNode::Split {
+                direction,
+                fraction,
+                first,
+                second,
+            } => {
+                let (first_rect, second_rect) = split_rect(rect, *direction, *fraction);
+                path.push(false);
+                first.tabs_rects(first_rect, path, out);
+                path.pop();
+                path.push(true);
+                second.tabs_rects(second_rect, path, out);
+                path.pop();
+            }
+        }
+    }
+}
+
+fn split_rect(rect: Rect, direction: SplitDirection, fraction: f32) -> (Rect, Rect) {
+    let (mut first, mut second) = (rect, rect);
+    match direction {
+        SplitDirection::Horizontal => {
+            let x = lerp(rect.x_range(), fraction);
+            first.max.x = x;
+            second.min.x = x;
+        }
+        SplitDirection::Vertical => {
+            let y = lerp(rect.y_range(), fraction);
+            first.max.y = y;
+            second.min.y = y;
+        }
+    }
+    (first, second)
+}
+
+/// How the windows in a [`DockArea`] are arranged.
+///
+/// The layout is stored in [`Memory`], and can be saved with `serde` using the `persistence` feature.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct DockLayout {
+    /// `None` when no window is docked.
+    pub root: Option<DockNode>,
+}
+
+impl DockLayout {
+    /// Is the window with the given [`Id`] docked?
+    pub fn contains(&self, id: Id) -> bool {
+        matches!(&self.root, Some(root) if root.contains(id))
+    }
+
+    /// Undock a window, returning its tab if it was docked.
+    pub fn remove(&mut self, id: Id) -> Option<DockTab> {
+        let mut removed = None;
+        self.root = self
+            .root
+            .take()
+            .and_then(|root| root.remove(id, &mut removed));
+        removed
+    }
+
+    fn dock(&mut self, tab: DockTab, target: DropTarget) {
+        self.remove(tab.id);
+        if self.root.is_none() {
+            self.root = Some(DockNode::tab(tab));
+            return;
+        }
+        let root = self.root.as_mut().unwrap();
+
+        let (path, side, fraction) = match target {
+            DropTarget::Tabs(path) => {
+                if let DockNode::Tabs { tabs, active } = root.node_mut(&path) {
+                    tabs.push(tab);
+                    *active = tabs.len() - 1;
+                }
+                return;
+            }
+            DropTarget::Split(path, side) => (path, side, 0.5),
+            DropTarget::Edge(side) => (vec![], side, 0.25),
+        };
+
+        let node = root.node_mut(&path);
+        let new = DockNode::tab(tab);
+        let old = std::mem::replace(node, new.clone());
+        let (direction, new_first) = match side {
+            Side::Left => (SplitDirection::Horizontal, true),
+            Side::Right => (SplitDirection::Horizontal, false),
+            Side::Top => (SplitDirection::Vertical, true),
+            Side::Bottom => (SplitDirection::Vertical, false),
+        };
+        *node = if new_first {
+            DockNode::Split {
+                direction,
+                fraction,
+                first: Box::new(new),
+                second: Box::new(old),
+            }
+        } else {
+            DockNode::Split {
+                direction,
+                fraction: 1.0 - fraction,
+                first: Box::new(old),
+                second: Box::new(new),
+            }
+        };
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Side {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+impl Side {
+    const ALL: [Side; 4] = [Side::Left, Side::Right, Side::Top, Side::Bottom];
+
+    fn dir(self) -> Vec2 {
+        match self {
+            Side::Left => -Vec2::X,
+            Side::Right => Vec2::X,
+            Side::Top => -Vec2::Y,
+            Side::Bottom => Vec2::Y,
+        }
+    }
+
+    /// The part of `rect` along this side.
+    fn part_of(self, rect: Rect, fraction: f32) -> Rect {
+        let mut part = rect;
+        match self {
+            Side::Left => part.max.x = lerp(rect.x_range(), fraction),
+            Side::Right => part.min.x = lerp(rect.x_range(), 1.0 - fraction),
+            Side::Top => part.max.y = lerp(rect.y_range(), fraction),
+            Side::Bottom => part.min.y = lerp(rect.y_range(), 1.0 - fraction),
+        }
+        part
+    }
+}
+
+/// Where a dragged window can be dropped.
+#[derive(Clone, Debug, PartialEq)]
+enum DropTarget {
+    /// Add as a tab.
+    Tabs(Vec<bool>),
+    /// Split the tabs in two.
+    Split(Vec<bool>, Side),
+    /// Along the edge of the whole [`DockArea`].
+    Edge(Side),
+}
+
+/// Short-term state shared by the [`DockArea`] and the windows, stored in [`Memory::data_temp`].
+#[derive(Clone, Debug, Default)]
+struct DockFrame {
+    /// All docked windows, shown or not.
+    docked: HashSet<Id>,
+    /// Where to show the windows that are the active tab.
+    content_rects: HashMap<Id, Rect>,
+    /// Docked windows shown since the `DockArea` was shown.
+    shown: HashSet<Id>,
+    /// The window that was moved by the user since the `DockArea` was shown.
+    dragged: Option<DockTab>,
+}
+
+/// Is the window docked in the [`DockArea`]?
+///
+/// Always `false` if the `DockArea` is not shown this frame, so that windows float again
+/// when it goes away.
+pub(crate) fn is_docked(ctx: &Context, id: Id) -> bool {
+    if !ctx.frame_state().dock_area_shown {
+        return false;
+    }
+    match ctx.memory().data_temp.get::<DockFrame>() {
+        Some(frame) => frame.docked.contains(&id),
+        None => false,
+    }
+}
+
+/// Tell the [`DockArea`] that the user is moving the window, so that it can be dropped on it.
+pub(crate) fn set_dragged(ctx: &Context, tab: DockTab) {
+    if !ctx.frame_state().dock_area_shown {
+        return;
+    }
+    ctx.memory()
+        .data_temp
+        .get_mut_or_default::<DockFrame>()
+        .dragged = Some(tab);
+}
+
+/// Show the contents of a docked window, if it is the active tab.
+pub(crate) fn show_docked(
+    ctx: &CtxRef,
+    id: Id,
+    scroll: Option<ScrollArea>,
+    add_contents: Box<dyn FnOnce(&mut Ui) + '_>,
+) -> Option<Response> {
+    let rect = {
+        let mut memory = ctx.memory();
+        let frame = memory.data_temp.get_mut_or_default::<DockFrame>();
+        frame.shown.insert(id);
+        frame.content_rects.get(&id).copied()
+    }?;
+
+    let mut ui = Ui::new(ctx.clone(), LayerId::background(), id, rect, rect);
+    let mut content_ui = ui.child_ui(
+        rect.shrink2(ctx.style().spacing.window_padding),
+        Layout::top_down(Align::Min),
+    );
+    if let Some(scroll) = scroll {
+        scroll.show(&mut content_ui, add_contents);
+    } else {
+        add_contents(&mut content_ui);
+    }
+    Some(ui.interact(rect, id, Sense::hover()))
+}
+
+// ----------------------------------------------------------------------------
+
+/// An area where [`Window`]s can be docked as panels and tabs.
+///
+/// Drag a window onto the drop targets that show up in the area to dock it,
+/// either as a tab, next to other docked windows, or along an edge of the area.
+/// Drag the tab of a docked window out of its tab bar to make it float again.
+///
+/// The `DockArea` covers the space not used by panels, like a [`CentralPanel`].
+/// Show it after the panels, but before the windows.
+/// There can only be one `DockArea` at a time.
+/// Only windows with [`Window::dockable`] set can be docked.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// egui::DockArea::new("dock").show(ctx);
+/// egui::Window::new("Inspector").dockable(true).show(ctx, |ui| {
+///    ui.label("Drag me onto the dock area");
+/// });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct DockArea {
+    id: Id,
+    default_layout: DockLayout,
+    frame: Option<Frame>,
+}
+
+impl DockArea {
+    /// `id_source`: Something unique, e.g. `"my_dock"`.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        Self {
+            id: Id::new(id_source),
+            default_layout: Default::default(),
+            frame: None,
+        }
+    }
+
+    /// How the windows are arranged before the user has moved them.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// use egui::{DockLayout, DockNode, DockTab, SplitDirection};
+    /// let layout = DockLayout {
+    ///     root: Some(DockNode::Split {
+    ///         direction: SplitDirection::Horizontal,
+    ///         fraction: 0.25,
+    ///         first: Box::new(DockNode::tab(DockTab::new("Files"))),
+    ///         second: Box::new(DockNode::tab(DockTab::new("Editor"))),
+    ///     }),
+    /// };
+    /// egui::DockArea::new("dock").default_layout(layout).show(ctx);
+    /// ```
+    pub fn default_layout(mut self, default_layout: DockLayout) -> Self {
+        self.default_layout = default_layout;
+        self
+    }
+
+    /// Change the background color, margins, etc.
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    /// The current arrangement of the windows, e.g. to save it.
+    pub fn layout(&self, ctx: &Context) -> DockLayout {
+        ctx.memory()
+            .id_data
+            .get::<DockLayout>(&self.id)
+            .cloned()
+            .unwrap_or_else(|| self.default_layout.clone())
+    }
+
+    /// Change the arrangement of the windows, e.g. to restore a saved one.
+    pub fn set_layout(&self, ctx: &Context, layout: DockLayout) {
+        ctx.memory().id_data.insert(self.id, layout);
+    }
+}
+
+impl DockArea {
+    pub fn show(self, ctx: &CtxRef) -> Response {
+        let rect = ctx.available_rect();
+        let mut layout = self.layout(ctx);
+        let DockFrame { dragged, shown, .. } = ctx
+            .memory()
+            .data_temp
+            .get::<DockFrame>()
+            .cloned()
+            .unwrap_or_default();
+
+        let mut ui = Ui::new(ctx.clone(), LayerId::background(), self.id, rect, rect);
+        let frame = self
+            .frame
+            .unwrap_or_else(|| Frame::central_panel(&ctx.style()));
+        ui.painter().add(frame.paint(rect));
+
+        if let Some(tab) = dragged {
+            drop_targets_ui(&ui, self.id, &mut layout, rect, tab);
+        }
+
+        let mut new_frame = DockFrame::default();
+        let mut undock = None;
+        if let Some(root) = &mut layout.root {
+            root.for_each_tab(&mut |tab| {
+                new_frame.docked.insert(tab.id);
+            });
+            node_ui(
+                &mut ui,
+                self.id,
+                root,
+                rect,
+                &mut vec![],
+                &shown,
+                &mut new_frame,
+                &mut undock,
+            );
+        }
+
+        if let Some(id) = undock {
+            layout.remove(id);
+            new_frame.docked.remove(&id);
+            new_frame.content_rects.remove(&id);
+            start_dragging_window(ctx, id);
+        }
+
+        self.set_layout(ctx, layout);
+        ctx.memory().data_temp.insert(new_frame);
+        ctx.frame_state().dock_area_shown = true;
+        ctx.frame_state().allocate_central_panel(rect);
+        ui.interact(rect, self.id, Sense::hover())
+    }
+}
+
+/// Show the tab bars and the lines between the docked windows.
+#[allow(clippy::too_many_arguments)]
+fn node_ui(
+    ui: &mut Ui,
+    id: Id,
+    node: &mut DockNode,
+    rect: Rect,
+    path: &mut Vec<bool>,
+    shown: &HashSet<Id>,
+    frame: &mut DockFrame,
+    undock: &mut Option<Id>,
+) {
+    match node {
+        DockNode::Split {
+            direction,
+            fraction,
+            first,
+            second,
+        } => {
+            let (first_rect, second_rect) = split_rect(rect, *direction, *fraction);
+            path.push(false);
+            node_ui(ui, id, first, first_rect, path, shown, frame, undock);
+            path.pop();
+            path.push(true);
+            node_ui(ui, id, second, second_rect, path, shown, frame, undock);
+            path.pop();
+
+            let grab_radius = ui.style().interaction.resize_grab_radius_side;
+            let (line, handle_rect, cursor) = match direction {
+                SplitDirection::Horizontal => {
+                    let x = first_rect.right();
+                    (
+                        [pos2(x, rect.top()), pos2(x, rect.bottom())],
+                        Rect::from_x_y_ranges(x - grab_radius..=x + grab_radius, rect.y_range()),
+                        CursorIcon::ResizeHorizontal,
+                    )
+                }
+                SplitDirection::Vertical => {
+                    let y = first_rect.bottom();
+                    (
+                        [pos2(rect.left(), y), pos2(rect.right(), y)],
+                        Rect::from_x_y_ranges(rect.x_range(), y - grab_radius..=y + grab_radius),
+                        CursorIcon::ResizeVertical,
+                    )
+                }
+            };
+            let response = ui
+                .interact(handle_rect, id.with(("split", &path)), Sense::drag())
+                .on_hover_cursor(cursor);
+            if let Some(pointer) = response.interact_pointer_pos() {
+                if response.dragged() {
+                    let t = match direction {
+                        SplitDirection::Horizontal => remap(pointer.x, rect.x_range(), 0.0..=1.0),
+                        SplitDirection::Vertical => remap(pointer.y, rect.y_range(), 0.0..=1.0),
+                    };
+                    *fraction = t.at_least(0.1).at_most(0.9);
+                }
+            }
+            let stroke = if response.hovered() || response.dragged() {
+                ui.style().interact(&response).fg_stroke
+            } else {
+                ui.visuals().widgets.noninteractive.bg_stroke
+            };
+            ui.painter().line_segment(line, stroke);
+        }
+        DockNode::Tabs { tabs, active } => {
+            // Tabs of windows that are closed are hidden, but stay in the layout.
+            let visible: Vec<usize> = (0..tabs.len())
+                .filter(|&i| shown.contains(&tabs[i].id))
+                .collect();
+            if !visible.contains(active) {
+                if let Some(&first) = visible.first() {
+                    *active = first;
+                }
+            }
+
+            let bar_height = ui.spacing().interact_size.y + ui.spacing().item_spacing.y;
+            let mut bar_rect = rect;
+            bar_rect.max.y = rect.min.y + bar_height;
+            ui.painter()
+                .rect_filled(bar_rect, 0.0, ui.visuals().widgets.noninteractive.bg_fill);
+            ui.painter().line_segment(
+                [bar_rect.left_bottom(), bar_rect.right_bottom()],
+                ui.visuals().widgets.noninteractive.bg_stroke,
+            );
+
+            let mut bar_ui = ui.child_ui(
+                bar_rect.shrink2(0.5 * ui.spacing().item_spacing),
+                Layout::left_to_right(),
+            );
+            bar_ui.set_clip_rect(bar_rect.intersect(ui.clip_rect()));
+            for &i in &visible {
+                let tab = &tabs[i];
+                let response = bar_ui.selectable_label(i == *active, &tab.title);
+                if response.clicked() {
+                    *active = i;
+                }
+                let response = response.interact(Sense::drag());
+                if response.dragged() {
+                    if let Some(pointer) = response.interact_pointer_pos() {
+                        if !bar_rect.expand(bar_height).contains(pointer) {
+                            *undock = Some(tab.id);
+                        }
+                    }
+                }
+            }
+
+            if visible.contains(active) {
+                let mut content_rect = rect;
+                content_rect.min.y = bar_rect.max.y;
+                frame.content_rects.insert(tabs[*active].id, content_rect);
+            }
+        }
+    }
+}
+
+/// Paint the places a dragged window can be dropped, and dock it when it is dropped on one.
+fn drop_targets_ui(ui: &Ui, id: Id, layout: &mut DockLayout, rect: Rect, tab: DockTab) {
+    let size = 2.0 * ui.spacing().interact_size.y;
+    let mut targets = vec![];
+    if let Some(root) = &layout.root {
+        let mut tabs_rects = vec![];
+        root.tabs_rects(rect, &mut vec![], &mut tabs_rects);
+        for (path, tabs_rect) in tabs_rects {
+            let center = tabs_rect.center();
+            targets.push((center, DropTarget::Tabs(path.clone()), tabs_rect));
+            for &side in &Side::ALL {
+                targets.push((
+                    center + 1.2 * size * side.dir(),
+                    DropTarget::Split(path.clone(), side),
+                    side.part_of(tabs_rect, 0.5),
+                ));
+            }
+        }
+        for &side in &Side::ALL {
+            let edge = rect.center() + 0.5 * rect.size() * side.dir();
+            targets.push((
+                edge - 0.75 * size * side.dir(),
+                DropTarget::Edge(side),
+                side.part_of(rect, 0.25),
+            ));
+        }
+    } else {
+        targets.push((rect.center(), DropTarget::Tabs(vec![]), rect));
+    }
+
+    let pointer = ui.input().pointer.hover_pos();
+    let released = !ui.input().pointer.any_down();
+    let painter = Painter::new(
+        ui.ctx().clone(),
+        LayerId::new(Order::Foreground, id.with("drop_targets")),
+        rect,
+    );
+    let visuals = ui.visuals();
+    for (center, target, preview_rect) in targets {
+        let target_rect = Rect::from_center_size(center, Vec2::splat(size));
+        let hovered = matches!(pointer, Some(pointer) if target_rect.contains(pointer));
+        if hovered {
+            if released {
+                layout.dock(tab, target);
+                return;
+            }
+            painter.rect_filled(
+                preview_rect,
+                0.0,
+                visuals.selection.bg_fill.linear_multiply(0.3),
+            );
+        }
+        let widget = if hovered {
+            visuals.widgets.hovered
+        } else {
+            visuals.widgets.inactive
+        };
+        painter.rect(
+            target_rect,
+            widget.corner_radius,
+            widget.bg_fill,
+            widget.bg_stroke,
+        );
+        let icon_rect = match target {
+            DropTarget::Tabs(_) => target_rect.shrink(0.25 * size),
+            DropTarget::Split(_, side) | DropTarget::Edge(side) => {
+                side.part_of(target_rect.shrink(0.25 * size), 0.4)
+            }
+        };
+        painter.rect_filled(icon_rect, 0.0, widget.fg_stroke.color);
+    }
+    if !released {
+        ui.ctx().request_repaint();
+    }
+}
+
+/// Let the user keep dragging a window that was just undocked.
+fn start_dragging_window(ctx: &Context, id: Id) {
+    let press_origin = match ctx.input().pointer.press_origin() {
+        Some(press_origin) => press_origin,
+        None => return,
+    };
+    let layer_id = LayerId::new(Order::Middle, id);
+    let size = ctx
+        .memory()
+        .areas
+        .get(id)
+        .map_or(Vec2::ZERO, |state| state.size);
+    // Keep the pointer on the title bar.
+    let grab_offset = vec2(
+        0.5 * size.x.at_most(200.0),
+        0.5 * ctx.style().spacing.interact_size.y,
+    );
+    let start_rect = Rect::from_min_size(press_origin - grab_offset, size);
+
+    let mut memory = ctx.memory();
+    memory.areas.set_state(
+        layer_id,
+        area::State {
+            pos: start_rect.min,
+            size,
+            interactable: true,
+        },
+    );
+    memory.areas.move_to_top(layer_id);
+    memory.interaction.drag_id = Some(id.with("frame_resize"));
+    memory.interaction.drag_is_window = true;
+    memory.window_interaction = Some(window::WindowInteraction {
+        area_layer_id: layer_id,
+        start_rect,
+        left: false,
+        right: false,
+        top: false,
+        bottom: false,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dock_and_undock() {
+        let mut layout = DockLayout::default();
+        layout.dock(DockTab::new("a"), DropTarget::Tabs(vec![]));
+        layout.dock(DockTab::new("b"), DropTarget::Split(vec![], Side::Right));
+        layout.dock(DockTab::new("c"), DropTarget::Tabs(vec![true]));
+        assert_eq!(
+            layout.root,
+            Some(DockNode::Split {
+                direction: SplitDirection::Horizontal,
+                fraction: 0.5,
+                first: Box::new(DockNode::tab(DockTab::new("a"))),
+                second: Box::new(DockNode::Tabs {
+                    tabs: vec![DockTab::new("b"), DockTab::new("c")],
+                    active: 1,
+                }),
+            })
+        );
+
+        assert_eq!(layout.remove(Id::new("a")), Some(DockTab::new("a")));
+        assert_eq!(
+            layout.root,
+            Some(DockNode::Tabs {
+                tabs: vec![DockTab::new("b"), DockTab::new("c")],
+                active: 1,
+            })
+        );
+        layout.remove(Id::new("c"));
+        layout.remove(Id::new("b"));
+        assert_eq!(layout.root, None);
+    }
+}
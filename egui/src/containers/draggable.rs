@@ -0,0 +1,286 @@
+//! Drag and drop of any widgets, see [`Draggable`] and [`DropZone`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// The item being dragged, stored in [`Memory`] so only one item is dragged at a time.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct DraggedItem {
+    pub id: Id,
+    /// Where the item was grabbed, relative to its top left corner.
+    pub grab_offset: Vec2,
+    /// The [`DropZone`] and index the item was over last frame.
+    pub target: Option<(Id, usize)>,
+}
+
+/// The items added to each [`DropZone`] that is being shown, innermost last.
+#[derive(Clone, Default)]
+struct DropZoneStack(Vec<Vec<(Id, Rect)>>);
+
+/// How opaque the floating copy of a dragged item is.
+const PREVIEW_OPACITY: f32 = 0.6;
+
+/// Returned by [`Draggable::show`].
+pub struct DraggableResponse<R> {
+    pub inner: R,
+    pub response: Response,
+    /// Was the item picked up this frame?
+    pub picked_up: bool,
+    /// Is the item being dragged?
+    pub is_dragged: bool,
+    /// Was the item let go of this frame?
+    pub dropped: bool,
+    /// The [`DropZone`] the item is over, and the index it would get there.
+    /// As of last frame, because the drop zone is done after its items.
+    pub drop_target: Option<(Id, usize)>,
+}
+
+/// Make some widgets draggable with the pointer. Put it in a [`DropZone`] to drop it somewhere.
+///
+/// While dragged, a semi-transparent copy of the widgets follows the pointer,
+/// and an empty space is left where they were.
+/// Only one item can be dragged at a time, see [`Memory::dragged_item`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut todo = vec!["Write docs".to_owned(), "Fix bug".to_owned()];
+/// # let mut done: Vec<String> = vec![];
+/// let mut dropped_from = None;
+/// egui::DropZone::new("todo").show(ui, |ui| {
+///     for (index, task) in todo.iter().enumerate() {
+///         if egui::Draggable::new(task).show(ui, |ui| ui.label(task)).dropped {
+///             dropped_from = Some(index);
+///         }
+///     }
+/// });
+/// let done_zone = egui::DropZone::new("done").show(ui, |ui| {
+///     for task in &done {
+///         egui::Draggable::new(task).show(ui, |ui| ui.label(task));
+///     }
+/// });
+/// if let (Some(from), Some((_, to))) = (dropped_from, done_zone.dropped) {
+///     let task = todo.remove(from);
+///     done.insert(to, task);
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Draggable {
+    id_source: Id,
+}
+
+impl Draggable {
+    /// `id_source`: Something unique for the item, which stays the same when it is moved.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+        }
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> DraggableResponse<R> {
+        let id = ui.make_persistent_id(self.id_source);
+        let dragged = ui.memory().dragged_item;
+        let pointer_pos = ui.input().pointer.hover_pos();
+
+        let (inner, response, picked_up, drag) = match dragged {
+            Some(drag) if drag.id == id => {
+                // Paint the item on top of everything, following the pointer:
+                let layer_id = LayerId::new(Order::Tooltip, id);
+                let InnerResponse { inner, response } = ui.with_layer_id(layer_id, add_contents);
+                let rect = response.rect;
+                // Leave a placeholder where it was:
+                ui.painter().rect_filled(
+                    rect,
+                    ui.visuals().widgets.noninteractive.corner_radius,
+                    ui.visuals().extreme_bg_color,
+                );
+                if let Some(pointer_pos) = pointer_pos {
+                    let delta = pointer_pos - drag.grab_offset - rect.min;
+                    ui.ctx().translate_layer(layer_id, delta);
+                }
+                ui.ctx()
+                    .graphics()
+                    .list(layer_id)
+                    .lock()
+                    .multiply_with_opacity(PREVIEW_OPACITY);
+                ui.output().cursor_icon = CursorIcon::Grabbing;
+                let response = ui.interact(rect, id, Sense::drag());
+                (inner, response, false, Some(drag))
+            }
+            _ => {
+                let InnerResponse { inner, response } = ui.scope(add_contents);
+                let response = ui.interact(response.rect, id, Sense::drag());
+                if dragged.is_none() && response.hovered() {
+                    ui.output().cursor_icon = CursorIcon::Grab;
+                }
+                let mut drag = None;
+                if dragged.is_none() && response.drag_started() {
+                    if let Some(pointer_pos) = pointer_pos {
+                        drag = Some(DraggedItem {
+                            id,
+                            grab_offset: pointer_pos - response.rect.min,
+                            target: None,
+                        });
+                        ui.memory().dragged_item = drag;
+                    }
+                }
+                (inner, response, drag.is_some(), drag)
+            }
+        };
+
+        if let Some(items) = ui
+            .memory()
+            .data_temp
+            .get_mut_or_default::<DropZoneStack>()
+            .0
+            .last_mut()
+        {
+            items.push((id, response.rect));
+        }
+
+        let dropped = drag.is_some() && ui.input().pointer.any_released();
+        DraggableResponse {
+            inner,
+            response,
+            picked_up,
+            is_dragged: drag.is_some(),
+            dropped,
+            drop_target: drag.and_then(|drag| drag.target),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Returned by [`DropZone::show`].
+pub struct DropZoneResponse<R> {
+    pub inner: R,
+    pub response: Response,
+    /// While an item is dragged over the zone: the index it would get if dropped.
+    pub hover_index: Option<usize>,
+    /// Set on the frame an item is dropped in the zone: the [`Id`] of the [`Draggable`],
+    /// and the index it should get among the items of the zone.
+    pub dropped: Option<(Id, usize)>,
+}
+
+/// An area where a [`Draggable`] can be dropped.
+///
+/// The index where an item is dropped is among the [`Draggable`]s added in the zone,
+/// not counting the dropped item itself, so for a move within the same zone it is
+/// the new index of the item.
+/// The zone is vertical or horizontal depending on the layout of the [`Ui`].
+///
+/// See [`Draggable`] for an example.
+#[must_use = "You should call .show()"]
+pub struct DropZone {
+    id_source: Id,
+}
+
+impl DropZone {
+    /// `id_source`: Something unique, e.g. `"todo"`.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+        }
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> DropZoneResponse<R> {
+        let id = ui.make_persistent_id(self.id_source);
+
+        ui.memory()
+            .data_temp
+            .get_mut_or_default::<DropZoneStack>()
+            .0
+            .push(vec![]);
+        let InnerResponse { inner, response } = ui.scope(add_contents);
+        let items = ui
+            .memory()
+            .data_temp
+            .get_mut_or_default::<DropZoneStack>()
+            .0
+            .pop()
+            .unwrap_or_default();
+
+        let mut hover_index = None;
+        let mut dropped = None;
+        let dragged = ui.memory().dragged_item;
+        if let Some(mut drag) = dragged {
+            let pointer_pos = ui.input().pointer.hover_pos();
+            match pointer_pos {
+                Some(pointer_pos) if response.rect.contains(pointer_pos) => {
+                    let horizontal = ui.layout().is_horizontal();
+                    let others: Vec<Rect> = items
+                        .iter()
+                        .filter(|(item_id, _)| *item_id != drag.id)
+                        .map(|(_, rect)| *rect)
+                        .collect();
+                    let index = others
+                        .iter()
+                        .filter(|rect| {
+                            if horizontal {
+                                rect.center().x < pointer_pos.x
+                            } else {
+                                rect.center().y < pointer_pos.y
+                            }
+                        })
+                        .count();
+                    paint_drop_marker(ui, response.rect, &others, index, horizontal);
+
+                    hover_index = Some(index);
+                    if ui.input().pointer.any_released() {
+                        dropped = Some((drag.id, index));
+                    }
+                    drag.target = Some((id, index));
+                }
+                _ => {
+                    if matches!(drag.target, Some((zone, _)) if zone == id) {
+                        drag.target = None;
+                    }
+                }
+            }
+            ui.memory().dragged_item = Some(drag);
+        }
+
+        DropZoneResponse {
+            inner,
+            response,
+            hover_index,
+            dropped,
+        }
+    }
+}
+
+/// Show where a dragged item would be dropped: a line between the items, or around the empty zone.
+fn paint_drop_marker(ui: &Ui, zone_rect: Rect, others: &[Rect], index: usize, horizontal: bool) {
+    let stroke = ui.visuals().selection.stroke;
+    if others.is_empty() {
+        ui.painter().rect_stroke(zone_rect.expand(1.0), 2.0, stroke);
+        return;
+    }
+
+    let half_spacing = 0.5 * ui.spacing().item_spacing;
+    let line = if horizontal {
+        let x = if index == 0 {
+            others[0].left() - half_spacing.x
+        } else {
+            others[index - 1].right() + half_spacing.x
+        };
+        [pos2(x, zone_rect.top()), pos2(x, zone_rect.bottom())]
+    } else {
+        let y = if index == 0 {
+            others[0].top() - half_spacing.y
+        } else {
+            others[index - 1].bottom() + half_spacing.y
+        };
+        [pos2(zone_rect.left(), y), pos2(zone_rect.right(), y)]
+    };
+    ui.painter().line_segment(line, stroke);
+}
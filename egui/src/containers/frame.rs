@@ -12,6 +12,8 @@ pub struct Frame {
     pub corner_radius: f32,
     pub shadow: Shadow,
     pub fill: Color32,
+    /// If set, this is used to fill the frame instead of [`Self::fill`].
+    pub gradient: Option<Gradient>,
     pub stroke: Stroke,
 }
 
@@ -56,6 +58,7 @@ impl Frame {
             corner_radius: style.visuals.window_corner_radius,
             shadow: style.visuals.window_shadow,
             fill: style.visuals.window_fill(),
+            gradient: None,
             stroke: style.visuals.window_stroke(),
         }
     }
@@ -66,6 +69,7 @@ impl Frame {
             corner_radius: 2.0,
             shadow: Shadow::small(),
             fill: style.visuals.window_fill(),
+            gradient: None,
             stroke: style.visuals.window_stroke(),
         }
     }
@@ -76,6 +80,7 @@ impl Frame {
             corner_radius: 5.0,
             shadow: Shadow::small(),
             fill: style.visuals.window_fill(),
+            gradient: None,
             stroke: style.visuals.window_stroke(),
         }
     }
@@ -98,13 +103,43 @@ impl Frame {
         self
     }
 
+    /// Fill the frame with a gradient instead of a solid color.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::{epaint::Gradient, Color32};
+    /// egui::Frame::group(ui.style())
+    ///     .gradient(Gradient::vertical(Color32::from_gray(60), Color32::from_gray(30)))
+    ///     .show(ui, |ui| {
+    ///         ui.label("A card");
+    ///     });
+    /// ```
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
     pub fn stroke(mut self, stroke: Stroke) -> Self {
         self.stroke = stroke;
         self
     }
 
+    pub fn corner_radius(mut self, corner_radius: f32) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    /// A drop shadow, e.g. `Shadow::small().with_offset(vec2(2.0, 4.0))`.
+    pub fn shadow(mut self, shadow: Shadow) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
     pub fn multiply_with_opacity(mut self, opacity: f32) -> Self {
         self.fill = self.fill.linear_multiply(opacity);
+        self.gradient = self
+            .gradient
+            .map(|gradient| gradient.multiply_with_opacity(opacity));
         self.stroke.color = self.stroke.color.linear_multiply(opacity);
         self.shadow.color = self.shadow.color.linear_multiply(opacity);
         self
@@ -153,9 +188,21 @@ impl Frame {
             corner_radius,
             shadow,
             fill,
+            gradient,
             stroke,
         } = *self;
 
+        let mut shapes = vec![];
+        if shadow != Default::default() {
+            shapes.push(Shape::Mesh(shadow.tessellate(outer_rect, corner_radius)));
+        }
+        let fill = match gradient {
+            Some(gradient) => {
+                shapes.push(Shape::Mesh(gradient.tessellate(outer_rect, corner_radius)));
+                Color32::TRANSPARENT
+            }
+            None => fill,
+        };
         let frame_shape = Shape::Rect {
             rect: outer_rect,
             corner_radius,
@@ -163,12 +210,11 @@ impl Frame {
             stroke,
         };
 
-        if shadow == Default::default() {
+        if shapes.is_empty() {
             frame_shape
         } else {
-            let shadow = shadow.tessellate(outer_rect, corner_radius);
-            let shadow = Shape::Mesh(shadow);
-            Shape::Vec(vec![shadow, frame_shape])
+            shapes.push(frame_shape);
+            Shape::Vec(shapes)
         }
     }
 }
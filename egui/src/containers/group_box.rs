@@ -0,0 +1,159 @@
+//! A frame with a caption in its border, see [`GroupBox`].
+
+use crate::*;
+
+/// A framed group of widgets with a caption embedded in the top of the frame,
+/// like an HTML fieldset.
+///
+/// The caption can be a checkbox, which then enables or disables the contents.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let (mut use_proxy, mut host) = (false, String::new());
+/// egui::GroupBox::new("Proxy")
+///     .checkbox(&mut use_proxy)
+///     .show(ui, |ui| {
+///         ui.label("Host:");
+///         ui.text_edit_singleline(&mut host);
+///     });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct GroupBox<'a> {
+    title: String,
+    checkbox: Option<&'a mut bool>,
+    frame: Option<Frame>,
+}
+
+impl<'a> GroupBox<'a> {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(title: impl ToString) -> Self {
+        Self {
+            title: title.to_string(),
+            checkbox: None,
+            frame: None,
+        }
+    }
+
+    /// Show a checkbox in the caption, and only enable the contents when it is checked.
+    pub fn checkbox(mut self, checked: &'a mut bool) -> Self {
+        self.checkbox = Some(checked);
+        self
+    }
+
+    /// Change the look of the frame. Default: [`Frame::group`].
+    pub fn frame(mut self, frame: Frame) -> Self {
+        self.frame = Some(frame);
+        self
+    }
+
+    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
+        let Self {
+            title,
+            checkbox,
+            frame,
+        } = self;
+        let frame = frame.unwrap_or_else(|| Frame::group(ui.style()));
+        let margin = frame.margin;
+        // The frame is painted in three parts, behind the caption and the contents:
+        let where_to_put_background: Vec<_> =
+            (0..3).map(|_| ui.painter().add(Shape::Noop)).collect();
+
+        let outer = ui.available_rect_before_wrap();
+        let caption_left = outer.left() + margin.x + frame.corner_radius;
+
+        // The caption, overlapping the top of the frame:
+        let mut caption_ui = ui.child_ui(
+            Rect::from_x_y_ranges(caption_left..=outer.right() - margin.x, outer.y_range()),
+            Layout::left_to_right(),
+        );
+        let enabled = match checkbox {
+            Some(checked) => {
+                caption_ui.checkbox(checked, title);
+                *checked
+            }
+            None => {
+                caption_ui.label(title);
+                true
+            }
+        };
+        let caption_rect = caption_ui.min_rect();
+
+        let mut content_rect = outer.shrink2(margin);
+        content_rect.min.y = caption_rect.bottom() + margin.y;
+        content_rect.max.x = content_rect.max.x.max(content_rect.min.x);
+        content_rect.max.y = content_rect.max.y.max(content_rect.min.y);
+        let mut content_ui = ui.child_ui(content_rect, *ui.layout());
+        if !enabled {
+            content_ui.set_enabled(false);
+        }
+        let inner = add_contents(&mut content_ui);
+
+        let max = content_ui.min_rect().max + margin;
+        let frame_rect = Rect::from_min_max(
+            pos2(outer.left(), caption_rect.center().y),
+            pos2(
+                max.x
+                    .max(caption_rect.right() + margin.x + frame.corner_radius),
+                max.y,
+            ),
+        );
+
+        // Paint the frame everywhere except behind the caption:
+        let gap = ui.spacing().item_spacing.x;
+        let everything = frame_rect.expand(frame.stroke.width + 1.0);
+        let clip_rects = [
+            Rect::from_min_max(
+                everything.min,
+                pos2(caption_rect.left() - gap, caption_rect.bottom()),
+            ),
+            Rect::from_min_max(
+                pos2(caption_rect.right() + gap, everything.top()),
+                pos2(everything.right(), caption_rect.bottom()),
+            ),
+            Rect::from_min_max(
+                pos2(everything.left(), caption_rect.bottom()),
+                everything.max,
+            ),
+        ];
+        for (clip_rect, idx) in clip_rects.iter().zip(where_to_put_background) {
+            ui.painter()
+                .sub_region(*clip_rect)
+                .set(idx, frame.paint(frame_rect));
+        }
+
+        let response = ui.allocate_rect(frame_rect.union(caption_rect), Sense::hover());
+        InnerResponse::new(inner, response)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_red_fill(shape: &Shape) -> bool {
+        match shape {
+            Shape::Rect { fill, .. } => *fill == Color32::RED,
+            Shape::Vec(shapes) => shapes.iter().any(is_red_fill),
+            _ => false,
+        }
+    }
+
+    #[test]
+    fn filled_frame_is_behind_contents() {
+        let mut ctx = CtxRef::default();
+        ctx.begin_frame(Default::default());
+        CentralPanel::default().show(&ctx, |ui| {
+            let frame = Frame::group(ui.style()).fill(Color32::RED);
+            GroupBox::new("Caption")
+                .frame(frame)
+                .show(ui, |ui| ui.label("Contents"));
+        });
+        let (_, shapes) = ctx.end_frame();
+
+        let fill = shapes.iter().position(|shape| is_red_fill(&shape.1));
+        let text = shapes
+            .iter()
+            .position(|shape| matches!(shape.1, Shape::Text { .. }));
+        assert!(fill.unwrap() < text.unwrap());
+    }
+}
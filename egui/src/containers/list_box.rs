@@ -0,0 +1,119 @@
+//! A list where several items can be selected, see [`ListBox`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// A framed, scrollable list of items where any number of them can be selected.
+///
+/// Click to select an item, Ctrl-click (⌘ on Mac) to add or remove an item from the selection,
+/// and Shift-click to select everything from the last clicked item.
+///
+/// See also [`ComboBox::show_multi`], for the same in a drop-down.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let devices = ["Microphone", "Line in", "Headset"];
+/// let mut selected = vec![true, false, false];
+/// egui::ListBox::new("devices").show(ui, &mut selected, |i| devices[i].to_owned());
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ListBox {
+    id_source: Id,
+    max_height: f32,
+}
+
+impl ListBox {
+    /// `id_source`: Something unique, e.g. `"devices"`.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            max_height: 128.0,
+        }
+    }
+
+    /// Scroll when the list is higher than this. Default: 128.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    /// `selected` has one entry per item, and `get` gives the text of an item.
+    pub fn show(
+        self,
+        ui: &mut Ui,
+        selected: &mut [bool],
+        get: impl Fn(usize) -> String,
+    ) -> Response {
+        let Self {
+            id_source,
+            max_height,
+        } = self;
+        let id = ui.make_persistent_id(id_source);
+        let mut anchor = ui.memory().id_data_temp.get::<usize>(&id).copied();
+        let mut changed = false;
+
+        let mut response = Frame::group(ui.style())
+            .show(ui, |ui| {
+                ScrollArea::from_max_height(max_height)
+                    .id_source(id)
+                    .show(ui, |ui| {
+                        ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                            for index in 0..selected.len() {
+                                if ui.selectable_label(selected[index], get(index)).clicked() {
+                                    let modifiers = ui.input().modifiers;
+                                    select(selected, &mut anchor, index, modifiers, false);
+                                    changed = true;
+                                }
+                            }
+                        });
+                    });
+            })
+            .response;
+
+        if let Some(anchor) = anchor {
+            ui.memory().id_data_temp.insert(id, anchor);
+        }
+        if changed {
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Update a multiple selection after a click on the item at `index`.
+///
+/// With `checkboxes`, a plain click toggles the item,
+/// and Shift-click gives the range the same state as the `anchor` (the last clicked item).
+pub(crate) fn select(
+    selected: &mut [bool],
+    anchor: &mut Option<usize>,
+    index: usize,
+    modifiers: Modifiers,
+    checkboxes: bool,
+) {
+    match *anchor {
+        Some(anchor) if modifiers.shift && anchor < selected.len() => {
+            let value = if checkboxes { selected[anchor] } else { true };
+            if !checkboxes && !modifiers.command {
+                for item in selected.iter_mut() {
+                    *item = false;
+                }
+            }
+            for item in &mut selected[anchor.min(index)..=anchor.max(index)] {
+                *item = value;
+            }
+        }
+        _ => {
+            if checkboxes || modifiers.command {
+                selected[index] = !selected[index];
+            } else {
+                for item in selected.iter_mut() {
+                    *item = false;
+                }
+                selected[index] = true;
+            }
+            *anchor = Some(index);
+        }
+    }
+}
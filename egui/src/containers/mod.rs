@@ -2,24 +2,42 @@
 //!
 //! For instance, a [`Frame`] adds a frame and background to some contained UI.
 
+mod accordion;
 pub(crate) mod area;
 pub(crate) mod collapsing_header;
 mod combo_box;
+pub(crate) mod dock;
+pub(crate) mod draggable;
 pub(crate) mod frame;
+mod group_box;
+pub(crate) mod list_box;
 pub(crate) mod panel;
 pub mod popup;
+mod reorderable_list;
 pub(crate) mod resize;
 pub(crate) mod scroll_area;
+mod split_button;
+mod toolbar;
+mod tree;
 pub(crate) mod window;
 
 pub use {
+    accordion::{Accordion, AccordionUi},
     area::Area,
     collapsing_header::*,
     combo_box::*,
+    dock::{DockArea, DockLayout, DockNode, DockTab, SplitDirection},
+    draggable::{Draggable, DraggableResponse, DropZone, DropZoneResponse},
     frame::Frame,
-    panel::{CentralPanel, SidePanel, TopPanel},
+    group_box::GroupBox,
+    list_box::ListBox,
+    panel::{CentralPanel, PanelGroup, PanelSeparator, SidePanel, TopPanel},
     popup::*,
+    reorderable_list::{ListMove, ReorderableList, ReorderableListResponse},
     resize::Resize,
-    scroll_area::ScrollArea,
-    window::Window,
+    scroll_area::{ScrollArea, ScrollAreaOutput},
+    split_button::SplitButton,
+    toolbar::Toolbar,
+    tree::{DropPosition, Tree, TreeMove, TreeResponse, TreeUi},
+    window::{Window, WindowResponse},
 };
@@ -7,45 +7,273 @@
 //!
 //! Add [`CentralPanel`] and [`Window`]:s last.
 
+use std::ops::RangeInclusive;
+
 use crate::*;
 
+/// State regarding panels.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+struct PanelState {
+    /// The area covered by the panel last frame.
+    rect: Rect,
+}
+
+/// The line between a panel and the rest of the screen,
+/// see [`SidePanel::separator`] and [`TopPanel::separator`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PanelSeparator {
+    /// Always painted along the edge of the panel. Default: `Stroke::none()`.
+    pub stroke: Stroke,
+
+    /// Highlight the line while the user hovers or drags it to resize the panel. Default: `true`.
+    pub hover_highlight: bool,
+
+    /// Double-click the line to give a resizable panel its default size again. Default: `true`.
+    pub double_click_to_reset: bool,
+
+    /// How close to the line the pointer must be to grab it.
+    /// `None` (default) means [`style::Interaction::resize_grab_radius_side`].
+    pub grab_radius: Option<f32>,
+}
+
+impl Default for PanelSeparator {
+    fn default() -> Self {
+        Self {
+            stroke: Stroke::none(),
+            hover_highlight: true,
+            double_click_to_reset: true,
+            grab_radius: None,
+        }
+    }
+}
+
+impl PanelSeparator {
+    fn grab_radius(&self, ui: &Ui) -> f32 {
+        self.grab_radius
+            .unwrap_or(ui.style().interaction.resize_grab_radius_side)
+    }
+
+    /// Paint the line along the edge of the panel, moved half a stroke `inward` to stay on the panel.
+    fn paint(&self, ui: &Ui, resize_response: Option<&Response>, line: [Pos2; 2], inward: Vec2) {
+        if self.stroke.width > 0.0 {
+            let offset = 0.5 * self.stroke.width * inward;
+            ui.painter()
+                .line_segment([line[0] + offset, line[1] + offset], self.stroke);
+        }
+
+        if let Some(resize_response) = resize_response {
+            if self.hover_highlight && (resize_response.hovered() || resize_response.dragged()) {
+                let stroke = ui.style().interact(resize_response).fg_stroke;
+                // Paint on top, so that the line is not covered by what comes after the panel.
+                let painter = Painter::new(
+                    ui.ctx().clone(),
+                    LayerId::new(Order::Foreground, Id::new("panel_resize")),
+                    ui.clip_rect(),
+                );
+                painter.line_segment(line, stroke);
+            }
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
-/// A panel that covers the entire left side of the screen.
+/// Which side of the screen a [`SidePanel`] is on.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    /// The x coordinate of this side of the rectangle.
+    fn side_x(self, rect: Rect) -> f32 {
+        match self {
+            Side::Left => rect.left(),
+            Side::Right => rect.right(),
+        }
+    }
+
+    /// The x coordinate of the other side of the rectangle, where the panel meets the rest of the screen.
+    fn inner_x(self, rect: Rect) -> f32 {
+        match self {
+            Side::Left => rect.right(),
+            Side::Right => rect.left(),
+        }
+    }
+
+    /// Change the width of the rectangle, keeping this side in place.
+    fn set_rect_width(self, rect: &mut Rect, width: f32) {
+        match self {
+            Side::Left => rect.max.x = rect.min.x + width,
+            Side::Right => rect.min.x = rect.max.x - width,
+        }
+    }
+}
+
+/// A panel that covers the entire left or right side of the screen.
 ///
 /// `SidePanel`s must be added before adding any [`CentralPanel`] or [`Window`]s.
 ///
+/// With [`Self::resizable`] the user can resize the panel by dragging its inner edge.
+/// The chosen width is remembered in [`Memory`].
+///
 /// ```
 /// # let mut ctx = egui::CtxRef::default();
 /// # ctx.begin_frame(Default::default());
 /// # let ctx = &ctx;
-/// egui::SidePanel::left("my_side_panel", 0.0).show(ctx, |ui| {
+/// egui::SidePanel::left("my_left_panel", 0.0).show(ctx, |ui| {
 ///    ui.label("Hello World!");
 /// });
+/// egui::SidePanel::right("my_right_panel", 200.0)
+///     .resizable(true)
+///     .show(ctx, |ui| {
+///         ui.label("Inspector");
+///     });
 /// ```
 #[must_use = "You should call .show()"]
 pub struct SidePanel {
+    side: Side,
     id: Id,
-    max_width: f32,
+    default_width: f32,
+    width_range: RangeInclusive<f32>,
+    resizable: bool,
+    auto_width: bool,
+    overlay: bool,
+    collapse_below: f32,
+    separator: PanelSeparator,
     frame: Option<Frame>,
 }
 
 impl SidePanel {
     /// `id_source`: Something unique, e.g. `"my_side_panel"`.
     /// The given `max_width` is a soft maximum (as always), and the actual panel may be smaller or larger.
+    /// It is also the width a resizable panel starts out with, see [`Self::default_width`].
     pub fn left(id_source: impl std::hash::Hash, max_width: f32) -> Self {
+        Self::new(Side::Left, id_source, max_width)
+    }
+
+    /// `id_source`: Something unique, e.g. `"my_side_panel"`.
+    /// The right panel always fills the given `max_width`, so that it lines up with the right edge.
+    pub fn right(id_source: impl std::hash::Hash, max_width: f32) -> Self {
+        Self::new(Side::Right, id_source, max_width)
+    }
+
+    fn new(side: Side, id_source: impl std::hash::Hash, max_width: f32) -> Self {
         Self {
+            side,
             id: Id::new(id_source),
-            max_width,
+            default_width: max_width,
+            width_range: 0.0..=f32::INFINITY,
+            resizable: false,
+            auto_width: false,
+            overlay: false,
+            collapse_below: 0.0,
+            separator: Default::default(),
             frame: None,
         }
     }
 
+    /// The width of a resizable panel before the user has resized it,
+    /// or the soft maximum width of a panel that is not resizable.
+    pub fn default_width(mut self, default_width: f32) -> Self {
+        self.default_width = default_width;
+        self
+    }
+
+    /// The panel is never narrower or wider than this, even when resized by the user.
+    /// Default: `0.0..=f32::INFINITY`.
+    pub fn width_range(mut self, width_range: RangeInclusive<f32>) -> Self {
+        self.width_range = width_range;
+        self
+    }
+
+    /// Can the user resize the panel by dragging its inner edge? Default: `false`.
+    ///
+    /// A resizable panel starts out [`Self::default_width`] wide, and always fills the width chosen by the user.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// How the line between the panel and the rest of the screen looks and behaves.
+    pub fn separator(mut self, separator: PanelSeparator) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Make the panel as wide as its widest contents, within [`Self::width_range`],
+    /// instead of [`Self::default_width`], e.g. for lists of loaded data. Default: `false`.
+    ///
+    /// The width follows the contents smoothly as they change from frame to frame.
+    /// Wrapping text will widen the panel up to the end of the width range.
+    /// The user can't resize a panel with automatic width.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let files = ["main.rs", "a_much_longer_file_name.rs"];
+    /// egui::SidePanel::left("files", 0.0)
+    ///     .auto_width(true)
+    ///     .show(ctx, |ui| {
+    ///         for file in &files {
+    ///             ui.selectable_label(false, *file);
+    ///         }
+    ///     });
+    /// ```
+    pub fn auto_width(mut self, auto_width: bool) -> Self {
+        self.auto_width = auto_width;
+        self
+    }
+
+    /// Float the panel above the [`CentralPanel`] instead of taking space from it,
+    /// e.g. for small screens. Default: `false`.
+    pub fn overlay(mut self, overlay: bool) -> Self {
+        self.overlay = overlay;
+        self
+    }
+
+    /// Close the panel shown with [`Self::show_animated`] whenever the available width of the screen
+    /// is less than `available_width`, e.g. on mobile. Default: `0.0`, i.e. never.
+    pub fn collapse_below(mut self, available_width: f32) -> Self {
+        self.collapse_below = available_width;
+        self
+    }
+
     /// Change the background color, margins, etc.
     pub fn frame(mut self, frame: Frame) -> Self {
         self.frame = Some(frame);
         self
     }
+
+    fn clamp_width(&self, width: f32, available_width: f32) -> f32 {
+        width
+            .at_least(*self.width_range.start())
+            .at_most(*self.width_range.end())
+            .at_most(available_width)
+    }
+
+    /// Where the panel goes within `available_rect`, before any resizing by the user this frame.
+    fn panel_rect(&self, ctx: &Context, available_rect: Rect) -> Rect {
+        let mut width = self.default_width;
+        if self.auto_width {
+            if let Some(auto_width) = ctx.memory().id_data.get::<f32>(&self.id.with("auto_width")) {
+                width = *auto_width;
+            }
+        } else if self.resizable {
+            if let Some(state) = ctx.memory().id_data.get::<PanelState>(&self.id) {
+                width = state.rect.width();
+            }
+        }
+        let mut panel_rect = available_rect;
+        self.side.set_rect_width(
+            &mut panel_rect,
+            self.clamp_width(width, available_rect.width()),
+        );
+        panel_rect
+    }
 }
 
 impl SidePanel {
@@ -54,32 +282,246 @@ impl SidePanel {
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
+        if self.overlay {
+            return self.show_overlay(ctx, add_contents);
+        }
+
+        let side = self.side;
+        let available_rect = ctx.available_rect();
+        let layer_id = LayerId::background();
+        let clip_rect = ctx.input().screen_rect();
+        let mut ui = Ui::new(ctx.clone(), layer_id, self.id, available_rect, clip_rect);
+
+        let inner_response = self.show_inside(&mut ui, add_contents);
+
+        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
+        let mut frame_state = ctx.frame_state();
+        match side {
+            Side::Left => frame_state.allocate_left_panel(inner_response.response.rect),
+            Side::Right => frame_state.allocate_right_panel(inner_response.response.rect),
+        }
+
+        inner_response
+    }
+
+    /// Show the panel along the side of the given [`Ui`] instead of the screen,
+    /// e.g. to give a [`Window`] its own sidebar.
+    ///
+    /// The remaining space of the `ui` shrinks accordingly, so add the panels before the rest of the contents.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// egui::SidePanel::left("window_sidebar", 100.0).show_inside(ui, |ui| {
+    ///    ui.label("Sidebar");
+    /// });
+    /// ui.label("Window contents");
+    /// ```
+    pub fn show_inside<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let available_rect = ui.available_rect_before_wrap_finite();
+        self.show_inside_rect(ui, available_rect, add_contents)
+    }
+
+    /// Show the panel floating above the rest of the screen.
+    fn show_overlay<R>(
+        self,
+        ctx: &CtxRef,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let available_rect = ctx.available_rect();
+        let panel_rect = self.panel_rect(ctx, available_rect);
+        let mut inner_response = None;
+        Area::new(self.id.with("overlay"))
+            .order(Order::Middle)
+            .movable(false)
+            .fixed_pos(panel_rect.min)
+            .show(ctx, |ui| {
+                inner_response = Some(self.show_inside_rect(ui, available_rect, add_contents));
+            });
+        inner_response.expect("Area::show always adds the contents")
+    }
+
+    fn show_inside_rect<R>(
+        self,
+        ui: &mut Ui,
+        available_rect: Rect,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let mut panel_rect = self.panel_rect(ui.ctx(), available_rect);
+        let clamp_width = |width: f32| self.clamp_width(width, available_rect.width());
         let Self {
+            side,
             id,
-            max_width,
+            default_width,
+            ref width_range,
+            resizable,
+            auto_width,
+            separator,
             frame,
+            ..
         } = self;
+        let resizable = resizable && !auto_width;
+
+        let mut resize_response = None;
+        if resizable {
+            let grab_radius = separator.grab_radius(ui);
+            let inner_x = side.inner_x(panel_rect);
+            let resize_rect = Rect::from_x_y_ranges(
+                inner_x - grab_radius..=inner_x + grab_radius,
+                panel_rect.y_range(),
+            );
+            let response = ui.interact(resize_rect, id.with("__resize"), Sense::drag());
+            if response.dragged() {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    let width = (pointer.x - side.side_x(panel_rect)).abs();
+                    side.set_rect_width(&mut panel_rect, clamp_width(width));
+                }
+            }
+            if separator.double_click_to_reset && response.double_clicked() {
+                side.set_rect_width(&mut panel_rect, clamp_width(default_width));
+            }
+            resize_response = Some(response.on_hover_cursor(CursorIcon::ResizeHorizontal));
+        }
 
-        let mut panel_rect = ctx.available_rect();
-        panel_rect.max.x = panel_rect.max.x.at_most(panel_rect.min.x + max_width);
-
-        let layer_id = LayerId::background();
-
-        let clip_rect = ctx.input().screen_rect();
-        let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
+        let mut panel_ui = ui.child_ui(panel_rect, Layout::top_down(Align::Min));
 
-        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
+        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        let min_inner_width = (width_range.start() - 2.0 * frame.margin.x).at_least(0.0);
+        let max_inner_width = clamp_width(f32::INFINITY) - 2.0 * frame.margin.x;
+        let mut content_width = 0.0;
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_height(ui.max_rect_finite().height()); // Make sure the frame fills the full height
-            add_contents(ui)
+            ui.set_min_width(min_inner_width.at_most(ui.max_rect_finite().width()));
+            if resizable || auto_width || side == Side::Right {
+                ui.set_min_width(ui.max_rect_finite().width()); // Fill the chosen width
+            }
+            if auto_width {
+                // Give the contents all the width they may want, and measure how much they use.
+                let content_rect = Rect::from_min_size(
+                    ui.max_rect_finite().min,
+                    vec2(max_inner_width, ui.max_rect_finite().height()),
+                );
+                let mut content_ui = ui.child_ui(content_rect, *ui.layout());
+                content_ui.set_clip_rect(
+                    ui.max_rect_finite()
+                        .expand(ui.visuals().clip_rect_margin)
+                        .intersect(ui.clip_rect()),
+                );
+                let inner = add_contents(&mut content_ui);
+                content_width = content_ui.min_rect().width();
+                inner
+            } else {
+                add_contents(ui)
+            }
         });
 
-        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
-        ctx.frame_state()
-            .allocate_left_panel(inner_response.response.rect);
+        if auto_width {
+            // Move towards the width of the contents, so the panel doesn't jump around.
+            let target_width = clamp_width(content_width + 2.0 * frame.margin.x);
+            let width = panel_rect.width();
+            let t = if ui.style().animations {
+                (ui.input().unstable_dt / ui.style().animation_time).at_most(1.0)
+            } else {
+                1.0
+            };
+            let mut new_width = width + t * (target_width - width);
+            if (new_width - target_width).abs() < 0.5 {
+                new_width = target_width;
+            } else {
+                ui.ctx().request_repaint();
+            }
+            ui.memory().id_data.insert(id.with("auto_width"), new_width);
+        }
+
+        let rect = inner_response.response.rect;
+        ui.memory().id_data.insert(id, PanelState { rect });
+
+        let inner_x = side.inner_x(rect);
+        let inward = match side {
+            Side::Left => -Vec2::X,
+            Side::Right => Vec2::X,
+        };
+        separator.paint(
+            ui,
+            resize_response.as_ref(),
+            [pos2(inner_x, rect.top()), pos2(inner_x, rect.bottom())],
+            inward,
+        );
+
+        // Leave the rest of the `ui` to what comes after the panel.
+        ui.expand_to_include_rect(rect);
+        let mut cursor = ui.cursor();
+        let mut max_rect = ui.max_rect();
+        match side {
+            Side::Left => {
+                cursor.min.x = rect.max.x;
+                max_rect.min.x = rect.max.x;
+            }
+            Side::Right => {
+                cursor.max.x = rect.min.x;
+                max_rect.max.x = rect.min.x;
+            }
+        }
+        ui.set_cursor(cursor);
+        ui.force_set_max_rect(max_rect);
 
         inner_response
     }
+
+    /// Show the panel if `is_open` is `true`, sliding it in and out when `is_open` changes.
+    ///
+    /// Returns `None` while the panel is closed or sliding.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let show_tools = true;
+    /// egui::SidePanel::left("tools", 200.0).show_animated(ctx, show_tools, |ui| {
+    ///    ui.label("Tools");
+    /// });
+    /// ```
+    pub fn show_animated<R>(
+        self,
+        ctx: &CtxRef,
+        is_open: bool,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let is_open = is_open && ctx.available_rect().width() >= self.collapse_below;
+        let how_open = ctx.animate_bool(self.id.with("animation"), is_open);
+        if how_open == 0.0 {
+            None
+        } else if how_open < 1.0 {
+            // Slide an empty panel of the same look, so the contents don't need to be squeezed.
+            let open_width = ctx
+                .memory()
+                .id_data
+                .get::<PanelState>(&self.id)
+                .map_or(self.default_width, |state| state.rect.width());
+            let width = how_open * open_width;
+            let frame = self
+                .frame
+                .unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
+            Self {
+                id: self.id.with("animating_panel"),
+                default_width: width,
+                width_range: width..=width,
+                resizable: false,
+                frame: Some(Frame {
+                    margin: Vec2::ZERO,
+                    ..frame
+                }),
+                ..self
+            }
+            .show(ctx, |_ui| {});
+            None
+        } else {
+            Some(self.show(ctx, add_contents))
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -99,7 +541,10 @@ impl SidePanel {
 #[must_use = "You should call .show()"]
 pub struct TopPanel {
     id: Id,
-    max_height: Option<f32>,
+    default_height: Option<f32>,
+    height_range: RangeInclusive<f32>,
+    resizable: bool,
+    separator: PanelSeparator,
     frame: Option<Frame>,
 }
 
@@ -110,11 +555,60 @@ impl TopPanel {
     pub fn top(id_source: impl std::hash::Hash) -> Self {
         Self {
             id: Id::new(id_source),
-            max_height: None,
+            default_height: None,
+            height_range: 0.0..=f32::INFINITY,
+            resizable: false,
+            separator: Default::default(),
             frame: None,
         }
     }
 
+    /// Make the panel exactly this high, unless the contents need more.
+    /// This also sets [`Self::height_range`], so a resizable panel can no longer be resized.
+    pub fn height(self, height: f32) -> Self {
+        self.default_height(height).height_range(height..=height)
+    }
+
+    /// The height of the panel, unless the contents need more.
+    /// Default: `interact_size.y`, i.e. the height of a button.
+    pub fn default_height(mut self, default_height: f32) -> Self {
+        self.default_height = Some(default_height);
+        self
+    }
+
+    /// The panel is never lower or higher than this. Default: `0.0..=f32::INFINITY`.
+    pub fn height_range(mut self, height_range: RangeInclusive<f32>) -> Self {
+        self.height_range = height_range;
+        self
+    }
+
+    /// The panel is never lower than this. Default: `0.0`.
+    pub fn min_height(mut self, min_height: f32) -> Self {
+        self.height_range = min_height..=*self.height_range.end();
+        self
+    }
+
+    /// The panel is never higher than this. Default: `f32::INFINITY`.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.height_range = *self.height_range.start()..=max_height;
+        self
+    }
+
+    /// Can the user resize the panel by dragging its bottom edge? Default: `false`.
+    ///
+    /// A resizable panel always fills the height chosen by the user, within [`Self::height_range`].
+    /// The chosen height is remembered in [`Memory`].
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// How the line between the panel and the rest of the screen looks and behaves.
+    pub fn separator(mut self, separator: PanelSeparator) -> Self {
+        self.separator = separator;
+        self
+    }
+
     /// Change the background color, margins, etc.
     pub fn frame(mut self, frame: Frame) -> Self {
         self.frame = Some(frame);
@@ -127,31 +621,111 @@ impl TopPanel {
         self,
         ctx: &CtxRef,
         add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<R> {
+        let available_rect = ctx.available_rect();
+        let layer_id = LayerId::background();
+        let clip_rect = ctx.input().screen_rect();
+        let mut ui = Ui::new(ctx.clone(), layer_id, self.id, available_rect, clip_rect);
+
+        let inner_response = self.show_inside(&mut ui, add_contents);
+
+        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
+        ctx.frame_state()
+            .allocate_top_panel(inner_response.response.rect);
+
+        inner_response
+    }
+
+    /// Show the panel along the top of the given [`Ui`] instead of the screen,
+    /// e.g. to give a [`Window`] its own toolbar.
+    ///
+    /// The remaining space of the `ui` shrinks accordingly, so add the panels before the rest of the contents.
+    pub fn show_inside<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
     ) -> InnerResponse<R> {
         let Self {
             id,
-            max_height,
+            default_height,
+            height_range,
+            resizable,
+            separator,
             frame,
         } = self;
-        let max_height = max_height.unwrap_or_else(|| ctx.style().spacing.interact_size.y);
 
-        let mut panel_rect = ctx.available_rect();
-        panel_rect.max.y = panel_rect.max.y.at_most(panel_rect.min.y + max_height);
-
-        let layer_id = LayerId::background();
+        let available_rect = ui.available_rect_before_wrap_finite();
+        let clamp_height = |height: f32| {
+            height
+                .at_least(*height_range.start())
+                .at_most(*height_range.end())
+                .at_most(available_rect.height())
+        };
+
+        let default_height = default_height.unwrap_or_else(|| ui.spacing().interact_size.y);
+        let mut height = default_height;
+        if resizable {
+            if let Some(state) = ui.memory().id_data.get::<PanelState>(&id) {
+                height = state.rect.height();
+            }
+        }
+        let mut panel_rect = available_rect;
+        panel_rect.max.y = panel_rect.min.y + clamp_height(height);
+
+        let mut resize_response = None;
+        if resizable {
+            let grab_radius = separator.grab_radius(ui);
+            let bottom = panel_rect.bottom();
+            let resize_rect = Rect::from_x_y_ranges(
+                panel_rect.x_range(),
+                bottom - grab_radius..=bottom + grab_radius,
+            );
+            let response = ui.interact(resize_rect, id.with("__resize"), Sense::drag());
+            if response.dragged() {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    panel_rect.max.y =
+                        panel_rect.min.y + clamp_height(pointer.y - panel_rect.min.y);
+                }
+            }
+            if separator.double_click_to_reset && response.double_clicked() {
+                panel_rect.max.y = panel_rect.min.y + clamp_height(default_height);
+            }
+            resize_response = Some(response.on_hover_cursor(CursorIcon::ResizeVertical));
+        }
 
-        let clip_rect = ctx.input().screen_rect();
-        let mut panel_ui = Ui::new(ctx.clone(), layer_id, id, panel_rect, clip_rect);
+        let mut panel_ui = ui.child_ui(panel_rect, Layout::top_down(Align::Min));
 
-        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(&ctx.style()));
+        let frame = frame.unwrap_or_else(|| Frame::side_top_panel(ui.style()));
+        let min_inner_height = (height_range.start() - 2.0 * frame.margin.y).at_least(0.0);
         let inner_response = frame.show(&mut panel_ui, |ui| {
             ui.set_min_width(ui.max_rect_finite().width()); // Make the frame fill full width
+            ui.set_min_height(min_inner_height);
+            if resizable {
+                ui.set_min_height(ui.max_rect_finite().height()); // Fill the chosen height
+            }
             add_contents(ui)
         });
 
-        // Only inform ctx about what we actually used, so we can shrink the native window to fit.
-        ctx.frame_state()
-            .allocate_top_panel(inner_response.response.rect);
+        let rect = inner_response.response.rect;
+        if resizable {
+            ui.memory().id_data.insert(id, PanelState { rect });
+        }
+
+        separator.paint(
+            ui,
+            resize_response.as_ref(),
+            [rect.left_bottom(), rect.right_bottom()],
+            -Vec2::Y,
+        );
+
+        // Leave the rest of the `ui` to what comes after the panel.
+        ui.expand_to_include_rect(rect);
+        let mut cursor = ui.cursor();
+        let mut max_rect = ui.max_rect();
+        cursor.min.y = rect.max.y;
+        max_rect.min.y = rect.max.y;
+        ui.set_cursor(cursor);
+        ui.force_set_max_rect(max_rect);
 
         inner_response
     }
@@ -159,6 +733,90 @@ impl TopPanel {
 
 // ----------------------------------------------------------------------------
 
+/// Adds several [`SidePanel`]s and [`TopPanel`]s in order of priority, instead of in code order.
+///
+/// The panel added first claims the full height or width of the screen,
+/// and the ones after it get what remains. Between panels, the remaining space is
+/// given by [`Context::available_rect`].
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// // The file tree spans the full height, with the menu bar to the right of it:
+/// egui::PanelGroup::new()
+///     .top_panel(0, egui::TopPanel::top("menu_bar"), |ui| {
+///         ui.label("Menu");
+///     })
+///     .side_panel(1, egui::SidePanel::left("file_tree", 200.0), |ui| {
+///         ui.label("Files");
+///     })
+///     .show(ctx);
+/// egui::CentralPanel::default().show(ctx, |ui| {
+///     ui.label("Editor");
+/// });
+/// ```
+#[must_use = "You should call .show()"]
+#[derive(Default)]
+pub struct PanelGroup<'a> {
+    /// The priority of each panel, and how to show it.
+    panels: Vec<(i32, ShowPanel<'a>)>,
+}
+
+type ShowPanel<'a> = Box<dyn FnOnce(&CtxRef) + 'a>;
+
+impl<'a> PanelGroup<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a side panel. Panels with a higher `priority` are added first,
+    /// and panels with the same priority in the order given here.
+    pub fn side_panel(
+        mut self,
+        priority: i32,
+        panel: SidePanel,
+        add_contents: impl FnOnce(&mut Ui) + 'a,
+    ) -> Self {
+        self.panels.push((
+            priority,
+            Box::new(move |ctx| {
+                panel.show(ctx, add_contents);
+            }),
+        ));
+        self
+    }
+
+    /// Add a top panel. Panels with a higher `priority` are added first,
+    /// and panels with the same priority in the order given here.
+    pub fn top_panel(
+        mut self,
+        priority: i32,
+        panel: TopPanel,
+        add_contents: impl FnOnce(&mut Ui) + 'a,
+    ) -> Self {
+        self.panels.push((
+            priority,
+            Box::new(move |ctx| {
+                panel.show(ctx, add_contents);
+            }),
+        ));
+        self
+    }
+
+    /// Add all the panels, and return the space that remains for the [`CentralPanel`].
+    pub fn show(self, ctx: &CtxRef) -> Rect {
+        let mut panels = self.panels;
+        panels.sort_by(|(a, _), (b, _)| b.cmp(a)); // stable, so ties keep their order
+        for (_, show_panel) in panels {
+            show_panel(ctx);
+        }
+        ctx.available_rect()
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A panel that covers the remainder of the screen,
 /// i.e. whatever area is left after adding other panels.
 ///
@@ -216,3 +874,29 @@ impl CentralPanel {
         inner_response
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widgets_after_panels_inside_ui() {
+        let mut ui = Ui::__test();
+
+        let left = SidePanel::left("left", 100.0).show_inside(&mut ui, |ui| ui.label("Left"));
+        let label = ui.label("After left");
+        assert_eq!(label.rect.min.x, left.response.rect.max.x);
+
+        let right = SidePanel::right("right", 100.0).show_inside(&mut ui, |ui| ui.label("Right"));
+        let label = ui.label("After right");
+        assert_eq!(label.rect.min.x, left.response.rect.max.x);
+        assert!(ui.max_rect().max.x <= right.response.rect.min.x);
+
+        let mut ui = Ui::__test();
+        ui.horizontal(|ui| {
+            let top = TopPanel::top("top").show_inside(ui, |ui| ui.label("Top"));
+            let label = ui.label("After top");
+            assert!(label.rect.min.y >= top.response.rect.max.y);
+        });
+    }
+}
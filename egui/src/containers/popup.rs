@@ -9,6 +9,11 @@ use crate::*;
 pub(crate) struct MonoState {
     last_id: Option<Id>,
     last_size: Option<Vec2>,
+    /// The [`Tooltip`] whose widget is hovered, and since when.
+    hover_start: Option<(Id, f64)>,
+    /// An interactive [`Tooltip`] that was shown last frame,
+    /// and the area (covering both widget and tooltip) that keeps it open.
+    interactive: Option<(Id, Rect)>,
 }
 
 impl MonoState {
@@ -73,10 +78,23 @@ pub fn show_tooltip_under(ctx: &CtxRef, id: Id, rect: &Rect, add_contents: impl
 
 pub fn show_tooltip_at(
     ctx: &CtxRef,
-    mut id: Id,
+    id: Id,
     suggested_position: Option<Pos2>,
     add_contents: impl FnOnce(&mut Ui),
 ) {
+    let max_width = ctx.style().spacing.tooltip_width;
+    show_tooltip_at_impl(ctx, id, suggested_position, max_width, false, add_contents);
+}
+
+/// Returns the rect of the tooltip, if shown.
+fn show_tooltip_at_impl(
+    ctx: &CtxRef,
+    mut id: Id,
+    suggested_position: Option<Pos2>,
+    max_width: f32,
+    interactive: bool,
+    add_contents: impl FnOnce(&mut Ui),
+) -> Option<Rect> {
     let mut tooltip_rect = Rect::NOTHING;
 
     let position = if let Some((stored_id, stored_tooltip_rect)) = ctx.frame_state().tooltip_rect {
@@ -89,7 +107,7 @@ pub fn show_tooltip_at(
     } else if ctx.memory().everything_is_visible() {
         Pos2::default()
     } else {
-        return; // No good place for a tooltip :(
+        return None; // No good place for a tooltip :(
     };
 
     let expected_size = ctx
@@ -101,13 +119,14 @@ pub fn show_tooltip_at(
     let position = position.min(ctx.input().screen_rect().right_bottom() - expected_size);
     let position = position.max(ctx.input().screen_rect().left_top());
 
-    let response = show_tooltip_area(ctx, id, position, add_contents);
+    let response = show_tooltip_area(ctx, id, position, max_width, interactive, add_contents);
     ctx.memory()
         .data_temp
         .get_mut_or_default::<crate::containers::popup::MonoState>()
         .set_tooltip_size(id, response.rect.size());
 
     ctx.frame_state().tooltip_rect = Some((id, tooltip_rect.union(response.rect)));
+    Some(response.rect)
 }
 
 /// Show some text at the current pointer position (if any).
@@ -133,27 +152,427 @@ fn show_tooltip_area(
     ctx: &CtxRef,
     id: Id,
     window_pos: Pos2,
+    max_width: f32,
+    interactive: bool,
     add_contents: impl FnOnce(&mut Ui),
 ) -> Response {
     use containers::*;
     Area::new(id)
         .order(Order::Tooltip)
         .fixed_pos(window_pos)
-        .interactable(false)
+        .interactable(interactive)
         .show(ctx, |ui| {
             Frame::popup(&ctx.style()).show(ui, |ui| {
-                ui.set_max_width(ui.spacing().tooltip_width);
+                ui.set_max_width(max_width);
                 add_contents(ui);
             });
         })
 }
 
+/// A tooltip with options, shown when a widget is hovered.
+///
+/// [`Response::on_hover_ui`] and [`Response::on_hover_text`] use the default options.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let response = ui.button("Help");
+/// egui::Tooltip::new()
+///     .delay(0.5)
+///     .max_width(200.0)
+///     .interactive(true)
+///     .show(&response, |ui| {
+///         ui.label("See the manual for more.");
+///         ui.hyperlink("https://github.com/emilk/egui");
+///     });
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[must_use = "You should call .show()"]
+pub struct Tooltip {
+    delay: Option<f32>,
+    max_width: Option<f32>,
+    interactive: bool,
+    at_pointer: bool,
+}
+
+impl Tooltip {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How many seconds the widget must be hovered before the tooltip shows.
+    /// Default: [`style::Interaction::tooltip_delay`].
+    pub fn delay(mut self, seconds: f32) -> Self {
+        self.delay = Some(seconds);
+        self
+    }
+
+    /// Wrap the contents at this width. Default: [`style::Spacing::tooltip_width`].
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Keep the tooltip open while the pointer moves onto it,
+    /// so that the user can click links and buttons in it.
+    /// Default: `false`.
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// Show the tooltip next to the pointer instead of under the widget.
+    pub fn at_pointer(mut self) -> Self {
+        self.at_pointer = true;
+        self
+    }
+
+    /// Returns `None` if the tooltip isn't shown.
+    pub fn show<R>(
+        self,
+        response: &Response,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<R> {
+        let Self {
+            delay,
+            max_width,
+            interactive,
+            at_pointer,
+        } = self;
+        let ctx = &response.ctx;
+        let id = response.id.with("__tooltip");
+        let time = ctx.input().time;
+        let pointer_pos = ctx.input().pointer.hover_pos();
+
+        let (hover_start, interactive_rect) = {
+            let mut memory = ctx.memory();
+            let state = memory.data_temp.get_or_default::<MonoState>();
+            (state.hover_start, state.interactive)
+        };
+        let on_tooltip = match (interactive_rect, pointer_pos) {
+            (Some((open_id, rect)), Some(pointer_pos)) => {
+                interactive && open_id == id && rect.contains(pointer_pos)
+            }
+            _ => false,
+        };
+
+        if !on_tooltip && !response.should_show_hover_ui() {
+            let mut memory = ctx.memory();
+            let state = memory.data_temp.get_mut_or_default::<MonoState>();
+            if matches!(state.hover_start, Some((hovered_id, _)) if hovered_id == id) {
+                state.hover_start = None;
+            }
+            if matches!(state.interactive, Some((open_id, _)) if open_id == id) {
+                state.interactive = None;
+            }
+            return None;
+        }
+
+        let hover_start = match hover_start {
+            Some((hovered_id, start)) if hovered_id == id => start,
+            _ => time,
+        };
+        let delay = delay.unwrap_or(ctx.style().interaction.tooltip_delay);
+        let is_shown = on_tooltip
+            || ctx.memory().everything_is_visible()
+            || time - hover_start >= delay as f64;
+
+        let mut inner = None;
+        let mut tooltip_rect = None;
+        if is_shown {
+            let suggested_position = if at_pointer {
+                pointer_pos.map(|pointer_pos| pointer_pos + vec2(16.0, 16.0))
+            } else {
+                Some(response.rect.left_bottom() + vec2(-2.0, 4.0))
+            };
+            let max_width = max_width.unwrap_or(ctx.style().spacing.tooltip_width);
+            tooltip_rect =
+                show_tooltip_at_impl(ctx, id, suggested_position, max_width, interactive, |ui| {
+                    inner = Some(add_contents(ui))
+                });
+        } else {
+            // Wait for the delay to pass:
+            ctx.request_repaint();
+        }
+
+        let mut memory = ctx.memory();
+        let state = memory.data_temp.get_mut_or_default::<MonoState>();
+        state.hover_start = Some((id, hover_start));
+        if interactive {
+            // Also cover the gap between the widget and the tooltip:
+            state.interactive =
+                tooltip_rect.map(|tooltip_rect| (id, tooltip_rect.union(response.rect)));
+        }
+        inner
+    }
+}
+
+/// Where to place a [`Popup`], relative to the widget it belongs to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PopupPlacement {
+    Above,
+    Below,
+    Left,
+    Right,
+    /// Where the pointer was when the popup opened.
+    Cursor,
+}
+
+/// A popup belonging to a widget, e.g. a drop-down list or a color picker.
+///
+/// Only one popup is open at a time. Open it with [`Memory::open_popup`] or [`Memory::toggle_popup`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let response = ui.button("Open popup");
+/// let popup_id = ui.make_persistent_id("my_unique_id");
+/// if response.clicked() {
+///     ui.memory().toggle_popup(popup_id);
+/// }
+/// egui::popup::Popup::new(popup_id)
+///     .placement(egui::popup::PopupPlacement::Right)
+///     .max_size(egui::vec2(200.0, 300.0))
+///     .show(ui, &response, |ui| {
+///         ui.label("Some more info, or things you can select:");
+///         ui.label("…");
+///     });
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Popup {
+    id: Id,
+    placement: PopupPlacement,
+    auto_flip: bool,
+    max_size: Vec2,
+    close_on_escape: bool,
+    close_on_click_outside: bool,
+    match_widget_width: bool,
+    clip_to_parent: bool,
+}
+
+impl Popup {
+    pub fn new(id: Id) -> Self {
+        Self {
+            id,
+            placement: PopupPlacement::Below,
+            auto_flip: true,
+            max_size: Vec2::INFINITY,
+            close_on_escape: true,
+            close_on_click_outside: true,
+            match_widget_width: false,
+            clip_to_parent: false,
+        }
+    }
+
+    /// Where to put the popup. Default: [`PopupPlacement::Below`].
+    pub fn placement(mut self, placement: PopupPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// If the popup doesn't fit on the screen where it is placed,
+    /// put it on the opposite side of the widget (or pointer) if there is more room there.
+    /// Default: `true`.
+    pub fn auto_flip(mut self, auto_flip: bool) -> Self {
+        self.auto_flip = auto_flip;
+        self
+    }
+
+    /// Limit the size of the popup. The contents wrap at the max width,
+    /// and get a scroll bar when higher than the max height.
+    /// Default: no limit.
+    pub fn max_size(mut self, max_size: Vec2) -> Self {
+        self.max_size = max_size;
+        self
+    }
+
+    /// Close the popup when escape is pressed. Default: `true`.
+    pub fn close_on_escape(mut self, close_on_escape: bool) -> Self {
+        self.close_on_escape = close_on_escape;
+        self
+    }
+
+    /// Close the popup on any click outside of the widget, including clicks in the popup itself
+    /// (e.g. selecting an item in a drop-down list). Default: `true`.
+    pub fn close_on_click_outside(mut self, close_on_click_outside: bool) -> Self {
+        self.close_on_click_outside = close_on_click_outside;
+        self
+    }
+
+    /// Make the popup exactly as wide as the widget, like a drop-down list. Default: `false`.
+    pub fn match_widget_width(mut self, match_widget_width: bool) -> Self {
+        self.match_widget_width = match_widget_width;
+        self
+    }
+
+    /// Clip the popup to the clip rect of the widget's `Ui`, e.g. a surrounding [`ScrollArea`].
+    pub(crate) fn clip_to_parent(mut self, clip_to_parent: bool) -> Self {
+        self.clip_to_parent = clip_to_parent;
+        self
+    }
+
+    /// Returns `None` if the popup is closed.
+    pub fn show<R>(
+        self,
+        ui: &Ui,
+        widget_response: &Response,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> Option<InnerResponse<R>> {
+        let Self {
+            id,
+            placement,
+            auto_flip,
+            max_size,
+            close_on_escape,
+            close_on_click_outside,
+            match_widget_width,
+            clip_to_parent,
+        } = self;
+
+        if !ui.memory().is_popup_open(id) {
+            ui.memory().id_data_temp.remove(&id);
+            return None;
+        }
+
+        let anchor_rect = if placement == PopupPlacement::Cursor {
+            let pointer_pos = ui.input().pointer.interact_pos();
+            let mut memory = ui.memory();
+            let pos = match memory
+                .id_data_temp
+                .get::<Pos2>(&id)
+                .copied()
+                .or(pointer_pos)
+            {
+                Some(pos) => pos,
+                None => widget_response.rect.left_bottom(),
+            };
+            memory.id_data_temp.insert(id, pos);
+            Rect::from_min_size(pos, Vec2::ZERO)
+        } else {
+            widget_response.rect
+        };
+
+        // The size of the popup last frame:
+        let area_id = Area::new(id).layer().id;
+        let size = ui
+            .memory()
+            .areas
+            .get(area_id)
+            .map_or(Vec2::ZERO, |state| state.size);
+        let screen = ui.ctx().input().screen_rect();
+        let pos = popup_pos(placement, auto_flip, anchor_rect, size, screen);
+
+        let parent_clip_rect = ui.clip_rect();
+        let mut inner = None;
+        let response = Area::new(id)
+            .order(Order::Foreground)
+            .fixed_pos(pos)
+            .show(ui.ctx(), |ui| {
+                if clip_to_parent {
+                    ui.set_clip_rect(parent_clip_rect); // for when the combo-box is in a scroll area.
+                }
+                let frame = Frame::popup(ui.style());
+                let frame_margin = frame.margin;
+                frame.show(ui, |ui| {
+                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                        if match_widget_width {
+                            ui.set_width(widget_response.rect.width() - 2.0 * frame_margin.x);
+                        }
+                        if max_size.x.is_finite() {
+                            ui.set_max_width(max_size.x - 2.0 * frame_margin.x);
+                        }
+                        inner = Some(if max_size.y.is_finite() {
+                            ScrollArea::from_max_height(max_size.y - 2.0 * frame_margin.y)
+                                .show(ui, add_contents)
+                                .inner
+                        } else {
+                            add_contents(ui)
+                        });
+                    });
+                });
+            });
+
+        let close = close_on_escape && ui.input().key_pressed(Key::Escape)
+            || close_on_click_outside && widget_response.clicked_elsewhere();
+        if close {
+            ui.memory().close_popup();
+        }
+        inner.map(|inner| InnerResponse::new(inner, response))
+    }
+}
+
+/// Where to put a popup of the given size next to `anchor`, flipping it if it doesn't fit.
+fn popup_pos(
+    placement: PopupPlacement,
+    auto_flip: bool,
+    anchor: Rect,
+    size: Vec2,
+    screen: Rect,
+) -> Pos2 {
+    let above = anchor.top() - size.y;
+    let below = anchor.bottom();
+    let left = anchor.left() - size.x;
+    let right = anchor.right();
+
+    // Stay on the preferred side if it fits, or if there is less room on the other side:
+    let flip = |fits: bool, room: f32, other_room: f32| auto_flip && !fits && other_room > room;
+
+    let mut pos = match placement {
+        PopupPlacement::Below => pos2(anchor.left(), below),
+        PopupPlacement::Above => pos2(anchor.left(), above),
+        PopupPlacement::Right => pos2(right, anchor.top()),
+        PopupPlacement::Left => pos2(left, anchor.top()),
+        PopupPlacement::Cursor => anchor.min,
+    };
+    let room_above = anchor.top() - screen.top();
+    let room_below = screen.bottom() - anchor.bottom();
+    let room_left = anchor.left() - screen.left();
+    let room_right = screen.right() - anchor.right();
+    match placement {
+        PopupPlacement::Below | PopupPlacement::Cursor => {
+            if flip(size.y <= room_below, room_below, room_above) {
+                pos.y = above;
+            }
+        }
+        PopupPlacement::Above => {
+            if flip(size.y <= room_above, room_above, room_below) {
+                pos.y = below;
+            }
+        }
+        PopupPlacement::Left | PopupPlacement::Right => {}
+    }
+    match placement {
+        PopupPlacement::Right | PopupPlacement::Cursor => {
+            if flip(size.x <= room_right, room_right, room_left) {
+                pos.x = left;
+            }
+        }
+        PopupPlacement::Left => {
+            if flip(size.x <= room_left, room_left, room_right) {
+                pos.x = right;
+            }
+        }
+        PopupPlacement::Above | PopupPlacement::Below => {}
+    }
+
+    // Keep it on the screen:
+    pos.x = pos
+        .x
+        .at_most(screen.right() - size.x)
+        .at_least(screen.left());
+    pos.y = pos
+        .y
+        .at_most(screen.bottom() - size.y)
+        .at_least(screen.top());
+    pos
+}
+
 /// Shows a popup below another widget.
 ///
 /// Useful for drop-down menus (combo boxes) or suggestion menus under text fields.
 ///
 /// You must open the popup with [`Memory::open_popup`] or  [`Memory::toggle_popup`].
 ///
+/// See [`Popup`] for more placement options.
+///
 /// ```
 /// # let ui = &mut egui::Ui::__test();
 /// let response = ui.button("Open popup");
@@ -173,26 +592,41 @@ pub fn popup_below_widget(
     widget_response: &Response,
     add_contents: impl FnOnce(&mut Ui),
 ) {
-    if ui.memory().is_popup_open(popup_id) {
-        let parent_clip_rect = ui.clip_rect();
+    Popup::new(popup_id)
+        .match_widget_width(true)
+        .clip_to_parent(true)
+        .show(ui, widget_response, add_contents);
+}
 
-        Area::new(popup_id)
-            .order(Order::Foreground)
-            .fixed_pos(widget_response.rect.left_bottom())
-            .show(ui.ctx(), |ui| {
-                ui.set_clip_rect(parent_clip_rect); // for when the combo-box is in a scroll area.
-                let frame = Frame::popup(ui.style());
-                let frame_margin = frame.margin;
-                frame.show(ui, |ui| {
-                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
-                        ui.set_width(widget_response.rect.width() - 2.0 * frame_margin.x);
-                        add_contents(ui)
-                    });
-                });
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn popup_flips_above_at_bottom_of_screen() {
+        let mut ctx = CtxRef::default();
+        let popup_id = Id::new("popup");
+        let run = |ctx: &mut CtxRef| {
+            ctx.begin_frame(RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, vec2(400.0, 400.0))),
+                ..Default::default()
+            });
+            let mut button_rect = Rect::NOTHING;
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add_space(340.0);
+                let response = ui.button("Open");
+                ui.memory().open_popup(popup_id);
+                Popup::new(popup_id).show(ui, &response, |ui| ui.add_space(100.0));
+                button_rect = response.rect;
             });
+            let _ = ctx.end_frame();
+            button_rect
+        };
 
-        if ui.input().key_pressed(Key::Escape) || widget_response.clicked_elsewhere() {
-            ui.memory().close_popup();
-        }
+        run(&mut ctx);
+        let button_rect = run(&mut ctx);
+        let area_id = Area::new(popup_id).layer().id;
+        let popup_rect = ctx.memory().areas.get(area_id).unwrap().rect();
+        assert!(popup_rect.bottom() <= button_rect.top());
     }
 }
@@ -0,0 +1,175 @@
+//! A list whose items can be dragged to reorder them, see [`ReorderableList`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// An item the user dragged to a new place in a [`ReorderableList`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ListMove {
+    /// The index the item was dragged from.
+    pub from: usize,
+    /// The index of the item after the move.
+    pub to: usize,
+}
+
+impl ListMove {
+    /// Move the item in `items`, i.e. the list that was shown.
+    pub fn apply<T>(&self, items: &mut Vec<T>) {
+        let item = items.remove(self.from);
+        items.insert(self.to, item);
+    }
+}
+
+/// Returned by [`ReorderableList::show`].
+pub struct ReorderableListResponse {
+    pub response: Response,
+    /// Set on the frame an item was dropped in a new place.
+    pub moved: Option<ListMove>,
+}
+
+/// A vertical list where items can be picked up with the pointer and dropped somewhere else.
+///
+/// The list doesn't change the items itself, but reports the move, see [`ListMove::apply`].
+/// It is built on [`Draggable`] and [`DropZone`], so only one item is dragged at a time,
+/// also across several lists.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut playlist = vec!["Intro", "Verse", "Chorus"];
+/// let response = egui::ReorderableList::new("playlist").show(ui, &playlist, |ui, song| {
+///     ui.label(*song);
+/// });
+/// if let Some(moved) = response.moved {
+///     moved.apply(&mut playlist);
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct ReorderableList {
+    id_source: Id,
+    auto_scroll: bool,
+}
+
+impl ReorderableList {
+    /// `id_source`: Something unique, e.g. `"playlist"`.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            auto_scroll: true,
+        }
+    }
+
+    /// Scroll the surrounding [`ScrollArea`] when an item is dragged near its edges.
+    /// Default: `true`.
+    pub fn auto_scroll(mut self, auto_scroll: bool) -> Self {
+        self.auto_scroll = auto_scroll;
+        self
+    }
+
+    pub fn show<T>(
+        self,
+        ui: &mut Ui,
+        items: &[T],
+        mut add_item: impl FnMut(&mut Ui, &T),
+    ) -> ReorderableListResponse {
+        let Self {
+            id_source,
+            auto_scroll,
+        } = self;
+
+        let mut dragging = false;
+        let mut dropped_from = None;
+        let zone = ui
+            .vertical(|ui| {
+                DropZone::new(id_source).show(ui, |ui| {
+                    for (index, item) in items.iter().enumerate() {
+                        let item =
+                            Draggable::new(id_source.with(index)).show(ui, |ui| add_item(ui, item));
+                        dragging |= item.is_dragged;
+                        if item.dropped {
+                            dropped_from = Some(index);
+                        }
+                    }
+                })
+            })
+            .inner;
+
+        let moved = match (dropped_from, zone.dropped) {
+            (Some(from), Some((_, to))) if from != to => Some(ListMove { from, to }),
+            _ => None,
+        };
+
+        if dragging && auto_scroll && ui.input().pointer.any_down() {
+            if let Some(pointer_pos) = ui.input().pointer.hover_pos() {
+                scroll_near_edges(ui, pointer_pos);
+            }
+        }
+
+        ReorderableListResponse {
+            response: zone.response,
+            moved,
+        }
+    }
+}
+
+/// Scroll the surrounding [`ScrollArea`] when the pointer is close to its edges.
+fn scroll_near_edges(ui: &Ui, pointer_pos: Pos2) {
+    let clip_rect = ui.clip_rect();
+    let edge = 2.0 * ui.spacing().interact_size.y;
+    let max_speed = 10.0 * edge; // points per second
+
+    let speed = if pointer_pos.y < clip_rect.top() + edge {
+        max_speed * (1.0 - (pointer_pos.y - clip_rect.top()) / edge).at_most(1.0)
+    } else if pointer_pos.y > clip_rect.bottom() - edge {
+        -max_speed * (1.0 - (clip_rect.bottom() - pointer_pos.y) / edge).at_most(1.0)
+    } else {
+        return;
+    };
+
+    let dt = ui.input().unstable_dt.at_most(0.1);
+    ui.ctx().frame_state().scroll_delta.y += speed * dt;
+    ui.ctx().request_repaint();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drag_item_to_end() {
+        let mut ctx = CtxRef::default();
+        let items = ["First", "Second", "Third"];
+        let run = |ctx: &mut CtxRef, events: Vec<Event>| {
+            ctx.begin_frame(RawInput {
+                events,
+                ..Default::default()
+            });
+            let mut response = None;
+            CentralPanel::default().show(ctx, |ui| {
+                response = Some(ReorderableList::new("list").show(ui, &items, |ui, item| {
+                    ui.label(*item);
+                }));
+            });
+            let _ = ctx.end_frame();
+            response.unwrap()
+        };
+        let button = |pos: Pos2, pressed: bool| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        let list = run(&mut ctx, vec![]).response.rect;
+        let first = pos2(list.center().x, list.top() + 2.0);
+        let below_last = pos2(list.center().x, list.bottom() - 2.0);
+        run(
+            &mut ctx,
+            vec![Event::PointerMoved(first), button(first, true)],
+        );
+        run(&mut ctx, vec![Event::PointerMoved(below_last)]);
+        assert!(ctx.memory().dragged_item().is_some());
+        let moved = run(&mut ctx, vec![button(below_last, false)]).moved;
+        assert_eq!(moved, Some(ListMove { from: 0, to: 2 }));
+    }
+}
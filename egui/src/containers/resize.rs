@@ -29,7 +29,7 @@ pub struct Resize {
     pub(crate) min_size: Vec2,
     pub(crate) max_size: Vec2,
 
-    default_size: Vec2,
+    pub(crate) default_size: Vec2,
 
     with_stroke: bool,
 }
@@ -110,6 +110,16 @@ impl Resize {
         self.max_size = max_size.into();
         self
     }
+    /// Won't expand to larger than this
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_size.x = max_width;
+        self
+    }
+    /// Won't expand to larger than this
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_size.y = max_height;
+        self
+    }
 
     /// Can you resize it with the mouse?
     /// Note that a window can still auto-resize
@@ -7,32 +7,51 @@ pub(crate) struct State {
     /// Positive offset means scrolling down/right
     offset: Vec2,
 
-    show_scroll: bool,
+    /// Show the horizontal and vertical scroll bar?
+    show_scroll: [bool; 2],
 
     /// Momentum, used for kinetic scrolling
     #[cfg_attr(feature = "persistence", serde(skip))]
     pub vel: Vec2,
-    /// Mouse offset relative to the top of the handle when started moving the handle.
-    scroll_start_offset_from_top: Option<f32>,
+    /// Is the content being dragged past the start or end, or springing back from that?
+    /// Until it has sprung back, the offset is allowed to be out of bounds.
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    bounce: [bool; 2],
+    /// Mouse offset relative to the top/left of the handle when started moving the handle,
+    /// for the horizontal and vertical scroll bar.
+    scroll_start_offset_from_top_left: [Option<f32>; 2],
 }
 
 impl Default for State {
     fn default() -> Self {
         Self {
             offset: Vec2::ZERO,
-            show_scroll: false,
+            show_scroll: [false; 2],
             vel: Vec2::ZERO,
-            scroll_start_offset_from_top: None,
+            bounce: [false; 2],
+            scroll_start_offset_from_top_left: [None; 2],
         }
     }
 }
 
-// TODO: rename VScroll
-/// Add vertical scrolling to a contained [`Ui`].
+/// Add vertical and/or horizontal scrolling to a contained [`Ui`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// egui::ScrollArea::both().show(ui, |ui| {
+///     for row in 0..100 {
+///         ui.label("A row that is much too wide to fit the screen. ".repeat(10));
+///     }
+/// });
+/// ```
 #[derive(Clone, Debug)]
 #[must_use = "You should call .show()"]
 pub struct ScrollArea {
-    max_height: f32,
+    /// Do we have a horizontal and/or vertical scroll bar?
+    has_bar: [bool; 2],
+    /// Can the mouse wheel scroll horizontally and/or vertically?
+    allow_wheel: [bool; 2],
+    max_size: Vec2,
     always_show_scroll: bool,
     id_source: Option<Id>,
     offset: Option<Vec2>,
@@ -46,33 +65,104 @@ impl ScrollArea {
 
     /// Use `f32::INFINITY` if you want the scroll area to expand to fit the surrounding Ui
     pub fn from_max_height(max_height: f32) -> Self {
+        Self::new([false, true]).max_height(max_height)
+    }
+
+    /// Scroll horizontally, with as much height as the contents need.
+    pub fn horizontal() -> Self {
+        Self::new([true, false])
+    }
+
+    /// Scroll both horizontally and vertically, e.g. for a large image or a spreadsheet.
+    pub fn both() -> Self {
+        Self::new([true, true])
+    }
+
+    fn new(has_bar: [bool; 2]) -> Self {
         Self {
-            max_height,
+            has_bar,
+            allow_wheel: [true; 2],
+            max_size: Vec2::INFINITY,
             always_show_scroll: false,
             id_source: None,
             offset: None,
         }
     }
 
-    /// If `false` (default), the scroll bar will be hidden when not needed/
-    /// If `true`, the scroll bar will always be displayed even if not needed.
+    /// The maximum width of the outer frame of the scroll area.
+    /// Use `f32::INFINITY` (default) if you want the scroll area to expand to fit the surrounding [`Ui`].
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.max_size.x = max_width;
+        self
+    }
+
+    /// The maximum height of the outer frame of the scroll area.
+    /// Use `f32::INFINITY` (default) if you want the scroll area to expand to fit the surrounding [`Ui`].
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.max_size.y = max_height;
+        self
+    }
+
+    /// If `false` (default), the scroll bars will be hidden when not needed/
+    /// If `true`, the scroll bars will always be displayed even if not needed.
     pub fn always_show_scroll(mut self, always_show_scroll: bool) -> Self {
         self.always_show_scroll = always_show_scroll;
         self
     }
 
+    /// Whether the mouse wheel (or touchpad) scrolls this area horizontally. Default: `true`.
+    ///
+    /// The innermost hovered [`ScrollArea`] is scrolled first, and only the scrolling
+    /// that it can't use (e.g. because it has reached its end) goes on to the surrounding one.
+    /// Turn this off to always leave horizontal scrolling to the surrounding area.
+    pub fn allow_wheel_x(mut self, on: bool) -> Self {
+        self.allow_wheel[0] = on;
+        self
+    }
+
+    /// Whether the mouse wheel (or touchpad) scrolls this area vertically. Default: `true`.
+    ///
+    /// See [`Self::allow_wheel_x`].
+    pub fn allow_wheel_y(mut self, on: bool) -> Self {
+        self.allow_wheel[1] = on;
+        self
+    }
+
     /// A source for the unique `Id`, e.g. `.id_source("second_scroll_area")` or `.id_source(loop_index)`.
     pub fn id_source(mut self, id_source: impl std::hash::Hash) -> Self {
         self.id_source = Some(Id::new(id_source));
         self
     }
 
-    /// Set the vertical scroll offset position.
+    /// Set the horizontal and vertical scroll offset position.
+    ///
+    /// Together with [`ScrollAreaOutput::offset`] this can be used to scroll two areas in sync:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut shared_offset = egui::Vec2::ZERO;
+    /// ui.columns(2, |columns| {
+    ///     let left = egui::ScrollArea::auto_sized()
+    ///         .id_source("left")
+    ///         .scroll_offset(shared_offset)
+    ///         .show(&mut columns[0], |ui| ui.label("Old text"));
+    ///     let right = egui::ScrollArea::auto_sized()
+    ///         .id_source("right")
+    ///         .scroll_offset(shared_offset)
+    ///         .show(&mut columns[1], |ui| ui.label("New text"));
+    ///     // Follow the one that the user scrolled:
+    ///     shared_offset = if left.offset != shared_offset {
+    ///         left.offset
+    ///     } else {
+    ///         right.offset
+    ///     };
+    /// });
+    /// ```
     ///
     /// See also: [`Ui::scroll_to_cursor`](crate::ui::Ui::scroll_to_cursor) and
     /// [`Response::scroll_to_me`](crate::Response::scroll_to_me)
-    pub fn scroll_offset(mut self, offset: f32) -> Self {
-        self.offset = Some(Vec2::new(0.0, offset));
+    pub fn scroll_offset(mut self, offset: Vec2) -> Self {
+        self.offset = Some(offset);
         self
     }
 }
@@ -80,7 +170,11 @@ impl ScrollArea {
 struct Prepared {
     id: Id,
     state: State,
-    current_scroll_bar_width: f32,
+    has_bar: [bool; 2],
+    allow_wheel: [bool; 2],
+    /// How much horizontal and vertical space are used up by the
+    /// width of the vertical bar, and the height of the horizontal bar?
+    current_bar_use: Vec2,
     always_show_scroll: bool,
     inner_rect: Rect,
     content_ui: Ui,
@@ -89,7 +183,9 @@ struct Prepared {
 impl ScrollArea {
     fn begin(self, ui: &mut Ui) -> Prepared {
         let Self {
-            max_height,
+            has_bar,
+            allow_wheel,
+            max_size,
             always_show_scroll,
             id_source,
             offset,
@@ -111,107 +207,267 @@ impl ScrollArea {
 
         let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
 
-        let current_scroll_bar_width = if always_show_scroll {
-            max_scroll_bar_width
-        } else {
-            max_scroll_bar_width * ui.ctx().animate_bool(id, state.show_scroll)
-        };
+        let mut current_bar_use = Vec2::ZERO;
+        for d in 0..2 {
+            if has_bar[d] {
+                // The vertical bar (d == 1) uses up horizontal space, and vice versa:
+                current_bar_use[1 - d] = if always_show_scroll {
+                    max_scroll_bar_width
+                } else {
+                    max_scroll_bar_width * ctx.animate_bool(bar_id(id, d), state.show_scroll[d])
+                };
+            }
+        }
 
         let available_outer = ui.available_rect_before_wrap();
 
-        let outer_size = vec2(
-            available_outer.width(),
-            available_outer.height().at_most(max_height),
-        );
+        let outer_size = available_outer.size().min(max_size);
 
-        let inner_size = outer_size - vec2(current_scroll_bar_width, 0.0);
+        let inner_size = outer_size - current_bar_use;
         let inner_rect = Rect::from_min_size(available_outer.min, inner_size);
 
+        let mut content_max_size = inner_size;
+        for d in 0..2 {
+            if has_bar[d] {
+                content_max_size[d] = f32::INFINITY;
+            }
+        }
+
         let mut content_ui = ui.child_ui(
-            Rect::from_min_size(
-                inner_rect.min - state.offset,
-                vec2(inner_size.x, f32::INFINITY),
-            ),
+            Rect::from_min_size(inner_rect.min - state.offset, content_max_size),
             *ui.layout(),
         );
         let mut content_clip_rect = inner_rect.expand(ui.visuals().clip_rect_margin);
         content_clip_rect = content_clip_rect.intersect(ui.clip_rect());
-        content_clip_rect.max.x = ui.clip_rect().max.x - current_scroll_bar_width; // Nice handling of forced resizing beyond the possible
+        for d in 0..2 {
+            if !has_bar[d] {
+                // Nice handling of forced resizing beyond the possible:
+                content_clip_rect.max[d] = ui.clip_rect().max[d] - current_bar_use[d];
+            }
+        }
         content_ui.set_clip_rect(content_clip_rect);
 
         Prepared {
             id,
             state,
-            current_scroll_bar_width,
+            has_bar,
+            allow_wheel,
+            current_bar_use,
             always_show_scroll,
             inner_rect,
             content_ui,
         }
     }
 
-    pub fn show<R>(self, ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> R {
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> ScrollAreaOutput<R> {
+        let mut prepared = self.begin(ui);
+        let inner = add_contents(&mut prepared.content_ui);
+        prepared.end(ui, inner)
+    }
+
+    /// Efficiently show a long list of rows, all `row_height` high (not counting the spacing between them).
+    ///
+    /// Only the rows that are visible are added: `add_contents` is given their range,
+    /// and the rest of the list is replaced by empty space.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let row_height = ui.fonts()[egui::TextStyle::Body].row_height();
+    /// let total_rows = 100_000;
+    /// egui::ScrollArea::auto_sized().show_rows(ui, row_height, total_rows, |ui, row_range| {
+    ///     for row in row_range {
+    ///         ui.label(format!("Row {}/{}", row + 1, total_rows));
+    ///     }
+    /// });
+    /// ```
+    pub fn show_rows<R>(
+        self,
+        ui: &mut Ui,
+        row_height: f32,
+        total_rows: usize,
+        add_contents: impl FnOnce(&mut Ui, std::ops::Range<usize>) -> R,
+    ) -> ScrollAreaOutput<R> {
         let mut prepared = self.begin(ui);
-        let ret = add_contents(&mut prepared.content_ui);
-        prepared.end(ui);
-        ret
+
+        let spacing = ui.spacing().item_spacing.y;
+        let row_height_with_spacing = row_height + spacing;
+
+        // The visible part of the contents, relative to the top of the contents:
+        let visible_top = prepared.state.offset.y;
+        let visible_bottom = visible_top + prepared.inner_rect.height();
+
+        let min_row = (visible_top / row_height_with_spacing)
+            .floor()
+            .at_least(0.0)
+            .at_most(total_rows as f32) as usize;
+        let max_row = ((visible_bottom / row_height_with_spacing).ceil() + 1.0)
+            .at_most(total_rows as f32) as usize;
+
+        let content_ui = &mut prepared.content_ui;
+        content_ui
+            .set_min_height((row_height_with_spacing * total_rows as f32 - spacing).at_least(0.0));
+        let top = content_ui.max_rect().top();
+        let rows_rect = Rect::from_x_y_ranges(
+            content_ui.max_rect().x_range(),
+            top + min_row as f32 * row_height_with_spacing
+                ..=top + max_row as f32 * row_height_with_spacing,
+        );
+        let inner = content_ui
+            .allocate_ui_at_rect(rows_rect, |ui| add_contents(ui, min_row..max_row))
+            .inner;
+
+        prepared.end(ui, inner)
+    }
+}
+
+/// What [`ScrollArea::show`] returns.
+pub struct ScrollAreaOutput<R> {
+    /// What the contents returned.
+    pub inner: R,
+    /// The current scroll offset, which can be given to [`ScrollArea::scroll_offset`].
+    pub offset: Vec2,
+    /// The size of the contents, which may be larger than the scroll area.
+    pub content_size: Vec2,
+    /// Where the contents are shown, not including the scroll bars.
+    pub inner_rect: Rect,
+}
+
+impl<R> ScrollAreaOutput<R> {
+    /// Has the user scrolled to within `distance` points of the bottom of the contents?
+    ///
+    /// Check this every frame after showing the scroll area. This is a check rather than a callback,
+    /// since the contents and the code that loads more of them usually borrow the same list.
+    ///
+    /// This is also `true` if all the contents fit, so you can use it to load more items
+    /// until the scroll area is full, and then whenever the user scrolls down to the end:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// let mut messages: Vec<String> = vec![];
+    /// let output = egui::ScrollArea::from_max_height(200.0).show(ui, |ui| {
+    ///     for message in &messages {
+    ///         ui.label(message);
+    ///     }
+    /// });
+    /// if output.is_near_end(100.0) {
+    ///     // Fetch older messages:
+    ///     let start = messages.len();
+    ///     messages.extend((start..start + 20).map(|i| format!("Message {}", i)));
+    /// }
+    /// ```
+    pub fn is_near_end(&self, distance: f32) -> bool {
+        let distance_to_end = self.content_size.y - (self.offset.y + self.inner_rect.height());
+        distance_to_end <= distance
     }
 }
 
 impl Prepared {
-    fn end(self, ui: &mut Ui) {
+    fn end<R>(self, ui: &mut Ui, inner: R) -> ScrollAreaOutput<R> {
         let Prepared {
             id,
             mut state,
             inner_rect,
+            has_bar,
+            allow_wheel,
             always_show_scroll,
-            mut current_scroll_bar_width,
+            mut current_bar_use,
             content_ui,
         } = self;
 
         let content_size = content_ui.min_size();
 
-        // We take the scroll target so only this ScrollArea will use it.
-        let scroll_target = content_ui.ctx().frame_state().scroll_target.take();
-        if let Some((scroll_y, align)) = scroll_target {
-            let center_factor = align.to_factor();
+        for (d, &has_bar) in has_bar.iter().enumerate() {
+            if !has_bar {
+                continue;
+            }
+            // We take the scroll target so only this ScrollArea will use it.
+            let scroll_target = content_ui.ctx().frame_state().scroll_target[d].take();
+            if let Some((scroll_target, align)) = scroll_target {
+                let center_factor = align.to_factor();
 
-            let top = content_ui.min_rect().top();
-            let visible_range = top..=top + content_ui.clip_rect().height();
-            let offset_y = scroll_y - lerp(visible_range, center_factor);
+                let min = content_ui.min_rect().min[d];
+                let visible_range = min..=min + content_ui.clip_rect().size()[d];
+                let offset = scroll_target - lerp(visible_range, center_factor);
 
-            let mut spacing = ui.spacing().item_spacing.y;
+                let mut spacing = ui.spacing().item_spacing[d];
 
-            // Depending on the alignment we need to add or subtract the spacing
-            spacing *= remap(center_factor, 0.0..=1.0, -1.0..=1.0);
+                // Depending on the alignment we need to add or subtract the spacing
+                spacing *= remap(center_factor, 0.0..=1.0, -1.0..=1.0);
 
-            state.offset.y = offset_y + spacing;
+                state.offset[d] = offset + spacing;
+            }
         }
 
-        let width = if inner_rect.width().is_finite() {
-            inner_rect.width().max(content_size.x) // Expand width to fit content
-        } else {
-            // ScrollArea is in an infinitely wide parent
-            content_size.x
-        };
+        let mut inner_size = inner_rect.size();
+        for d in 0..2 {
+            if !has_bar[d] {
+                inner_size[d] = if inner_size[d].is_finite() {
+                    inner_size[d].max(content_size[d]) // Expand to fit content
+                } else {
+                    // ScrollArea is in an infinitely large parent
+                    content_size[d]
+                };
+            }
+        }
+        let inner_rect = Rect::from_min_size(inner_rect.min, inner_size);
 
-        let inner_rect = Rect::from_min_size(inner_rect.min, vec2(width, inner_rect.height()));
+        let outer_rect = Rect::from_min_size(inner_rect.min, inner_rect.size() + current_bar_use);
 
-        let outer_rect = Rect::from_min_size(
-            inner_rect.min,
-            inner_rect.size() + vec2(current_scroll_bar_width, 0.0),
-        );
+        let content_is_too_large = [
+            has_bar[0] && content_size.x > inner_rect.width(),
+            has_bar[1] && content_size.y > inner_rect.height(),
+        ];
 
-        let content_is_too_small = content_size.y > inner_rect.height();
+        let max_offset = content_size - inner_rect.size();
 
-        if content_is_too_small {
+        if content_is_too_large[0] || content_is_too_large[1] {
             // Drag contents to scroll (for touch screens mostly):
             let content_response = ui.interact(inner_rect, id.with("area"), Sense::drag());
 
             let input = ui.input();
             if content_response.dragged() {
-                state.offset.y -= input.pointer.delta().y;
-                state.vel = input.pointer.velocity();
+                for (d, &too_large) in content_is_too_large.iter().enumerate() {
+                    if too_large {
+                        let mut delta = input.pointer.delta()[d];
+                        if state.offset[d] < 0.0 || max_offset[d] < state.offset[d] {
+                            delta *= 0.5; // Resist dragging past the start or end
+                        }
+                        let max_overscroll = 0.25 * inner_rect.size()[d];
+                        state.offset[d] = (state.offset[d] - delta)
+                            .at_least(-max_overscroll)
+                            .at_most(max_offset[d] + max_overscroll);
+                        state.vel[d] = input.pointer.velocity()[d];
+                        state.bounce[d] = true;
+                    } else {
+                        state.vel[d] = 0.0;
+                    }
+                }
             } else {
+                // Spring back after having been dragged past the start or end:
+                let t = if ui.style().animations {
+                    (input.unstable_dt / ui.style().animation_time).at_most(1.0)
+                } else {
+                    1.0
+                };
+                for d in 0..2 {
+                    if !state.bounce[d] {
+                        continue;
+                    }
+                    let target = state.offset[d].at_most(max_offset[d]).at_least(0.0);
+                    if (state.offset[d] - target).abs() < 0.5 {
+                        state.offset[d] = target;
+                        state.bounce[d] = false;
+                    } else {
+                        state.offset[d] = lerp(state.offset[d]..=target, t);
+                        state.vel[d] = 0.0;
+                        ui.ctx().request_repaint();
+                    }
+                }
+
                 let stop_speed = 20.0; // Pixels per second.
                 let friction_coeff = 1000.0; // Pixels per second squared.
                 let dt = input.unstable_dt;
@@ -223,101 +479,136 @@ impl Prepared {
                     state.vel -= friction * state.vel.normalized();
                     // Offset has an inverted coordinate system compared to
                     // the velocity, so we subtract it instead of adding it
-                    state.offset.y -= state.vel.y * dt;
+                    state.offset -= state.vel * dt;
                     ui.ctx().request_repaint();
                 }
             }
         }
 
-        let max_offset = content_size.y - inner_rect.height();
         if ui.rect_contains_pointer(outer_rect) {
             let mut frame_state = ui.ctx().frame_state();
-            let scroll_delta = frame_state.scroll_delta;
-
-            let scrolling_up = state.offset.y > 0.0 && scroll_delta.y > 0.0;
-            let scrolling_down = state.offset.y < max_offset && scroll_delta.y < 0.0;
+            for d in 0..2 {
+                if has_bar[d] && allow_wheel[d] {
+                    let scroll_delta = frame_state.scroll_delta[d];
+
+                    let scrolling_up = state.offset[d] > 0.0 && scroll_delta > 0.0;
+                    let scrolling_down = state.offset[d] < max_offset[d] && scroll_delta < 0.0;
+
+                    if scrolling_up || scrolling_down {
+                        let new_offset = (state.offset[d] - scroll_delta)
+                            .at_most(max_offset[d])
+                            .at_least(0.0);
+                        // Leave what we couldn't use for the parent scroll:
+                        frame_state.scroll_delta[d] -= state.offset[d] - new_offset;
+                        state.offset[d] = new_offset;
+                        state.bounce[d] = false;
+                    }
+                }
+            }
+        }
 
-            if scrolling_up || scrolling_down {
-                state.offset.y -= scroll_delta.y;
-                // Clear scroll delta so no parent scroll will use it.
-                frame_state.scroll_delta = Vec2::ZERO;
+        for d in 0..2 {
+            if !content_is_too_large[d] {
+                state.bounce[d] = false;
+            }
+            if !state.bounce[d] {
+                // Stop at the start and end, e.g. when flinging the contents:
+                let unbounded_offset = state.offset[d];
+                state.offset[d] = state.offset[d].at_most(max_offset[d]).at_least(0.0);
+                if state.offset[d] != unbounded_offset {
+                    state.vel[d] = 0.0;
+                }
             }
         }
 
-        let show_scroll_this_frame = content_is_too_small || always_show_scroll;
+        let mut show_scroll_this_frame = [false; 2];
+        for d in 0..2 {
+            show_scroll_this_frame[d] =
+                has_bar[d] && (content_is_too_large[d] || always_show_scroll);
+        }
 
         let max_scroll_bar_width = max_scroll_bar_width_with_margin(ui);
 
-        if show_scroll_this_frame && current_scroll_bar_width <= 0.0 {
-            // Avoid frame delay; start showing scroll bar right away:
-            current_scroll_bar_width = max_scroll_bar_width * ui.ctx().animate_bool(id, true);
+        for d in 0..2 {
+            if show_scroll_this_frame[d] && current_bar_use[1 - d] <= 0.0 {
+                // Avoid frame delay; start showing scroll bar right away:
+                current_bar_use[1 - d] =
+                    max_scroll_bar_width * ui.ctx().animate_bool(bar_id(id, d), true);
+            }
         }
 
-        if current_scroll_bar_width > 0.0 {
-            let animation_t = current_scroll_bar_width / max_scroll_bar_width;
+        for d in 0..2 {
+            // The vertical bar (d == 1) is as wide as the horizontal space it uses up:
+            let animation_t = current_bar_use[1 - d] / max_scroll_bar_width;
+            if animation_t <= 0.0 {
+                continue;
+            }
+
             // margin between contents and scroll bar
             let margin = animation_t * ui.spacing().item_spacing.x;
-            let left = inner_rect.right() + margin;
-            let right = outer_rect.right();
-            let corner_radius = (right - left) / 2.0;
-            let top = inner_rect.top();
-            let bottom = inner_rect.bottom();
-
-            let outer_scroll_rect = Rect::from_min_max(
-                pos2(left, inner_rect.top()),
-                pos2(right, inner_rect.bottom()),
-            );
+            // Along the bar (`main`), and across the bar (`cross`).
+            // For the vertical bar, `main` is top/bottom and `cross` is left/right.
+            let min_cross = inner_rect.max[1 - d] + margin;
+            let max_cross = outer_rect.max[1 - d];
+            let min_main = inner_rect.min[d];
+            let max_main = inner_rect.max[d];
+            let corner_radius = (max_cross - min_cross) / 2.0;
+
+            let bar_rect = |min_main: f32, max_main: f32| {
+                if d == 0 {
+                    Rect::from_min_max(pos2(min_main, min_cross), pos2(max_main, max_cross))
+                } else {
+                    Rect::from_min_max(pos2(min_cross, min_main), pos2(max_cross, max_main))
+                }
+            };
+
+            let outer_scroll_rect = bar_rect(min_main, max_main);
 
             let from_content =
-                |content_y| remap_clamp(content_y, 0.0..=content_size.y, top..=bottom);
+                |content| remap_clamp(content, 0.0..=content_size[d], min_main..=max_main);
 
-            let handle_rect = Rect::from_min_max(
-                pos2(left, from_content(state.offset.y)),
-                pos2(right, from_content(state.offset.y + inner_rect.height())),
+            let handle_rect = bar_rect(
+                from_content(state.offset[d]),
+                from_content(state.offset[d] + inner_rect.size()[d]),
             );
 
-            let interact_id = id.with("vertical");
+            let interact_id = bar_id(id, d);
             let response = ui.interact(outer_scroll_rect, interact_id, Sense::click_and_drag());
 
             if let Some(pointer_pos) = response.interact_pointer_pos() {
-                let scroll_start_offset_from_top =
-                    state.scroll_start_offset_from_top.get_or_insert_with(|| {
+                let scroll_start_offset_from_top_left = state.scroll_start_offset_from_top_left[d]
+                    .get_or_insert_with(|| {
                         if handle_rect.contains(pointer_pos) {
-                            pointer_pos.y - handle_rect.top()
+                            pointer_pos[d] - handle_rect.min[d]
                         } else {
-                            let handle_top_pos_at_bottom = bottom - handle_rect.height();
+                            let handle_top_pos_at_bottom = max_main - handle_rect.size()[d];
                             // Calculate the new handle top position, centering the handle on the mouse.
-                            let new_handle_top_pos = (pointer_pos.y - handle_rect.height() / 2.0)
-                                .clamp(top, handle_top_pos_at_bottom);
-                            pointer_pos.y - new_handle_top_pos
+                            let new_handle_top_pos = (pointer_pos[d] - handle_rect.size()[d] / 2.0)
+                                .clamp(min_main, handle_top_pos_at_bottom);
+                            pointer_pos[d] - new_handle_top_pos
                         }
                     });
 
-                let new_handle_top = pointer_pos.y - *scroll_start_offset_from_top;
-                state.offset.y = remap(new_handle_top, top..=bottom, 0.0..=content_size.y);
+                let new_handle_top = pointer_pos[d] - *scroll_start_offset_from_top_left;
+                state.offset[d] = remap(new_handle_top, min_main..=max_main, 0.0..=content_size[d])
+                    .at_most(max_offset[d])
+                    .at_least(0.0);
+                state.vel[d] = 0.0;
+                state.bounce[d] = false;
             } else {
-                state.scroll_start_offset_from_top = None;
-            }
-
-            let unbounded_offset_y = state.offset.y;
-            state.offset.y = state.offset.y.max(0.0);
-            state.offset.y = state.offset.y.min(max_offset);
-
-            if state.offset.y != unbounded_offset_y {
-                state.vel = Vec2::ZERO;
+                state.scroll_start_offset_from_top_left[d] = None;
             }
 
             // Avoid frame-delay by calculating a new handle rect:
-            let mut handle_rect = Rect::from_min_max(
-                pos2(left, from_content(state.offset.y)),
-                pos2(right, from_content(state.offset.y + inner_rect.height())),
+            let mut handle_rect = bar_rect(
+                from_content(state.offset[d]),
+                from_content(state.offset[d] + inner_rect.size()[d]),
             );
-            let min_handle_height = (2.0 * corner_radius).max(8.0);
-            if handle_rect.size().y < min_handle_height {
-                handle_rect = Rect::from_center_size(
-                    handle_rect.center(),
-                    vec2(handle_rect.size().x, min_handle_height),
-                );
+            let min_handle_size = (2.0 * corner_radius).max(8.0);
+            if handle_rect.size()[d] < min_handle_size {
+                let mut size = handle_rect.size();
+                size[d] = min_handle_size;
+                handle_rect = Rect::from_center_size(handle_rect.center(), size);
             }
 
             let visuals = ui.style().interact(&response);
@@ -339,21 +630,44 @@ impl Prepared {
             });
         }
 
-        let size = vec2(
-            outer_rect.size().x,
-            outer_rect.size().y.min(content_size.y), // shrink if content is so small that we don't need scroll bars
-        );
+        // shrink if content is so small that we don't need scroll bars
+        let mut size = outer_rect.size();
+        for d in 0..2 {
+            if has_bar[d] {
+                size[d] = size[d].min(content_size[d] + current_bar_use[d]);
+            }
+        }
         ui.advance_cursor_after_rect(Rect::from_min_size(outer_rect.min, size));
 
         if show_scroll_this_frame != state.show_scroll {
             ui.ctx().request_repaint();
         }
 
-        state.offset.y = state.offset.y.min(content_size.y - inner_rect.height());
-        state.offset.y = state.offset.y.max(0.0);
+        for d in 0..2 {
+            if !state.bounce[d] {
+                state.offset[d] = state.offset[d].min(content_size[d] - inner_rect.size()[d]);
+                state.offset[d] = state.offset[d].max(0.0);
+            }
+        }
         state.show_scroll = show_scroll_this_frame;
 
         ui.memory().id_data.insert(id, state);
+
+        ScrollAreaOutput {
+            inner,
+            offset: state.offset,
+            content_size,
+            inner_rect,
+        }
+    }
+}
+
+/// The id of the horizontal (`d == 0`) or vertical (`d == 1`) scroll bar.
+fn bar_id(id: Id, d: usize) -> Id {
+    if d == 0 {
+        id.with("horizontal")
+    } else {
+        id.with("vertical")
     }
 }
 
@@ -0,0 +1,140 @@
+//! A button with a drop-down of alternative actions, see [`SplitButton`].
+
+use std::hash::Hash;
+
+use crate::*;
+
+/// A button with two parts: the main part does the default action,
+/// and the arrow next to it opens a drop-down with alternative actions.
+///
+/// The response of [`Self::show`] is that of the main part,
+/// so `clicked()` means the default action should be done.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let response = egui::SplitButton::new("save", "Save").show(ui, |ui| {
+///     if ui.button("Save As…").clicked() {
+///         // save as…
+///     }
+///     if ui.button("Save All").clicked() {
+///         // save all…
+///     }
+/// });
+/// if response.response.clicked() {
+///     // save…
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct SplitButton {
+    id_source: Id,
+    text: String,
+}
+
+impl SplitButton {
+    /// `id_source`: Something unique, e.g. `"save"`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(id_source: impl Hash, text: impl ToString) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            text: text.to_string(),
+        }
+    }
+
+    /// Show the button, and the drop-down if it is open.
+    ///
+    /// The drop-down closes when you click anywhere, including on one of its items.
+    /// The inner value is `None` while the drop-down is closed.
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut Ui) -> R,
+    ) -> InnerResponse<Option<R>> {
+        let Self { id_source, text } = self;
+        let id = ui.make_persistent_id(id_source);
+        let popup_id = id.with("popup");
+
+        let button_padding = ui.spacing().button_padding;
+        let galley = ui.fonts().layout_no_wrap(TextStyle::Button, text);
+        let arrow_width = ui.spacing().icon_width;
+        let main_size = galley.size + 2.0 * button_padding;
+        let arrow_size = vec2(arrow_width, 0.0) + 2.0 * button_padding;
+        let desired_size =
+            vec2(main_size.x + arrow_size.x, main_size.y).at_least(ui.spacing().interact_size);
+
+        let (rect, _) = ui.allocate_exact_size(desired_size, Sense::hover());
+        let arrow_rect =
+            Rect::from_min_max(pos2(rect.right() - arrow_size.x, rect.top()), rect.max);
+        let main_rect = Rect::from_min_max(rect.min, pos2(arrow_rect.left(), rect.bottom()));
+
+        let main_response = ui.interact(main_rect, id, Sense::click());
+        main_response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, &galley.text));
+        let mut arrow_response = ui.interact(arrow_rect, id.with("arrow"), Sense::click());
+        if arrow_response.clicked() {
+            ui.memory().toggle_popup(popup_id);
+        } else if main_response.clicked() && ui.memory().is_popup_open(popup_id) {
+            ui.memory().close_popup();
+        }
+        let popup_open = ui.memory().is_popup_open(popup_id);
+        arrow_response.is_pointer_button_down_on |= popup_open;
+
+        if ui.clip_rect().intersects(rect) {
+            // The frame looks hovered while either part is, so the two parts read as one button,
+            // and the part under the pointer (or the open arrow) gets its own fill.
+            let frame_visuals = if main_response.hovered() || arrow_response.hovered() {
+                &ui.visuals().widgets.hovered
+            } else {
+                ui.style().interact(&main_response)
+            };
+            let main_visuals = ui.style().interact(&main_response);
+            let arrow_visuals = ui.style().interact(&arrow_response);
+
+            let painter = ui.painter();
+            painter.rect(
+                rect.expand(frame_visuals.expansion),
+                frame_visuals.corner_radius,
+                frame_visuals.bg_fill,
+                frame_visuals.bg_stroke,
+            );
+            for (segment_rect, visuals) in
+                [(main_rect, main_visuals), (arrow_rect, arrow_visuals)].iter()
+            {
+                if visuals.bg_fill != frame_visuals.bg_fill {
+                    painter.rect_filled(
+                        segment_rect.shrink(frame_visuals.bg_stroke.width),
+                        visuals.corner_radius,
+                        visuals.bg_fill,
+                    );
+                }
+            }
+            painter.line_segment(
+                [
+                    arrow_rect.left_top() + vec2(0.0, button_padding.y),
+                    arrow_rect.left_bottom() - vec2(0.0, button_padding.y),
+                ],
+                frame_visuals.bg_stroke,
+            );
+
+            let text_pos = Align2::CENTER_CENTER
+                .align_size_within_rect(galley.size, main_rect)
+                .min;
+            let text_color = ui
+                .visuals()
+                .override_text_color
+                .unwrap_or_else(|| main_visuals.text_color());
+            painter.galley(text_pos, galley, text_color);
+
+            let icon_rect = Rect::from_center_size(arrow_rect.center(), Vec2::splat(arrow_width));
+            super::combo_box::paint_icon(painter, icon_rect.shrink(4.0), arrow_visuals);
+        }
+
+        let button_response = main_response.union(arrow_response);
+        let inner = Popup::new(popup_id)
+            .show(ui, &button_response, |ui| {
+                ui.set_min_width(rect.width());
+                add_contents(ui)
+            })
+            .map(|inner| inner.inner);
+
+        InnerResponse::new(inner, main_response)
+    }
+}
@@ -0,0 +1,210 @@
+//! A bar of buttons along the top of the screen, see [`Toolbar`].
+
+use crate::*;
+
+enum ToolbarItem<'a> {
+    Button {
+        icon: String,
+        text: String,
+        on_click: Box<dyn FnOnce() + 'a>,
+    },
+    Toggle {
+        icon: String,
+        text: String,
+        value: &'a mut bool,
+    },
+    Separator,
+}
+
+/// A [`TopPanel`] with a row of icon buttons and toggles.
+///
+/// Items that don't fit are moved into an overflow menu at the end of the toolbar,
+/// where they are shown together with their text.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// let mut saved = false;
+/// let mut show_grid = true;
+/// egui::Toolbar::new("toolbar")
+///     .button("💾", "Save", || saved = true)
+///     .separator()
+///     .toggle("#", "Show grid", &mut show_grid)
+///     .show(ctx);
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Toolbar<'a> {
+    id: Id,
+    panel: TopPanel,
+    items: Vec<ToolbarItem<'a>>,
+}
+
+impl<'a> Toolbar<'a> {
+    /// `id_source`: Something unique, e.g. `"my_toolbar"`.
+    pub fn new(id_source: impl std::hash::Hash) -> Self {
+        let id = Id::new(id_source);
+        Self {
+            id,
+            panel: TopPanel::top(id.with("panel")),
+            items: Vec::new(),
+        }
+    }
+
+    /// Change the panel the toolbar is shown in, e.g. to change its frame.
+    pub fn panel(mut self, panel: TopPanel) -> Self {
+        self.panel = panel;
+        self
+    }
+
+    /// A button showing `icon`, with `text` shown on hover and in the overflow menu.
+    /// `on_click` is called when the button is clicked.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn button(
+        mut self,
+        icon: impl ToString,
+        text: impl ToString,
+        on_click: impl FnOnce() + 'a,
+    ) -> Self {
+        self.items.push(ToolbarItem::Button {
+            icon: icon.to_string(),
+            text: text.to_string(),
+            on_click: Box::new(on_click),
+        });
+        self
+    }
+
+    /// A button showing `icon` that toggles `value` when clicked,
+    /// with `text` shown on hover and in the overflow menu.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn toggle(mut self, icon: impl ToString, text: impl ToString, value: &'a mut bool) -> Self {
+        self.items.push(ToolbarItem::Toggle {
+            icon: icon.to_string(),
+            text: text.to_string(),
+            value,
+        });
+        self
+    }
+
+    /// A line between groups of items.
+    pub fn separator(mut self) -> Self {
+        self.items.push(ToolbarItem::Separator);
+        self
+    }
+
+    /// Show the toolbar along the top of the screen.
+    pub fn show(self, ctx: &CtxRef) -> Response {
+        let Self { id, panel, items } = self;
+        panel
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| items_ui(ui, id, items));
+            })
+            .response
+    }
+
+    /// Show the toolbar along the top of the given [`Ui`], see [`TopPanel::show_inside`].
+    pub fn show_inside(self, ui: &mut Ui) -> Response {
+        let Self { id, panel, items } = self;
+        panel
+            .show_inside(ui, |ui| {
+                ui.horizontal(|ui| items_ui(ui, id, items));
+            })
+            .response
+    }
+}
+
+/// The width of an item in the toolbar, including the spacing before the next item.
+fn item_width(ui: &Ui, item: &ToolbarItem<'_>) -> f32 {
+    match item {
+        ToolbarItem::Button { icon, .. } | ToolbarItem::Toggle { icon, .. } => {
+            button_width(ui, icon)
+        }
+        ToolbarItem::Separator => Separator::DEFAULT_SPACING + ui.spacing().item_spacing.x,
+    }
+}
+
+/// The width of a button with the given text, including the spacing before the next item.
+fn button_width(ui: &Ui, text: &str) -> f32 {
+    let text_style = ui.style().override_text_style.unwrap_or(TextStyle::Button);
+    let galley = ui.fonts().layout_no_wrap(text_style, text.to_owned());
+    galley.size.x + 2.0 * ui.spacing().button_padding.x + ui.spacing().item_spacing.x
+}
+
+fn items_ui(ui: &mut Ui, id: Id, items: Vec<ToolbarItem<'_>>) {
+    let overflow_icon = "⏷";
+    let available_width = ui.available_width();
+    let total_width: f32 = items.iter().map(|item| item_width(ui, item)).sum();
+
+    // Leave room for the overflow button if not everything fits.
+    let mut shown = items.len();
+    if total_width > available_width {
+        let mut width = button_width(ui, overflow_icon);
+        shown = items
+            .iter()
+            .take_while(|item| {
+                width += item_width(ui, item);
+                width <= available_width
+            })
+            .count();
+    }
+
+    let mut items = items.into_iter();
+    for item in items.by_ref().take(shown) {
+        match item {
+            ToolbarItem::Button {
+                icon,
+                text,
+                on_click,
+            } => {
+                if ui.button(icon).on_hover_text(text).clicked() {
+                    on_click();
+                }
+            }
+            ToolbarItem::Toggle { icon, text, value } => {
+                if ui
+                    .selectable_label(*value, icon)
+                    .on_hover_text(text)
+                    .clicked()
+                {
+                    *value = !*value;
+                }
+            }
+            ToolbarItem::Separator => {
+                ui.separator();
+            }
+        }
+    }
+
+    let overflow: Vec<_> = items.collect();
+    if overflow.is_empty() {
+        return;
+    }
+
+    let popup_id = id.with("overflow");
+    let button_response = ui.button(overflow_icon).on_hover_text("More");
+    if button_response.clicked() {
+        ui.memory().toggle_popup(popup_id);
+    }
+    // Clicking an item is a click outside of the overflow button, which closes the popup:
+    popup::Popup::new(popup_id).show(ui, &button_response, |ui| {
+        for item in overflow {
+            match item {
+                ToolbarItem::Button {
+                    icon,
+                    text,
+                    on_click,
+                } => {
+                    if ui.button(format!("{} {}", icon, text)).clicked() {
+                        on_click();
+                    }
+                }
+                ToolbarItem::Toggle { icon, text, value } => {
+                    ui.checkbox(value, format!("{} {}", icon, text));
+                }
+                ToolbarItem::Separator => {
+                    ui.separator();
+                }
+            }
+        }
+    });
+}
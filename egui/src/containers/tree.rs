@@ -0,0 +1,520 @@
+//! A hierarchical list of selectable nodes, see [`Tree`].
+
+use std::hash::Hash;
+
+use crate::{containers::collapsing_header::State as OpenState, *};
+
+/// Where a dragged node was dropped, relative to [`TreeMove::target`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DropPosition {
+    /// Just above the target, with the same parent.
+    Before,
+    /// Just below the target, with the same parent.
+    After,
+    /// As the last child of the target directory.
+    Inside,
+}
+
+/// A node the user dragged somewhere else in a [`Tree`].
+///
+/// The tree doesn't own your data, so it is up to you to move the node.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreeMove {
+    /// The node that was dragged.
+    pub node: Id,
+    /// The node it was dropped on.
+    pub target: Id,
+    pub position: DropPosition,
+}
+
+/// Returned by [`Tree::show`].
+pub struct TreeResponse<R> {
+    /// What the contents closure returned.
+    pub inner: R,
+    /// The response of the whole tree. [`Response::changed`] is true if the selection changed.
+    pub response: Response,
+    /// The selected nodes, in the order they were selected.
+    pub selected: Vec<Id>,
+    /// Set on the frame a node was dropped after dragging (see [`Tree::reorderable`]).
+    pub moved: Option<TreeMove>,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+struct State {
+    selected: Vec<Id>,
+    /// Where a shift-selection starts.
+    anchor: Option<Id>,
+    /// The node moved with the arrow keys.
+    cursor: Option<Id>,
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    dragged: Option<Id>,
+}
+
+/// A visible row, in the order they were shown.
+struct Row {
+    node: Id,
+    parent: Option<Id>,
+    is_dir: bool,
+    rect: Rect,
+}
+
+struct Shared {
+    tree_id: Id,
+    state: State,
+    multi_select: bool,
+    reorderable: bool,
+    indent_guides: bool,
+    left: f32,
+    rows: Vec<Row>,
+    clicked: Option<(Id, Modifiers)>,
+    drop: Option<(Id, DropPosition)>,
+}
+
+/// A tree of directories and leaves, with selection, keyboard navigation
+/// and optional drag-to-reorder.
+///
+/// Click a node to select it. With [`Self::multi_select`], ctrl/cmd-click toggles
+/// a node and shift-click selects a range.
+/// When the tree has keyboard focus, the up and down arrows move the selection,
+/// right expands a directory (or moves into it) and left collapses it (or moves to its parent).
+///
+/// Every node needs an `id_source` that is unique within the tree.
+/// The selection is reported as the [`Id`]s of those, i.e. `Id::new(id_source)`.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let response = egui::Tree::new("files")
+///     .multi_select(true)
+///     .show(ui, |tree| {
+///         tree.dir("src", "src", |tree| {
+///             tree.leaf("lib.rs", "lib.rs");
+///             tree.leaf("main.rs", "main.rs");
+///         });
+///         tree.leaf("Cargo.toml", "Cargo.toml");
+///     });
+/// if response.selected.contains(&egui::Id::new("lib.rs")) {
+///     // …
+/// }
+/// ```
+#[must_use = "You should call .show()"]
+pub struct Tree {
+    id_source: Id,
+    multi_select: bool,
+    reorderable: bool,
+    indent_guides: bool,
+}
+
+impl Tree {
+    /// `id_source`: Something unique, e.g. `"file_tree"`.
+    pub fn new(id_source: impl Hash) -> Self {
+        Self {
+            id_source: Id::new(id_source),
+            multi_select: false,
+            reorderable: false,
+            indent_guides: true,
+        }
+    }
+
+    /// Allow selecting more than one node with ctrl/cmd and shift. Default: `false`.
+    pub fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Allow dragging nodes around. See [`TreeResponse::moved`]. Default: `false`.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Draw a faint line to the left of the contents of each open directory. Default: `true`.
+    pub fn indent_guides(mut self, indent_guides: bool) -> Self {
+        self.indent_guides = indent_guides;
+        self
+    }
+
+    pub fn show<R>(
+        self,
+        ui: &mut Ui,
+        add_contents: impl FnOnce(&mut TreeUi<'_>) -> R,
+    ) -> TreeResponse<R> {
+        let Self {
+            id_source,
+            multi_select,
+            reorderable,
+            indent_guides,
+        } = self;
+        let tree_id = ui.make_persistent_id(id_source);
+        let state = ui.memory().id_data.get_or_default::<State>(tree_id).clone();
+
+        let mut shared = Shared {
+            tree_id,
+            state,
+            multi_select,
+            reorderable,
+            indent_guides,
+            left: ui.max_rect().left(),
+            rows: Vec::new(),
+            clicked: None,
+            drop: None,
+        };
+
+        let InnerResponse { inner, response } = ui.vertical(|ui| {
+            let mut tree = TreeUi {
+                ui,
+                shared: &mut shared,
+                depth: 0,
+                parent: None,
+            };
+            add_contents(&mut tree)
+        });
+
+        let mut response = ui.interact(response.rect, tree_id, Sense::focusable_noninteractive());
+        ui.memory().interested_in_focus(tree_id);
+
+        let old_selection = shared.state.selected.clone();
+        if let Some((node, modifiers)) = shared.clicked {
+            shared.click(node, modifiers);
+            ui.memory().request_focus(tree_id);
+        } else if ui.memory().has_focus(tree_id) {
+            shared.keyboard(ui);
+        }
+        if shared.state.selected != old_selection {
+            response.mark_changed();
+        }
+
+        let moved = shared.end_drag(ui);
+
+        let Shared { state, .. } = shared;
+        let selected = state.selected.clone();
+        ui.memory().id_data.insert(tree_id, state);
+
+        TreeResponse {
+            inner,
+            response,
+            selected,
+            moved,
+        }
+    }
+}
+
+impl Shared {
+    fn row_index(&self, node: Id) -> Option<usize> {
+        self.rows.iter().position(|row| row.node == node)
+    }
+
+    /// Select a single node, or extend the selection to it if `extend`.
+    fn select(&mut self, node: Id, extend: bool) {
+        let anchor = self.state.anchor.and_then(|anchor| self.row_index(anchor));
+        match (extend && self.multi_select, anchor, self.row_index(node)) {
+            (true, Some(anchor), Some(index)) => {
+                let range = anchor.min(index)..=anchor.max(index);
+                self.state.selected = self.rows[range].iter().map(|row| row.node).collect();
+            }
+            _ => {
+                self.state.selected = vec![node];
+                self.state.anchor = Some(node);
+            }
+        }
+        self.state.cursor = Some(node);
+    }
+
+    fn click(&mut self, node: Id, modifiers: Modifiers) {
+        if self.multi_select && modifiers.command {
+            if let Some(index) = self.state.selected.iter().position(|&id| id == node) {
+                self.state.selected.remove(index);
+            } else {
+                self.state.selected.push(node);
+            }
+            self.state.anchor = Some(node);
+            self.state.cursor = Some(node);
+        } else {
+            self.select(node, modifiers.shift);
+        }
+    }
+
+    fn keyboard(&mut self, ui: &Ui) {
+        if self.rows.is_empty() {
+            return;
+        }
+        let (up, down, left, right, shift) = {
+            let input = ui.input();
+            (
+                input.key_pressed(Key::ArrowUp),
+                input.key_pressed(Key::ArrowDown),
+                input.key_pressed(Key::ArrowLeft),
+                input.key_pressed(Key::ArrowRight),
+                input.modifiers.shift,
+            )
+        };
+
+        let cursor = match self.state.cursor.and_then(|node| self.row_index(node)) {
+            Some(cursor) => cursor,
+            None => {
+                if up || down || left || right {
+                    self.select(self.rows[0].node, false);
+                }
+                return;
+            }
+        };
+        let row = &self.rows[cursor];
+        let is_open = row.is_dir
+            && OpenState::is_open(ui.ctx(), self.tree_id.with(row.node)).unwrap_or(false);
+
+        let mut target = None;
+        if up {
+            target = cursor.checked_sub(1);
+        } else if down {
+            target = Some(cursor + 1).filter(|&index| index < self.rows.len());
+        } else if right && row.is_dir {
+            if is_open {
+                target = Some(cursor + 1)
+                    .filter(|&index| index < self.rows.len())
+                    .filter(|&index| self.rows[index].parent == Some(row.node));
+            } else {
+                set_open(ui, self.tree_id.with(row.node), true);
+            }
+        } else if left {
+            if is_open {
+                set_open(ui, self.tree_id.with(row.node), false);
+            } else if let Some(parent) = row.parent {
+                target = self.row_index(parent);
+            }
+        }
+
+        if let Some(target) = target {
+            let node = self.rows[target].node;
+            self.select(node, shift && (up || down));
+            let rect = self.rows[target].rect;
+            let clip_rect = ui.clip_rect();
+            if rect.top() < clip_rect.top() || rect.bottom() > clip_rect.bottom() {
+                ui.scroll_to_rect(rect, Align::Center);
+            }
+        }
+    }
+
+    /// Returns the finished move, if a drag ended this frame.
+    fn end_drag(&mut self, ui: &Ui) -> Option<TreeMove> {
+        let dragged = self.state.dragged?;
+        let pointer = &ui.input().pointer;
+        if pointer.any_released() || !pointer.any_down() {
+            self.state.dragged = None;
+            let (target, position) = self.drop?;
+            Some(TreeMove {
+                node: dragged,
+                target,
+                position,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+fn set_open(ui: &Ui, id: Id, open: bool) {
+    let mut state = OpenState::from_memory_with_default_open(ui.ctx(), id, false);
+    if OpenState::is_open(ui.ctx(), id) != Some(open) {
+        state.toggle(ui);
+    }
+    ui.memory().id_data.insert(id, state);
+}
+
+/// The [`Ui`] of a [`Tree`], used to add directories and leaves.
+pub struct TreeUi<'a> {
+    ui: &'a mut Ui,
+    shared: &'a mut Shared,
+    depth: usize,
+    parent: Option<Id>,
+}
+
+impl<'a> TreeUi<'a> {
+    /// The underlying [`Ui`], e.g. for adding other widgets between the nodes.
+    pub fn ui(&mut self) -> &mut Ui {
+        self.ui
+    }
+
+    /// A node without children.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn leaf(&mut self, id_source: impl Hash, text: impl ToString) -> Response {
+        self.row(Id::new(id_source), text.to_string(), None)
+    }
+
+    /// A node that can be expanded to show the children added in `add_contents`.
+    ///
+    /// Returns the response of the directory row.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn dir(
+        &mut self,
+        id_source: impl Hash,
+        text: impl ToString,
+        add_contents: impl FnOnce(&mut TreeUi<'_>),
+    ) -> Response {
+        let node = Id::new(id_source);
+        let id = self.shared.tree_id.with(node);
+        let mut state = OpenState::from_memory_with_default_open(self.ui.ctx(), id, false);
+        let response = self.row(node, text.to_string(), Some(&mut state));
+
+        let shared = &mut *self.shared;
+        let depth = self.depth;
+        let children = state.add_contents(self.ui, id, |ui| {
+            let mut tree = TreeUi {
+                ui,
+                shared,
+                depth: depth + 1,
+                parent: Some(node),
+            };
+            add_contents(&mut tree);
+        });
+        self.ui.memory().id_data.insert(id, state);
+
+        if let Some(children) = children {
+            if self.shared.indent_guides && children.response.rect.height() > 0.0 {
+                let indent = self.ui.spacing().indent;
+                let x = self.shared.left + (depth as f32 + 0.5) * indent;
+                let stroke = self.ui.visuals().widgets.noninteractive.bg_stroke;
+                let top = self
+                    .ui
+                    .painter()
+                    .round_pos_to_pixels(pos2(x, response.rect.bottom()));
+                let bottom = self
+                    .ui
+                    .painter()
+                    .round_pos_to_pixels(pos2(x, children.response.rect.bottom()));
+                self.ui.painter().line_segment([top, bottom], stroke);
+            }
+        }
+
+        response
+    }
+
+    fn row(&mut self, node: Id, text: String, open: Option<&mut OpenState>) -> Response {
+        let shared = &mut *self.shared;
+        let ui = &mut *self.ui;
+        let id = shared.tree_id.with(node);
+        let is_dir = open.is_some();
+
+        let indent = ui.spacing().indent;
+        let text_left = shared.left + (self.depth + 1) as f32 * indent;
+        let galley = ui.fonts().layout_no_wrap(TextStyle::Button, text);
+        let width = ui
+            .available_width()
+            .at_least(text_left - shared.left + galley.size.x);
+        let height = galley.size.y.at_least(ui.spacing().interact_size.y);
+        let (rect, _) = ui.allocate_exact_size(vec2(width, height), Sense::hover());
+
+        let sense = if shared.reorderable {
+            Sense::click_and_drag()
+        } else {
+            Sense::click()
+        };
+        let response = ui.interact(rect, id, sense);
+
+        let selected = shared.state.selected.contains(&node);
+        let widget_type = if is_dir {
+            WidgetType::CollapsingHeader
+        } else {
+            WidgetType::SelectableLabel
+        };
+        response.widget_info(|| WidgetInfo::selected(widget_type, selected, &galley.text));
+
+        if let Some(state) = open {
+            let icon_rect = Rect::from_center_size(
+                pos2(text_left - 0.5 * indent, rect.center().y),
+                vec2(ui.spacing().icon_width, ui.spacing().icon_width),
+            );
+            let icon_hovered = match ui.input().pointer.interact_pos() {
+                Some(pos) => icon_rect.contains(pos),
+                None => false,
+            };
+            if (response.clicked() && icon_hovered) || response.double_clicked() {
+                state.toggle(ui);
+            }
+            let openness = state.openness(ui.ctx(), id);
+            let icon_response = Response {
+                rect: icon_rect,
+                ..response.clone()
+            };
+            collapsing_header::paint_icon(ui, openness, &icon_response);
+        }
+
+        if response.clicked() {
+            let modifiers = ui.input().modifiers;
+            shared.clicked = Some((node, modifiers));
+        }
+
+        let visuals = ui.style().interact_selectable(&response, selected);
+        if selected || response.hovered() {
+            ui.painter()
+                .rect(rect, 2.0, visuals.bg_fill, visuals.bg_stroke);
+        }
+        let text_pos = pos2(text_left, rect.center().y - 0.5 * galley.size.y);
+        ui.painter().galley(text_pos, galley, visuals.text_color());
+
+        if shared.reorderable && response.drag_started() {
+            shared.state.dragged = Some(node);
+        }
+        shared.rows.push(Row {
+            node,
+            parent: self.parent,
+            is_dir,
+            rect,
+        });
+        if shared.reorderable {
+            self.drop_target(node, rect, is_dir);
+        }
+
+        response
+    }
+
+    /// Check if the dragged node is hovering this row, and if so, where it would be dropped.
+    fn drop_target(&mut self, node: Id, rect: Rect, is_dir: bool) {
+        let shared = &mut *self.shared;
+        let dragged = match shared.state.dragged {
+            Some(dragged) => dragged,
+            None => return,
+        };
+        let pos = match self.ui.input().pointer.hover_pos() {
+            Some(pos) if rect.contains(pos) => pos,
+            _ => return,
+        };
+
+        // Can't drop a node on itself or on one of its descendants:
+        let mut ancestor = Some(node);
+        while let Some(id) = ancestor {
+            if id == dragged {
+                return;
+            }
+            ancestor = shared
+                .rows
+                .iter()
+                .find(|row| row.node == id)
+                .and_then(|row| row.parent);
+        }
+
+        let t = (pos.y - rect.top()) / rect.height();
+        let position = if is_dir && (0.25..0.75).contains(&t) {
+            DropPosition::Inside
+        } else if t < 0.5 {
+            DropPosition::Before
+        } else {
+            DropPosition::After
+        };
+        shared.drop = Some((node, position));
+
+        let stroke = self.ui.visuals().selection.stroke;
+        let painter = self.ui.painter();
+        match position {
+            DropPosition::Before => {
+                painter.line_segment([rect.left_top(), rect.right_top()], stroke);
+            }
+            DropPosition::After => {
+                painter.line_segment([rect.left_bottom(), rect.right_bottom()], stroke);
+            }
+            DropPosition::Inside => {
+                painter.rect_stroke(rect, 2.0, stroke);
+            }
+        }
+    }
+}
@@ -5,6 +5,9 @@ use epaint::*;
 
 use super::*;
 
+/// Custom contents of a window title bar, see [`Window::title_bar_ui`].
+type TitleBarUi<'a> = Box<dyn FnOnce(&mut Ui) + 'a>;
+
 /// Builder for a floating window which can be dragged, closed, collapsed, resized and scrolled (off by default).
 ///
 /// You can customize:
@@ -31,6 +34,16 @@ pub struct Window<'open> {
     scroll: Option<ScrollArea>,
     collapsible: bool,
     with_title_bar: bool,
+    minimize_button: bool,
+    maximize_button: bool,
+    default_maximized: bool,
+    modal: bool,
+    snap_distance: f32,
+    dockable: bool,
+    opacity: f32,
+    transparent_background: bool,
+    title_bar_ui: Option<TitleBarUi<'open>>,
+    on_close_requested: Option<Box<dyn FnOnce() -> bool + 'open>>,
 }
 
 impl<'open> Window<'open> {
@@ -54,6 +67,16 @@ impl<'open> Window<'open> {
             scroll: None,
             collapsible: true,
             with_title_bar: true,
+            minimize_button: false,
+            maximize_button: false,
+            default_maximized: false,
+            modal: false,
+            snap_distance: 0.0,
+            dockable: false,
+            opacity: 1.0,
+            transparent_background: false,
+            title_bar_ui: None,
+            on_close_requested: None,
         }
     }
 
@@ -67,12 +90,40 @@ impl<'open> Window<'open> {
     ///
     /// * If `*open == false`, the window will not be visible.
     /// * If `*open == true`, the window will have a close button.
-    /// * If the close button is pressed, `*open` will be set to `false`.
+    /// * If the close button is pressed, `*open` will be set to `false`,
+    ///   unless vetoed by [`Self::on_close_requested`].
     pub fn open(mut self, open: &'open mut bool) -> Self {
         self.open = Some(open);
         self
     }
 
+    /// Called when the user presses the close button (see [`Self::open`]).
+    ///
+    /// Return `true` to close the window, or `false` to keep it open,
+    /// e.g. to first ask the user to save their changes.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// # let has_unsaved_changes = true;
+    /// let mut open = true;
+    /// let mut show_save_prompt = false;
+    /// egui::Window::new("Document")
+    ///     .open(&mut open)
+    ///     .on_close_requested(|| {
+    ///         show_save_prompt = has_unsaved_changes;
+    ///         !has_unsaved_changes
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Some unsaved text");
+    ///     });
+    /// ```
+    pub fn on_close_requested(mut self, on_close_requested: impl FnOnce() -> bool + 'open) -> Self {
+        self.on_close_requested = Some(Box::new(on_close_requested));
+        self
+    }
+
     /// If `false` the window will be grayed out and non-interactive.
     pub fn enabled(mut self, enabled: bool) -> Self {
         self.area = self.area.enabled(enabled);
@@ -99,6 +150,44 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Add your own widgets to the title bar, e.g. status icons or extra buttons.
+    ///
+    /// The widgets are placed to the right of the title, which is then no longer centered.
+    /// The window can still be moved, collapsed and closed as usual.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let mut pinned = false;
+    /// egui::Window::new("Inspector")
+    ///     .title_bar_ui(|ui| {
+    ///         ui.checkbox(&mut pinned, "📌");
+    ///     })
+    ///     .show(ctx, |ui| {
+    ///         ui.label("Contents");
+    ///     });
+    /// ```
+    pub fn title_bar_ui(mut self, title_bar_ui: impl FnOnce(&mut Ui) + 'open) -> Self {
+        self.title_bar_ui = Some(Box::new(title_bar_ui));
+        self
+    }
+
+    /// Make the whole window (frame and contents) more transparent.
+    ///
+    /// `opacity` is in the range [0, 1], where `0.0` is invisible and `1.0` (the default) is opaque.
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.at_least(0.0).at_most(1.0);
+        self
+    }
+
+    /// Don't paint the background, border or shadow of the window, only its contents.
+    /// The margins are kept. Useful for HUD-style overlays on top of e.g. a game.
+    pub fn transparent_background(mut self, transparent_background: bool) -> Self {
+        self.transparent_background = transparent_background;
+        self
+    }
+
     /// Set minimum width of the window.
     pub fn min_width(mut self, min_width: f32) -> Self {
         self.resize = self.resize.min_width(min_width);
@@ -110,6 +199,17 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Set maximum width of the window.
+    pub fn max_width(mut self, max_width: f32) -> Self {
+        self.resize = self.resize.max_width(max_width);
+        self
+    }
+    /// Set maximum height of the window.
+    pub fn max_height(mut self, max_height: f32) -> Self {
+        self.resize = self.resize.max_height(max_height);
+        self
+    }
+
     /// Set current position of the window.
     /// If the window is movable it is up to you to keep track of where it moved to!
     pub fn current_pos(mut self, current_pos: impl Into<Pos2>) -> Self {
@@ -178,6 +278,13 @@ impl<'open> Window<'open> {
         self.fixed_pos(rect.min).fixed_size(rect.size())
     }
 
+    /// Keep the window on top of other windows, even when the user clicks on them,
+    /// e.g. for a tool palette. Default: `false`.
+    pub fn always_on_top(mut self, always_on_top: bool) -> Self {
+        self.area = self.area.always_on_top(always_on_top);
+        self
+    }
+
     /// Can the user resize the window by dragging its edges?
     /// Note that even if you set this to `false` the window may still auto-resize.
     pub fn resizable(mut self, resizable: bool) -> Self {
@@ -198,6 +305,28 @@ impl<'open> Window<'open> {
         self
     }
 
+    /// Add a button to the title bar that collapses the window to just its title bar. Default: `false`.
+    pub fn minimize_button(mut self, minimize_button: bool) -> Self {
+        self.minimize_button = minimize_button;
+        self
+    }
+
+    /// Add a button to the title bar that makes the window fill the screen,
+    /// and puts it back where it was when clicked again. Default: `false`.
+    pub fn maximize_button(mut self, maximize_button: bool) -> Self {
+        self.maximize_button = maximize_button;
+        self
+    }
+
+    /// Start out maximized the first time the window is shown. Default: `false`.
+    ///
+    /// Restoring the window puts it in the middle of the screen, at its default size.
+    /// See also [`Self::set_maximized`].
+    pub fn default_maximized(mut self, default_maximized: bool) -> Self {
+        self.default_maximized = default_maximized;
+        self
+    }
+
     /// Not resizable, just takes the size of its contents.
     /// Also disabled scrolling.
     /// Text will not wrap, but will instead make your window width expand.
@@ -228,11 +357,85 @@ impl<'open> Window<'open> {
         self.area = self.area.drag_bounds(bounds);
         self
     }
+
+    /// Keep the window within the screen (or the [`Self::drag_bounds`]),
+    /// even if it is positioned or dragged outside of it. Default: `true`.
+    ///
+    /// Turn this off to restore the exact positions of windows, e.g. from a bigger screen.
+    pub fn constrain(mut self, constrain: bool) -> Self {
+        self.area = self.area.constrain(constrain);
+        self
+    }
+
+    /// Dim everything behind the window, and block all interaction with it, e.g. for a confirmation dialog.
+    /// Keyboard focus stays within the window. Default: `false`.
+    ///
+    /// A modal window is shown on top of other windows, and can not be docked.
+    /// Close it with [`Self::open`], e.g. from its buttons or its close button.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let mut open = true;
+    /// let mut close = false;
+    /// egui::Window::new("Delete file?")
+    ///     .modal(true)
+    ///     .open(&mut open)
+    ///     .show(ctx, |ui| {
+    ///         if ui.button("Delete").clicked() {
+    ///             // delete the file …
+    ///             close = true;
+    ///         }
+    ///         if ui.button("Cancel").clicked() {
+    ///             close = true;
+    ///         }
+    ///     });
+    /// if close {
+    ///     open = false;
+    /// }
+    /// ```
+    pub fn modal(mut self, modal: bool) -> Self {
+        self.modal = modal;
+        self
+    }
+
+    /// While the user moves the window, snap its edges to the edges of the screen and of other windows
+    /// that are closer than this. Default: `0.0`, i.e. no snapping.
+    pub fn snap_distance(mut self, snap_distance: f32) -> Self {
+        self.snap_distance = snap_distance;
+        self
+    }
+
+    /// Can the user dock the window in the [`DockArea`] by dragging it there? Default: `false`.
+    pub fn dockable(mut self, dockable: bool) -> Self {
+        self.dockable = dockable;
+        self
+    }
 }
 
 impl<'open> Window<'open> {
-    /// Returns `None` if the windows is not open (if [`Window::open`] was called with `&mut false`.
-    pub fn show(self, ctx: &CtxRef, add_contents: impl FnOnce(&mut Ui)) -> Option<Response> {
+    /// Returns `None` if the windows is not open (if [`Window::open`] was called with `&mut false`,
+    /// or if the window is docked in a tab that is not shown.
+    ///
+    /// The rect of the window, e.g. to save it or position things next to it,
+    /// is the `rect` of [`WindowResponse::response`].
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let window = egui::Window::new("Tools").current_pos([32.0, 32.0]).show(ctx, |ui| {
+    ///     ui.label("Hello");
+    /// });
+    /// if let Some(window) = window {
+    ///     let below_tools = window.response.rect.left_bottom();
+    ///     egui::Window::new("Help").current_pos(below_tools).show(ctx, |ui| {
+    ///         ui.label("World");
+    ///     });
+    /// }
+    /// ```
+    pub fn show(self, ctx: &CtxRef, add_contents: impl FnOnce(&mut Ui)) -> Option<WindowResponse> {
         self.show_impl(ctx, Box::new(add_contents))
     }
 
@@ -240,7 +443,7 @@ impl<'open> Window<'open> {
         self,
         ctx: &CtxRef,
         add_contents: Box<dyn FnOnce(&mut Ui) + 'c>,
-    ) -> Option<Response> {
+    ) -> Option<WindowResponse> {
         let Window {
             title_label,
             open,
@@ -250,21 +453,111 @@ impl<'open> Window<'open> {
             scroll,
             collapsible,
             with_title_bar,
+            minimize_button,
+            maximize_button,
+            default_maximized,
+            modal,
+            snap_distance,
+            dockable,
+            opacity,
+            transparent_background,
+            title_bar_ui,
+            on_close_requested,
         } = self;
+        let dockable = dockable && !modal;
+        let area = if modal {
+            area.order(Order::Foreground)
+        } else {
+            area
+        };
 
-        let frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
+        let mut frame = frame.unwrap_or_else(|| Frame::window(&ctx.style()));
+        if transparent_background {
+            frame = frame.multiply_with_opacity(0.0);
+        }
 
         let is_open = !matches!(open, Some(false)) || ctx.memory().everything_is_visible();
-        area.show_open_close_animation(ctx, &frame, is_open);
+        let visibility =
+            area.show_open_close_animation(ctx, &frame.multiply_with_opacity(opacity), is_open);
 
         if !is_open {
             return None;
         }
 
         let area_id = area.id;
+        if dockable && dock::is_docked(ctx, area_id) {
+            return dock::show_docked(ctx, area_id, scroll, add_contents).map(|response| {
+                WindowResponse {
+                    response,
+                    collapsed: false,
+                    maximized: false,
+                }
+            });
+        }
+
         let area_layer_id = area.layer();
+        if modal {
+            show_modal_backdrop(ctx, area_id);
+            ctx.memory().trap_focus(area_layer_id);
+        }
+
         let resize_id = area_id.with("resize");
         let collapsing_id = area_id.with("collapsing");
+        let maximize_id = area_id.with("maximize");
+
+        let title_content_spacing = 2.0 * ctx.style().spacing.item_spacing.y;
+
+        // Calculate roughly how much larger the window size is compared to the inner rect
+        let title_bar_height = if with_title_bar {
+            title_label.font_height(ctx.fonts(), &ctx.style()) + title_content_spacing
+        } else {
+            0.0
+        };
+        let margins = 2.0 * frame.margin + vec2(0.0, title_bar_height);
+
+        let (mut area, mut resize) = (area, resize);
+        let mut maximized = false;
+        let mut maximize_state = ctx
+            .memory()
+            .id_data
+            .get::<MaximizeState>(&maximize_id)
+            .copied();
+        if maximize_state.is_none()
+            && default_maximized
+            && ctx.memory().areas.get(area_id).is_none()
+        {
+            maximize_state = Some(MaximizeState {
+                restore_rect: Rect::NAN,
+                maximized: true,
+            });
+        }
+        if let Some(state) = &mut maximize_state {
+            if !state.restore_rect.is_finite() {
+                // Maximized before the window was first shown, so restore it to its default place:
+                state.restore_rect = Rect::from_center_size(
+                    ctx.available_rect().center(),
+                    resize.default_size + margins,
+                );
+                ctx.memory().id_data.insert(maximize_id, *state);
+            }
+        }
+        if let Some(maximize_state) = maximize_state {
+            if maximize_state.maximized {
+                let rect = ctx.available_rect();
+                area = area.fixed_pos(rect.min);
+                resize = resize.fixed_size(rect.size() - margins);
+                maximized = true;
+            } else {
+                // Go back to where we were before being maximized:
+                let restore_rect = maximize_state.restore_rect;
+                area = area.current_pos(restore_rect.min);
+                let mut memory = ctx.memory();
+                if let Some(state) = memory.id_data.get_mut::<resize::State>(&resize_id) {
+                    state.requested_size = Some(restore_rect.size() - margins);
+                }
+                memory.id_data.remove(&maximize_id);
+            }
+        }
 
         let is_collapsed = with_title_bar
             && !collapsing_header::State::is_open(ctx, collapsing_id).unwrap_or_default();
@@ -276,8 +569,6 @@ impl<'open> Window<'open> {
 
         let mut area = area.begin(ctx);
 
-        let title_content_spacing = 2.0 * ctx.style().spacing.item_spacing.y;
-
         // First interact (move etc) to avoid frame delay:
         let last_frame_outer_rect = area.state().rect();
         let interaction = if possible.movable || possible.resizable() {
@@ -289,19 +580,14 @@ impl<'open> Window<'open> {
                 last_frame_outer_rect,
             )
             .and_then(|window_interaction| {
-                // Calculate roughly how much larger the window size is compared to the inner rect
-                let title_bar_height = if with_title_bar {
-                    title_label.font_height(ctx.fonts(), &ctx.style()) + title_content_spacing
-                } else {
-                    0.0
-                };
-                let margins = 2.0 * frame.margin + vec2(0.0, title_bar_height);
-                let bounds = area.drag_bounds();
+                let bounds = area.constrain_rect(ctx);
 
                 interact(
                     window_interaction,
                     ctx,
                     margins,
+                    resize.min_size,
+                    resize.max_size,
                     area_layer_id,
                     area.state_mut(),
                     resize_id,
@@ -311,6 +597,22 @@ impl<'open> Window<'open> {
         } else {
             None
         };
+        if let Some(interaction) = interaction {
+            if snap_distance > 0.0 && !interaction.is_resize() {
+                let rect = area.state().rect();
+                area.state_mut().pos =
+                    snap_window_rect(ctx, area_layer_id, rect, snap_distance).min;
+            }
+            if dockable && !interaction.is_resize() {
+                dock::set_dragged(
+                    ctx,
+                    DockTab {
+                        id: area_id,
+                        title: title_label.text().to_owned(),
+                    },
+                );
+            }
+        }
         let hover_interaction = resize_hover(ctx, possible, area_layer_id, last_frame_outer_rect);
 
         let mut area_content_ui = area.content_ui(ctx);
@@ -326,15 +628,18 @@ impl<'open> Window<'open> {
                 collapsing_id,
                 default_expanded,
             );
-            let show_close_button = open.is_some();
+            let right_buttons = usize::from(open.is_some())
+                + usize::from(minimize_button)
+                + usize::from(maximize_button);
             let title_bar = if with_title_bar {
                 let title_bar = show_title_bar(
                     &mut frame.content_ui,
                     title_label,
-                    show_close_button,
+                    right_buttons,
                     collapsing_id,
                     &mut collapsing,
                     collapsible,
+                    title_bar_ui,
                 );
                 resize.min_size.x = resize.min_size.x.at_least(title_bar.rect.width()); // Prevent making window smaller than title bar width
                 Some(title_bar)
@@ -364,14 +669,49 @@ impl<'open> Window<'open> {
             // END FRAME --------------------------------
 
             if let Some(title_bar) = title_bar {
+                let was_maximized = maximized;
+                let mut close_clicked = false;
                 title_bar.ui(
                     &mut area_content_ui,
                     outer_rect,
                     &content_response,
-                    open,
+                    if open.is_some() {
+                        Some(&mut close_clicked)
+                    } else {
+                        None
+                    },
                     &mut collapsing,
                     collapsible,
+                    minimize_button,
+                    if maximize_button {
+                        Some(&mut maximized)
+                    } else {
+                        None
+                    },
                 );
+                if maximized != was_maximized {
+                    let restore_rect =
+                        maximize_state.map_or(outer_rect, |state| state.restore_rect);
+                    ctx.memory().id_data.insert(
+                        maximize_id,
+                        MaximizeState {
+                            restore_rect: if maximized { outer_rect } else { restore_rect },
+                            maximized,
+                        },
+                    );
+                    ctx.request_repaint();
+                }
+                if close_clicked {
+                    let close = match on_close_requested {
+                        Some(on_close_requested) => on_close_requested(),
+                        None => true,
+                    };
+                    if close {
+                        if let Some(open) = open {
+                            *open = false;
+                        }
+                    }
+                }
             }
 
             area_content_ui
@@ -399,10 +739,121 @@ impl<'open> Window<'open> {
         }
         let full_response = area.end(ctx, area_content_ui);
 
-        Some(full_response)
+        let opacity = visibility * opacity;
+        if opacity < 1.0 {
+            // Fade in when opening, and apply `Window::opacity`:
+            ctx.graphics()
+                .list(area_layer_id)
+                .lock()
+                .multiply_with_opacity(opacity);
+        }
+
+        Some(WindowResponse {
+            response: full_response,
+            collapsed: with_title_bar
+                && !collapsing_header::State::is_open(ctx, collapsing_id).unwrap_or(true),
+            maximized,
+        })
     }
 }
 
+/// ## Maximizing
+impl Window<'_> {
+    /// Is the window with the given id maximized to fill the screen?
+    ///
+    /// The id is the one given to [`Self::id`], or else `Id::new(title)`.
+    pub fn is_maximized(ctx: &Context, id: Id) -> bool {
+        matches!(
+            ctx.memory().id_data.get::<MaximizeState>(&id.with("maximize")),
+            Some(state) if state.maximized
+        )
+    }
+
+    /// Maximize the window with the given id to fill the [`Context::available_rect`],
+    /// or put it back where it was before it was maximized.
+    ///
+    /// This works even if the window has no maximize button,
+    /// or has not been shown yet (like [`Self::default_maximized`]).
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let id = egui::Id::new("Tool");
+    /// // "Focus mode":
+    /// let maximized = egui::Window::is_maximized(ctx, id);
+    /// egui::Window::set_maximized(ctx, id, !maximized);
+    /// let response = egui::Window::new("Tool").show(ctx, |ui| {
+    ///     ui.label("Contents");
+    /// });
+    /// # assert!(response.unwrap().maximized);
+    /// ```
+    pub fn set_maximized(ctx: &Context, id: Id, maximized: bool) {
+        if maximized == Self::is_maximized(ctx, id) {
+            return;
+        }
+        let maximize_id = id.with("maximize");
+        let mut memory = ctx.memory();
+        if maximized {
+            // If the window has not been shown yet, its restore rect is set when it is shown:
+            let restore_rect = memory.areas.get(id).map_or(Rect::NAN, |state| state.rect());
+            memory.id_data.insert(
+                maximize_id,
+                MaximizeState {
+                    restore_rect,
+                    maximized,
+                },
+            );
+        } else if let Some(state) = memory.id_data.get_mut::<MaximizeState>(&maximize_id) {
+            state.maximized = false;
+        }
+        drop(memory);
+        ctx.request_repaint();
+    }
+}
+
+/// Dim everything behind a modal window, and catch the clicks meant for it.
+fn show_modal_backdrop(ctx: &CtxRef, window_id: Id) {
+    let screen_rect = ctx.input().screen_rect();
+    let backdrop = Area::new(window_id.with("modal_backdrop"))
+        .order(Order::Middle)
+        .fixed_pos(screen_rect.min);
+    let layer_id = backdrop.layer();
+    backdrop.show(ctx, |ui| {
+        // Not clipped to the central area, like the contents of other areas:
+        Painter::new(ctx.clone(), layer_id, screen_rect).rect_filled(
+            screen_rect,
+            0.0,
+            Color32::from_black_alpha(100),
+        );
+        ui.set_min_size(screen_rect.size());
+    });
+    // Above all other windows, but below the modal window:
+    ctx.memory().areas.move_to_top(layer_id);
+}
+
+/// The response from showing a [`Window`].
+#[derive(Debug)]
+pub struct WindowResponse {
+    /// The response of the whole window, including its title bar.
+    pub response: Response,
+    /// Is the window collapsed to just its title bar?
+    pub collapsed: bool,
+    /// Does the window fill the screen? See [`Window::maximize_button`].
+    pub maximized: bool,
+}
+
+/// Where a window goes back to after being maximized.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub(crate) struct MaximizeState {
+    /// The outer rect of the window before it was maximized.
+    /// Not finite if the window was maximized before it was first shown.
+    restore_rect: Rect,
+    /// `false` for one frame when the window should be restored.
+    maximized: bool,
+}
+
 fn paint_resize_corner(
     ui: &mut Ui,
     possible: &PossibleInteractions,
@@ -487,22 +938,30 @@ impl WindowInteraction {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn interact(
     window_interaction: WindowInteraction,
     ctx: &Context,
     margins: Vec2,
+    min_size: Vec2,
+    max_size: Vec2,
     area_layer_id: LayerId,
     area_state: &mut area::State,
     resize_id: Id,
-    drag_bounds: Option<Rect>,
+    constrain_rect: Option<Rect>,
 ) -> Option<WindowInteraction> {
-    let new_rect = move_and_resize_window(ctx, &window_interaction)?;
+    let new_rect = move_and_resize_window(
+        ctx,
+        &window_interaction,
+        min_size + margins,
+        max_size + margins,
+    )?;
     let new_rect = ctx.round_rect_to_pixels(new_rect);
 
-    let new_rect = if let Some(bounds) = drag_bounds {
+    let new_rect = if let Some(bounds) = constrain_rect {
         ctx.constrain_window_rect_to_area(new_rect, bounds)
     } else {
-        ctx.constrain_window_rect(new_rect)
+        new_rect
     };
 
     // TODO: add this to a Window state instead as a command "move here next frame"
@@ -520,22 +979,112 @@ fn interact(
     Some(window_interaction)
 }
 
-fn move_and_resize_window(ctx: &Context, window_interaction: &WindowInteraction) -> Option<Rect> {
+/// Move the window so that its edges line up with nearby edges of the screen and of other windows,
+/// and show where it snapped.
+fn snap_window_rect(ctx: &CtxRef, area_layer_id: LayerId, rect: Rect, snap_distance: f32) -> Rect {
+    let screen_rect = ctx.available_rect();
+    let other_windows: Vec<Rect> = {
+        let memory = ctx.memory();
+        memory
+            .areas
+            .visible_layer_ids()
+            .into_iter()
+            .filter(|layer| layer.order == Order::Middle && *layer != area_layer_id)
+            .filter_map(|layer| memory.areas.get(layer.id).map(area::State::rect))
+            .collect()
+    };
+
+    // Pairs of (edge of the window, edge to snap it to):
+    let mut x_edges = vec![
+        (rect.left(), screen_rect.left()),
+        (rect.right(), screen_rect.right()),
+    ];
+    let mut y_edges = vec![
+        (rect.top(), screen_rect.top()),
+        (rect.bottom(), screen_rect.bottom()),
+    ];
+    for other in other_windows {
+        let near = other.expand(snap_distance);
+        if near.top() <= rect.bottom() && rect.top() <= near.bottom() {
+            for &edge in &[rect.left(), rect.right()] {
+                x_edges.push((edge, other.left()));
+                x_edges.push((edge, other.right()));
+            }
+        }
+        if near.left() <= rect.right() && rect.left() <= near.right() {
+            for &edge in &[rect.top(), rect.bottom()] {
+                y_edges.push((edge, other.top()));
+                y_edges.push((edge, other.bottom()));
+            }
+        }
+    }
+
+    // Returns the offset to the closest edge to snap to, and that edge:
+    let closest = |edges: &[(f32, f32)]| {
+        edges
+            .iter()
+            .map(|&(edge, target)| (target - edge, target))
+            .filter(|(delta, _)| delta.abs() <= snap_distance)
+            .min_by(|a, b| {
+                a.0.abs()
+                    .partial_cmp(&b.0.abs())
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+    };
+
+    let mut rect = rect;
+    let painter = Painter::new(
+        ctx.clone(),
+        LayerId::new(Order::Foreground, Id::new("window_snap")),
+        ctx.input().screen_rect(),
+    );
+    let stroke = ctx.style().visuals.selection.stroke;
+    if let Some((delta, x)) = closest(&x_edges) {
+        rect = rect.translate(vec2(delta, 0.0));
+        painter.line_segment([pos2(x, rect.top()), pos2(x, rect.bottom())], stroke);
+    }
+    if let Some((delta, y)) = closest(&y_edges) {
+        rect = rect.translate(vec2(0.0, delta));
+        painter.line_segment([pos2(rect.left(), y), pos2(rect.right(), y)], stroke);
+    }
+    rect
+}
+
+/// `min_size` and `max_size` are for the outer rect of the window.
+fn move_and_resize_window(
+    ctx: &Context,
+    window_interaction: &WindowInteraction,
+    min_size: Vec2,
+    max_size: Vec2,
+) -> Option<Rect> {
     window_interaction.set_cursor(ctx);
     let pointer_pos = ctx.input().pointer.interact_pos()?;
     let mut rect = window_interaction.start_rect; // prevent drift
 
     if window_interaction.is_resize() {
+        // Keep the opposite edge in place when reaching the minimum or maximum size:
         if window_interaction.left {
-            rect.min.x = ctx.round_to_pixel(pointer_pos.x);
+            rect.min.x = ctx
+                .round_to_pixel(pointer_pos.x)
+                .at_least(rect.max.x - max_size.x)
+                .at_most(rect.max.x - min_size.x);
         } else if window_interaction.right {
-            rect.max.x = ctx.round_to_pixel(pointer_pos.x);
+            rect.max.x = ctx
+                .round_to_pixel(pointer_pos.x)
+                .at_least(rect.min.x + min_size.x)
+                .at_most(rect.min.x + max_size.x);
         }
 
         if window_interaction.top {
-            rect.min.y = ctx.round_to_pixel(pointer_pos.y);
+            rect.min.y = ctx
+                .round_to_pixel(pointer_pos.y)
+                .at_least(rect.max.y - max_size.y)
+                .at_most(rect.max.y - min_size.y);
         } else if window_interaction.bottom {
-            rect.max.y = ctx.round_to_pixel(pointer_pos.y);
+            rect.max.y = ctx
+                .round_to_pixel(pointer_pos.y)
+                .at_least(rect.min.y + min_size.y)
+                .at_most(rect.min.y + max_size.y);
         }
     } else {
         // Movement.
@@ -734,17 +1283,21 @@ struct TitleBar {
     id: Id,
     title_label: Label,
     title_galley: std::sync::Arc<Galley>,
+    /// Where the title goes, if not centered.
+    title_rect: Option<Rect>,
     min_rect: Rect,
     rect: Rect,
 }
 
+/// `right_buttons`: how many buttons go to the right of the title, e.g. the close button.
 fn show_title_bar(
     ui: &mut Ui,
     title_label: Label,
-    show_close_button: bool,
+    right_buttons: usize,
     collapsing_id: Id,
     collapsing: &mut collapsing_header::State,
     collapsible: bool,
+    title_bar_ui: Option<TitleBarUi<'_>>,
 ) -> TitleBar {
     let inner_response = ui.horizontal(|ui| {
         let height = title_label
@@ -771,9 +1324,26 @@ fn show_title_bar(
 
         let title_galley = title_label.layout(ui);
 
-        let minimum_width = if collapsible || show_close_button {
-            // If at least one button is shown we make room for both buttons (since title is centered):
-            2.0 * (pad + button_size.x + item_spacing.x) + title_galley.size.x
+        let buttons = right_buttons.max(usize::from(collapsible));
+
+        if let Some(title_bar_ui) = title_bar_ui {
+            // Title to the left, followed by the custom contents, and then room for the buttons:
+            let (id, title_rect) = ui.allocate_space(title_galley.size);
+            title_bar_ui(ui);
+            ui.add_space(buttons as f32 * (button_size.x + item_spacing.x));
+            return TitleBar {
+                id,
+                title_label,
+                title_galley,
+                title_rect: Some(title_rect),
+                min_rect: ui.min_rect(),
+                rect: Rect::NAN, // Will be filled in later
+            };
+        }
+
+        let minimum_width = if buttons > 0 {
+            // If at least one button is shown we make room for buttons on both sides (since title is centered):
+            2.0 * (pad + buttons as f32 * (button_size.x + item_spacing.x)) + title_galley.size.x
         } else {
             pad + title_galley.size.x + pad
         };
@@ -784,6 +1354,7 @@ fn show_title_bar(
             id,
             title_label,
             title_galley,
+            title_rect: None,
             min_rect,
             rect: Rect::NAN, // Will be filled in later
         }
@@ -796,25 +1367,39 @@ fn show_title_bar(
 }
 
 impl TitleBar {
+    #[allow(clippy::too_many_arguments)]
     fn ui(
         mut self,
         ui: &mut Ui,
         outer_rect: Rect,
         content_response: &Option<Response>,
-        open: Option<&mut bool>,
+        close_clicked: Option<&mut bool>,
         collapsing: &mut collapsing_header::State,
         collapsible: bool,
+        show_minimize_button: bool,
+        maximized: Option<&mut bool>,
     ) {
         if let Some(content_response) = &content_response {
             // Now we know how large we got to be:
             self.rect.max.x = self.rect.max.x.max(content_response.rect.max.x);
         }
 
-        if let Some(open) = open {
-            // Add close button now that we know our full width:
-            if self.close_button_ui(ui).clicked() {
-                *open = false;
+        // Add buttons now that we know our full width:
+        let mut button_index = 0;
+        if let Some(close_clicked) = close_clicked {
+            *close_clicked = close_button(ui, self.button_rect(ui, button_index)).clicked();
+            button_index += 1;
+        }
+        if let Some(maximized) = maximized {
+            let rect = self.button_rect(ui, button_index);
+            if maximize_button(ui, rect, *maximized).clicked() {
+                *maximized = !*maximized;
             }
+            button_index += 1;
+        }
+        if show_minimize_button && minimize_button(ui, self.button_rect(ui, button_index)).clicked()
+        {
+            collapsing.toggle(ui);
         }
 
         // Always have inactive style for the window.
@@ -824,8 +1409,12 @@ impl TitleBar {
         self.title_label = self.title_label.text_color(style.fg_stroke.color);
 
         let full_top_rect = Rect::from_x_y_ranges(self.rect.x_range(), self.min_rect.y_range());
-        let text_pos = emath::align::center_size_in_rect(self.title_galley.size, full_top_rect);
-        let text_pos = text_pos.left_top() - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
+        let mut text_pos =
+            emath::align::center_size_in_rect(self.title_galley.size, full_top_rect).left_top();
+        if let Some(title_rect) = self.title_rect {
+            text_pos.x = title_rect.left();
+        }
+        let text_pos = text_pos - 1.5 * Vec2::Y; // HACK: center on x-height of text (looks better)
         self.title_label
             .paint_galley(ui, text_pos, self.title_galley);
 
@@ -850,18 +1439,18 @@ impl TitleBar {
         }
     }
 
-    fn close_button_ui(&self, ui: &mut Ui) -> Response {
+    /// Where to put a button, counting from the right, where the close button is.
+    fn button_rect(&self, ui: &Ui, index: usize) -> Rect {
         let button_size = Vec2::splat(ui.spacing().icon_width);
         let pad = (self.rect.height() - button_size.y) / 2.0; // calculated so that the icon is on the diagonal (if window padding is symmetrical)
-        let button_rect = Rect::from_min_size(
+        let offset = index as f32 * (button_size.x + ui.spacing().item_spacing.x);
+        Rect::from_min_size(
             pos2(
-                self.rect.right() - pad - button_size.x,
+                self.rect.right() - pad - button_size.x - offset,
                 self.rect.center().y - 0.5 * button_size.y,
             ),
             button_size,
-        );
-
-        close_button(ui, button_rect)
+        )
     }
 }
 
@@ -879,3 +1468,38 @@ fn close_button(ui: &mut Ui, rect: Rect) -> Response {
         .line_segment([rect.right_top(), rect.left_bottom()], stroke);
     response
 }
+
+fn minimize_button(ui: &mut Ui, rect: Rect) -> Response {
+    let minimize_id = ui.auto_id_with("window_minimize_button");
+    let response = ui.interact(rect, minimize_id, Sense::click());
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    ui.painter()
+        .line_segment([rect.left_bottom(), rect.right_bottom()], visuals.fg_stroke);
+    response
+}
+
+/// Shows a single square, or two overlapping squares when `maximized`.
+fn maximize_button(ui: &mut Ui, rect: Rect, maximized: bool) -> Response {
+    let maximize_id = ui.auto_id_with("window_maximize_button");
+    let response = ui.interact(rect, maximize_id, Sense::click());
+    ui.expand_to_include_rect(response.rect);
+
+    let visuals = ui.style().interact(&response);
+    let rect = rect.shrink(2.0).expand(visuals.expansion);
+    let stroke = visuals.fg_stroke;
+    if maximized {
+        let offset = 0.25 * rect.width();
+        let back = Rect::from_min_max(rect.min + vec2(offset, 0.0), rect.max - vec2(0.0, offset));
+        let front = back.translate(vec2(-offset, offset));
+        ui.painter().rect_stroke(back, 0.0, stroke);
+        ui.painter()
+            .rect_filled(front, 0.0, ui.visuals().widgets.noninteractive.bg_fill);
+        ui.painter().rect_stroke(front, 0.0, stroke);
+    } else {
+        ui.painter().rect_stroke(rect, 0.0, stroke);
+    }
+    response
+}
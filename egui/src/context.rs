@@ -12,6 +12,7 @@ use crate::{
     input_state::*,
     layers::GraphicLayers,
     mutex::{Mutex, MutexGuard},
+    toasts::Toasts,
     *,
 };
 use epaint::{stats::*, text::Fonts, *};
@@ -102,6 +103,8 @@ impl CtxRef {
         let mut self_: Context = (*self.0).clone();
         self_.begin_frame_mut(new_input);
         *self = Self(Arc::new(self_));
+
+        crate::toasts::show(self);
     }
 
     // ---------------------------------------------------------------------
@@ -196,7 +199,11 @@ impl CtxRef {
             changed: false, // must be set by the widget itself
         };
 
-        if !enabled || !sense.focusable || !layer_id.allow_interaction() {
+        if !enabled
+            || !sense.focusable
+            || !layer_id.allow_interaction()
+            || !self.memory().allows_focus(layer_id)
+        {
             // Not interested or allowed input:
             self.memory().surrender_focus(id);
             return response;
@@ -298,6 +305,8 @@ impl CtxRef {
 
 // ----------------------------------------------------------------------------
 
+type OpenUrlHandler = Arc<dyn Fn(&crate::output::OpenUrl) -> bool + Send + Sync>;
+
 /// This is the first thing you need when working with egui. Create using [`CtxRef`].
 ///
 /// Contains the [`InputState`], [`Memory`], [`Output`], and more.
@@ -332,6 +341,10 @@ pub struct Context {
 
     paint_stats: Arc<Mutex<PaintStats>>,
 
+    toasts: Arc<Mutex<Toasts>>,
+
+    open_url_handler: Arc<Mutex<Option<OpenUrlHandler>>>,
+
     /// While positive, keep requesting repaints. Decrement at the end of each frame.
     repaint_requests: AtomicU32,
 }
@@ -347,6 +360,8 @@ impl Clone for Context {
             graphics: self.graphics.clone(),
             output: self.output.clone(),
             paint_stats: self.paint_stats.clone(),
+            toasts: self.toasts.clone(),
+            open_url_handler: self.open_url_handler.clone(),
             repaint_requests: self.repaint_requests.load(SeqCst).into(),
         }
     }
@@ -385,6 +400,10 @@ impl Context {
         self.frame_state.lock()
     }
 
+    pub(crate) fn toasts(&self) -> MutexGuard<'_, Toasts> {
+        self.toasts.lock()
+    }
+
     /// Call this if there is need to repaint the UI, i.e. if you are showing an animation.
     /// If this is called at least once in a frame, then there will be another frame right after this.
     /// Call as many times as you wish, only one repaint will be issued.
@@ -399,6 +418,33 @@ impl Context {
         &self.input
     }
 
+    /// Was this keyboard shortcut pressed this frame, without anything else having used it yet?
+    ///
+    /// The first caller gets `true`, so that e.g. a menu item and a button with the same shortcut
+    /// don't both trigger on the same key press.
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// let save = KeyboardShortcut::new(Modifiers { command: true, ..Default::default() }, Key::S);
+    /// if ctx.shortcut_pressed(save) {
+    ///     // save…
+    /// }
+    /// ```
+    pub fn shortcut_pressed(&self, shortcut: KeyboardShortcut) -> bool {
+        if !self.input.shortcut_pressed(shortcut) {
+            return false;
+        }
+        let mut frame_state = self.frame_state();
+        if frame_state.used_shortcuts.contains(&shortcut) {
+            false
+        } else {
+            frame_state.used_shortcuts.push(shortcut);
+            true
+        }
+    }
+
     /// Not valid until first call to [`CtxRef::begin_frame()`].
     /// That's because since we don't know the proper `pixels_per_point` until then.
     pub fn fonts(&self) -> &Fonts {
@@ -708,6 +754,119 @@ impl Context {
         }
     }
 
+    /// Show the given layer on top of the other layers of the same [`Order`], starting next frame.
+    ///
+    /// This is what happens when the user clicks a [`Window`].
+    pub fn move_to_top(&self, layer_id: LayerId) {
+        self.memory().areas.move_to_top(layer_id);
+    }
+
+    /// Show the given layer behind the other layers of the same [`Order`], starting next frame.
+    pub fn send_to_back(&self, layer_id: LayerId) {
+        self.memory().areas.send_to_back(layer_id);
+    }
+
+    /// The positions, sizes and collapsed state of all windows, so you can save them
+    /// and restore them later with [`Self::set_window_layout`].
+    ///
+    /// ```
+    /// # let mut ctx = egui::CtxRef::default();
+    /// # ctx.begin_frame(Default::default());
+    /// # let ctx = &ctx;
+    /// let layout = ctx.window_layout();
+    /// // … the user moves some windows around …
+    /// ctx.set_window_layout(layout);
+    /// ```
+    pub fn window_layout(&self) -> WindowLayout {
+        self.memory().window_layout()
+    }
+
+    /// Restore a layout from [`Self::window_layout`].
+    ///
+    /// Windows that are not part of the layout keep their current position and size.
+    pub fn set_window_layout(&self, layout: WindowLayout) {
+        self.memory().set_window_layout(layout);
+        self.request_repaint();
+    }
+
+    /// Put all windows and areas back at their default positions and sizes, and un-collapse them.
+    ///
+    /// Unlike resetting all of [`Memory`], this keeps e.g. scroll positions and text cursors.
+    /// Useful for a "Reset layout" menu item.
+    pub fn reset_window_layout(&self) {
+        self.memory().reset_window_layout();
+        self.request_repaint();
+    }
+
+    // ---------------------------------------------------------------------
+
+    /// Show a notification in a corner of the screen, starting next frame.
+    ///
+    /// See [`Toast`] for an example.
+    pub fn notify(&self, toast: Toast) -> ToastId {
+        self.request_repaint();
+        self.toasts().add(toast)
+    }
+
+    /// Close a toast, e.g. when whatever it was about is no longer relevant.
+    pub fn dismiss_toast(&self, id: ToastId) {
+        self.toasts().dismiss(id, self.input.time);
+    }
+
+    /// If one of the buttons of the given toast (see [`Toast::button`]) was clicked this frame,
+    /// which one was it?
+    pub fn toast_button_clicked(&self, id: ToastId) -> Option<usize> {
+        self.toasts().button_clicked(id)
+    }
+
+    /// In which corner of the screen to show the toasts. Default: [`Align2::RIGHT_BOTTOM`].
+    pub fn set_toast_anchor(&self, anchor: Align2) {
+        self.toasts().set_anchor(anchor);
+    }
+
+    // ---------------------------------------------------------------------
+
+    /// Decide what happens when a [`Hyperlink`] is clicked, e.g. to navigate within the app.
+    ///
+    /// The handler returns `true` if it took care of the link.
+    /// If it returns `false`, the link is put in [`Output::open_url`] for the backend to open.
+    ///
+    /// ```
+    /// # let ctx = egui::CtxRef::default();
+    /// ctx.set_open_url_handler(|open_url| {
+    ///     if let Some(page) = open_url.url.strip_prefix("app://") {
+    ///         // go to `page`…
+    ///         true
+    ///     } else {
+    ///         false
+    ///     }
+    /// });
+    /// ```
+    pub fn set_open_url_handler(
+        &self,
+        handler: impl Fn(&crate::output::OpenUrl) -> bool + Send + Sync + 'static,
+    ) {
+        *self.open_url_handler.lock() = Some(Arc::new(handler));
+    }
+
+    /// Go back to putting all links in [`Output::open_url`].
+    pub fn clear_open_url_handler(&self) {
+        *self.open_url_handler.lock() = None;
+    }
+
+    /// Open a link: give it to the handler set with [`Self::set_open_url_handler`],
+    /// or else put it in [`Output::open_url`].
+    pub fn open_url(&self, open_url: crate::output::OpenUrl) {
+        // Clone the handler so it can call back into the context:
+        let handler = self.open_url_handler.lock().clone();
+        if let Some(handler) = handler {
+            if handler(&open_url) {
+                return;
+            }
+        }
+        self.output().open_url = Some(open_url);
+    }
+
     pub fn layer_id_at(&self, pos: Pos2) -> Option<LayerId> {
         let resize_grab_radius_side = self.style().interaction.resize_grab_radius_side;
         self.memory().layer_id_at(pos, resize_grab_radius_side)
@@ -732,7 +891,12 @@ impl Context {
     ///
     /// The function will call [`Self::request_repaint()`] when appropriate.
     pub fn animate_bool(&self, id: Id, value: bool) -> f32 {
-        let animation_time = self.style().animation_time;
+        let style = self.style();
+        let animation_time = if style.animations {
+            style.animation_time
+        } else {
+            0.0
+        };
         let animated_value =
             self.animation_manager
                 .lock()
@@ -282,6 +282,70 @@ pub enum Key {
     Z, // Used for cmd+Z (undo)
 }
 
+/// A keyboard shortcut, e.g. `Ctrl+S`.
+///
+/// See [`crate::Context::shortcut_pressed`] and [`crate::Button::shortcut`].
+///
+/// ```
+/// use egui::{Key, KeyboardShortcut, Modifiers};
+/// let save = KeyboardShortcut::new(Modifiers { command: true, ..Default::default() }, Key::S);
+/// assert_eq!(save.to_string(), "Ctrl+S");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct KeyboardShortcut {
+    pub modifiers: Modifiers,
+    pub key: Key,
+}
+
+impl KeyboardShortcut {
+    pub fn new(modifiers: Modifiers, key: Key) -> Self {
+        Self { modifiers, key }
+    }
+
+    /// Does a press of `key` with the given modifiers trigger this shortcut?
+    ///
+    /// If the shortcut uses [`Modifiers::command`], that is compared instead of
+    /// `ctrl` and `mac_cmd`, so that the same shortcut works on Mac and elsewhere.
+    pub fn matches(&self, key: Key, modifiers: Modifiers) -> bool {
+        let command_matches = if self.modifiers.command {
+            modifiers.command
+        } else {
+            !modifiers.command
+                && self.modifiers.ctrl == modifiers.ctrl
+                && self.modifiers.mac_cmd == modifiers.mac_cmd
+        };
+        self.key == key
+            && command_matches
+            && self.modifiers.alt == modifiers.alt
+            && self.modifiers.shift == modifiers.shift
+    }
+}
+
+impl std::fmt::Display for KeyboardShortcut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Modifiers {
+            alt,
+            ctrl,
+            shift,
+            mac_cmd,
+            command,
+        } = self.modifiers;
+        if mac_cmd {
+            write!(f, "Cmd+")?;
+        } else if ctrl || command {
+            write!(f, "Ctrl+")?;
+        }
+        if alt {
+            write!(f, "Alt+")?;
+        }
+        if shift {
+            write!(f, "Shift+")?;
+        }
+        let key = format!("{:?}", self.key);
+        write!(f, "{}", key.strip_prefix("Num").unwrap_or(&key))
+    }
+}
+
 impl RawInput {
     pub fn ui(&self, ui: &mut crate::Ui) {
         #![allow(deprecated)] // for screen_size
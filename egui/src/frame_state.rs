@@ -28,7 +28,20 @@ pub(crate) struct FrameState {
 
     /// Cleared by the first `ScrollArea` that makes use of it.
     pub(crate) scroll_delta: Vec2,
-    pub(crate) scroll_target: Option<(f32, Align)>,
+    /// Where a [`crate::ScrollArea`] should scroll to, horizontally and vertically.
+    pub(crate) scroll_target: [Option<(f32, Align)>; 2],
+
+    /// Keyboard shortcuts that have already triggered something this frame,
+    /// see [`crate::Context::shortcut_pressed`].
+    pub(crate) used_shortcuts: Vec<KeyboardShortcut>,
+
+    /// Has the [`crate::DockArea`] been shown this frame?
+    /// If not, windows ignore its layout from previous frames.
+    pub(crate) dock_area_shown: bool,
+
+    /// How many menus are adding their items right now.
+    /// A [`crate::menu::menu()`] among them is a sub-menu.
+    pub(crate) menu_depth: usize,
 }
 
 impl Default for FrameState {
@@ -40,7 +53,10 @@ impl Default for FrameState {
             used_by_panels: Rect::NAN,
             tooltip_rect: None,
             scroll_delta: Vec2::ZERO,
-            scroll_target: None,
+            scroll_target: [None; 2],
+            used_shortcuts: Vec::new(),
+            dock_area_shown: false,
+            menu_depth: 0,
         }
     }
 }
@@ -55,6 +71,9 @@ impl FrameState {
             tooltip_rect,
             scroll_delta,
             scroll_target,
+            used_shortcuts,
+            dock_area_shown,
+            menu_depth,
         } = self;
 
         used_ids.clear();
@@ -63,7 +82,18 @@ impl FrameState {
         *used_by_panels = Rect::NOTHING;
         *tooltip_rect = None;
         *scroll_delta = input.scroll_delta;
-        *scroll_target = None;
+        *scroll_target = [None; 2];
+        used_shortcuts.clear();
+        *dock_area_shown = false;
+        *menu_depth = 0;
+    }
+
+    /// See [`crate::Ui::scroll_to_rect`].
+    pub(crate) fn scroll_to_rect(&mut self, rect: Rect, align: Align) {
+        for d in 0..2 {
+            let target = lerp(rect.min[d]..=rect.max[d], align.to_factor());
+            self.scroll_target[d] = Some((target, align));
+        }
     }
 
     /// How much space is still available after panels has been added.
@@ -88,6 +118,18 @@ impl FrameState {
         self.used_by_panels = self.used_by_panels.union(panel_rect);
     }
 
+    /// Shrink `available_rect`.
+    pub(crate) fn allocate_right_panel(&mut self, panel_rect: Rect) {
+        crate::egui_assert!(
+            (panel_rect.min.y - self.available_rect.min.y).abs() < 0.1
+                && panel_rect.max.x >= self.available_rect.max.x - 0.1,
+            "Mismatching right panel. You must not create a panel from within another panel."
+        );
+        self.available_rect.max.x = panel_rect.min.x;
+        self.unused_rect.max.x = panel_rect.min.x;
+        self.used_by_panels = self.used_by_panels.union(panel_rect);
+    }
+
     /// Shrink `available_rect`.
     pub(crate) fn allocate_top_panel(&mut self, panel_rect: Rect) {
         crate::egui_assert!(
@@ -36,6 +36,26 @@ impl State {
 
 // ----------------------------------------------------------------------------
 
+/// Settings for one column of a [`Grid`].
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ColumnSettings {
+    min_width: Option<f32>,
+    max_width: Option<f32>,
+    align: Align,
+}
+
+impl Default for ColumnSettings {
+    fn default() -> Self {
+        Self {
+            min_width: None,
+            max_width: None,
+            align: Align::Min,
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 pub(crate) struct GridLayout {
     ctx: CtxRef,
     style: std::sync::Arc<Style>,
@@ -51,10 +71,16 @@ pub(crate) struct GridLayout {
 
     striped: bool,
     initial_x: f32,
+    /// Right edge of the grid, used by `right_align_last_column`.
+    max_x: f32,
     min_cell_size: Vec2,
     max_cell_size: Vec2,
+    columns: Vec<ColumnSettings>,
+    right_align_last_column: bool,
     col: usize,
     row: usize,
+    /// How many columns the next cell spans.
+    span: usize,
 }
 
 impl GridLayout {
@@ -79,19 +105,55 @@ impl GridLayout {
             spacing: ui.spacing().item_spacing,
             striped: false,
             initial_x,
+            max_x: available.max.x,
             min_cell_size: ui.spacing().interact_size,
             max_cell_size: Vec2::INFINITY,
+            columns: Vec::new(),
+            right_align_last_column: false,
             col: 0,
             row: 0,
+            span: 1,
         }
     }
 }
 
 impl GridLayout {
+    fn column(&self, col: usize) -> ColumnSettings {
+        self.columns.get(col).copied().unwrap_or_default()
+    }
+
+    fn min_col_width(&self, col: usize) -> f32 {
+        self.column(col).min_width.unwrap_or(self.min_cell_size.x)
+    }
+
+    fn max_col_width(&self, col: usize) -> f32 {
+        self.column(col).max_width.unwrap_or(self.max_cell_size.x)
+    }
+
+    fn is_last_column_right_aligned(&self, col: usize) -> bool {
+        let num_columns = self.prev_state.col_widths.len();
+        self.right_align_last_column
+            && num_columns > 1
+            && col + self.span == num_columns
+            && self.max_x.is_finite()
+    }
+
     fn prev_col_width(&self, col: usize) -> f32 {
         self.prev_state
             .col_width(col)
-            .unwrap_or(self.min_cell_size.x)
+            .unwrap_or_else(|| self.min_col_width(col))
+    }
+
+    /// Width of the current cell last frame, including any spanned columns.
+    fn prev_cell_width(&self, cursor: Rect) -> Option<f32> {
+        if self.is_last_column_right_aligned(self.col) {
+            return Some(self.max_x - cursor.min.x);
+        }
+        let mut width = 0.0;
+        for col in self.col..self.col + self.span {
+            width += self.prev_state.col_width(col)?;
+        }
+        Some(width + (self.span - 1) as f32 * self.spacing.x)
     }
     fn prev_row_height(&self, row: usize) -> f32 {
         self.prev_state
@@ -100,7 +162,7 @@ impl GridLayout {
     }
 
     pub(crate) fn wrap_text(&self) -> bool {
-        self.max_cell_size.x.is_finite()
+        self.max_col_width(self.col).is_finite()
     }
 
     pub(crate) fn available_rect(&self, region: &Region) -> Rect {
@@ -109,20 +171,20 @@ impl GridLayout {
     }
 
     pub(crate) fn available_rect_finite(&self, region: &Region) -> Rect {
-        let width = if self.max_cell_size.x.is_finite() {
+        let available = region.max_rect.intersect(region.cursor);
+
+        let max_width = self.max_col_width(self.col);
+        let width = if max_width.is_finite() && self.span == 1 {
             // TODO: should probably heed `prev_state` here too
-            self.max_cell_size.x
+            max_width
         } else {
             // If we want to allow width-filling widgets like `Separator` in one of the first cells
             // then we need to make sure they don't spill out of the first cell:
-            self.prev_state
-                .col_width(self.col)
+            self.prev_cell_width(region.cursor)
                 .or_else(|| self.curr_state.col_width(self.col))
-                .unwrap_or(self.min_cell_size.x)
+                .unwrap_or_else(|| self.min_col_width(self.col))
         };
 
-        let available = region.max_rect.intersect(region.cursor);
-
         let height = region.max_rect_finite().max.y - available.top();
         let height = height
             .at_least(self.min_cell_size.y)
@@ -132,16 +194,19 @@ impl GridLayout {
     }
 
     pub(crate) fn next_cell(&self, cursor: Rect, child_size: Vec2) -> Rect {
-        let width = self.prev_state.col_width(self.col).unwrap_or(0.0);
+        let width = self.prev_cell_width(cursor).unwrap_or(0.0);
         let height = self.prev_row_height(self.row);
         let size = child_size.max(vec2(width, height));
         Rect::from_min_size(cursor.min, size)
     }
 
-    #[allow(clippy::unused_self)]
     pub(crate) fn align_size_within_rect(&self, size: Vec2, frame: Rect) -> Rect {
-        // TODO: allow this alignment to be customized
-        Align2::LEFT_CENTER.align_size_within_rect(size, frame)
+        let align = if self.is_last_column_right_aligned(self.col) {
+            Align::Max
+        } else {
+            self.column(self.col).align
+        };
+        Align2([align, Align::Center]).align_size_within_rect(size, frame)
     }
 
     pub(crate) fn justify_and_align(&self, frame: Rect, size: Vec2) -> Rect {
@@ -153,7 +218,7 @@ impl GridLayout {
         let debug_expand_height = self.style.debug.show_expand_height;
         if debug_expand_width || debug_expand_height {
             let rect = widget_rect;
-            let too_wide = rect.width() > self.prev_col_width(self.col);
+            let too_wide = self.span == 1 && rect.width() > self.prev_col_width(self.col);
             let too_high = rect.height() > self.prev_row_height(self.row);
 
             if (debug_expand_width && too_wide) || (debug_expand_height && too_high) {
@@ -171,17 +236,35 @@ impl GridLayout {
             }
         }
 
-        self.curr_state
-            .set_min_col_width(self.col, widget_rect.width().at_least(self.min_cell_size.x));
+        if self.span == 1 {
+            let width = widget_rect
+                .width()
+                .at_most(self.max_col_width(self.col))
+                .at_least(self.min_col_width(self.col));
+            self.curr_state.set_min_col_width(self.col, width);
+        } else {
+            // A spanning cell only widens the last of its columns, and only if it doesn't fit:
+            let last = self.col + self.span - 1;
+            let others: f32 = (self.col..last)
+                .map(|col| self.prev_state.col_width(col).unwrap_or(0.0) + self.spacing.x)
+                .sum();
+            let width = (widget_rect.width() - others).at_least(self.min_col_width(last));
+            self.curr_state.set_min_col_width(last, width);
+        }
         self.curr_state.set_min_row_height(
             self.row,
             widget_rect.height().at_least(self.min_cell_size.y),
         );
 
-        self.col += 1;
+        self.col += self.span;
+        self.span = 1;
         cursor.min.x += frame_rect.width() + self.spacing.x;
     }
 
+    pub(crate) fn set_span(&mut self, columns: usize) {
+        self.span = columns.at_least(1);
+    }
+
     pub(crate) fn end_row(&mut self, cursor: &mut Rect, painter: &Painter) {
         let row_height = self.prev_row_height(self.row);
 
@@ -189,11 +272,17 @@ impl GridLayout {
         cursor.min.y += row_height + self.spacing.y;
         self.col = 0;
         self.row += 1;
+        self.span = 1;
 
         if self.striped && self.row % 2 == 1 {
             if let Some(height) = self.prev_state.row_height(self.row) {
                 // Paint background for coming row:
-                let size = Vec2::new(self.prev_state.full_width(self.spacing.x), height);
+                let width = if self.right_align_last_column && self.max_x.is_finite() {
+                    self.max_x - self.initial_x
+                } else {
+                    self.prev_state.full_width(self.spacing.x)
+                };
+                let size = Vec2::new(width, height);
                 let rect = Rect::from_min_size(cursor.min, size);
                 let rect = rect.expand2(0.5 * self.spacing.y * Vec2::Y);
                 let rect = rect.expand2(2.0 * Vec2::X); // HACK: just looks better with some spacing on the sides
@@ -224,10 +313,12 @@ impl GridLayout {
 /// A simple grid layout.
 ///
 /// The cells are always layed out left to right, top-down.
-/// The contents of each cell will be aligned to the left and center.
+/// The contents of each cell will be aligned to the left and center,
+/// unless you change it with [`Self::column_align`].
 ///
 /// If you want to add multiple widgets to a cell you need to group them with
 /// [`Ui::horizontal`], [`Ui::vertical`] etc.
+/// Use [`Ui::span_columns`] to let a cell take up more than one column.
 ///
 /// ```
 /// # let ui = &mut egui::Ui::__test();
@@ -244,8 +335,32 @@ impl GridLayout {
 ///     ui.horizontal(|ui| { ui.label("Same"); ui.label("cell"); });
 ///     ui.label("Third row, second column");
 ///     ui.end_row();
+///
+///     ui.span_columns(2);
+///     ui.label("Fourth row, spanning the first two columns");
+///     ui.end_row();
 /// });
 /// ```
+///
+/// A property inspector, with the values right-aligned:
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let (mut name, mut visible) = (String::new(), true);
+/// egui::Grid::new("inspector")
+///     .striped(true)
+///     .column_min_width(0, 80.0)
+///     .right_align_last_column(true)
+///     .show(ui, |ui| {
+///         ui.label("Name");
+///         ui.text_edit_singleline(&mut name);
+///         ui.end_row();
+///
+///         ui.label("Visible");
+///         ui.checkbox(&mut visible, "");
+///         ui.end_row();
+///     });
+/// ```
 #[must_use = "You should call .show()"]
 pub struct Grid {
     id_source: Id,
@@ -254,6 +369,8 @@ pub struct Grid {
     min_row_height: Option<f32>,
     max_cell_size: Vec2,
     spacing: Option<Vec2>,
+    columns: Vec<ColumnSettings>,
+    right_align_last_column: bool,
 }
 
 impl Grid {
@@ -266,7 +383,16 @@ impl Grid {
             min_row_height: None,
             max_cell_size: Vec2::INFINITY,
             spacing: None,
+            columns: Vec::new(),
+            right_align_last_column: false,
+        }
+    }
+
+    fn column_mut(&mut self, col: usize) -> &mut ColumnSettings {
+        if self.columns.len() <= col {
+            self.columns.resize(col + 1, Default::default());
         }
+        &mut self.columns[col]
     }
 
     /// If `true`, add a subtle background color to every other row.
@@ -298,6 +424,36 @@ impl Grid {
         self
     }
 
+    /// Set the minimum width of a specific column (counting from zero),
+    /// overriding [`Self::min_col_width`].
+    pub fn column_min_width(mut self, col: usize, min_width: f32) -> Self {
+        self.column_mut(col).min_width = Some(min_width);
+        self
+    }
+
+    /// Set the soft maximum width (wrapping width) of a specific column (counting from zero),
+    /// overriding [`Self::max_col_width`].
+    pub fn column_max_width(mut self, col: usize, max_width: f32) -> Self {
+        self.column_mut(col).max_width = Some(max_width);
+        self
+    }
+
+    /// How to align the contents of a specific column (counting from zero).
+    /// Default: [`Align::Min`] (left).
+    pub fn column_align(mut self, col: usize, align: Align) -> Self {
+        self.column_mut(col).align = align;
+        self
+    }
+
+    /// If `true`, the last column fills the remaining width, with its contents aligned to the right.
+    ///
+    /// Stripes (see [`Self::striped`]) then also cover the full width.
+    /// Default: `false`.
+    pub fn right_align_last_column(mut self, right_align_last_column: bool) -> Self {
+        self.right_align_last_column = right_align_last_column;
+        self
+    }
+
     /// Set spacing between columns/rows.
     /// Default: [`crate::style::Spacing::item_spacing`].
     pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
@@ -315,6 +471,8 @@ impl Grid {
             min_row_height,
             max_cell_size,
             spacing,
+            columns,
+            right_align_last_column,
         } = self;
         let min_col_width = min_col_width.unwrap_or_else(|| ui.spacing().interact_size.x);
         let min_row_height = min_row_height.unwrap_or_else(|| ui.spacing().interact_size.y);
@@ -331,6 +489,8 @@ impl Grid {
                 spacing,
                 min_cell_size: vec2(min_col_width, min_row_height),
                 max_cell_size,
+                columns,
+                right_align_last_column,
                 ..GridLayout::new(ui, id)
             };
 
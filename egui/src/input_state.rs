@@ -180,6 +180,21 @@ impl InputState {
         self.num_presses(desired_key) > 0
     }
 
+    /// Was the given keyboard shortcut pressed this frame?
+    ///
+    /// Consider using [`crate::Context::shortcut_pressed`] instead,
+    /// which makes sure each press only triggers one action.
+    pub fn shortcut_pressed(&self, shortcut: KeyboardShortcut) -> bool {
+        self.events.iter().any(|event| match event {
+            Event::Key {
+                key,
+                pressed: true,
+                modifiers,
+            } => shortcut.matches(*key, *modifiers),
+            _ => false,
+        })
+    }
+
     /// How many times were the given key pressed this frame?
     pub fn num_presses(&self, desired_key: Key) -> usize {
         self.events
@@ -123,6 +123,13 @@ impl PaintList {
             shape.translate(delta);
         }
     }
+
+    /// Make each [`Shape`] more transparent, in-place. See [`Shape::multiply_with_opacity`].
+    pub fn multiply_with_opacity(&mut self, opacity: f32) {
+        for ClippedShape(_, shape) in &mut self.0 {
+            shape.multiply_with_opacity(opacity);
+        }
+    }
 }
 
 #[derive(Clone, Default)]
@@ -335,6 +335,7 @@ pub(crate) mod placer;
 mod response;
 mod sense;
 pub mod style;
+mod toasts;
 mod ui;
 pub mod util;
 pub mod widgets;
@@ -367,11 +368,12 @@ pub use {
     input_state::{InputState, MultiTouchInfo, PointerState},
     layers::{LayerId, Order},
     layout::*,
-    memory::Memory,
+    memory::{Memory, WindowLayout},
     painter::Painter,
     response::{InnerResponse, Response},
     sense::Sense,
     style::{Style, Visuals},
+    toasts::{Toast, ToastId, ToastKind},
     ui::Ui,
     widgets::*,
 };
@@ -1,6 +1,8 @@
 use std::collections::{HashMap, HashSet};
 
-use crate::{any, area, window, Id, InputState, LayerId, Pos2, Rect, Style};
+use crate::{
+    any, area, collapsing_header, resize, window, Id, InputState, LayerId, Pos2, Rect, Style,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -69,6 +71,10 @@ pub struct Memory {
     #[cfg_attr(feature = "persistence", serde(skip))]
     popup: Option<Id>,
 
+    /// The [`crate::Draggable`] being dragged (if any).
+    #[cfg_attr(feature = "persistence", serde(skip))]
+    pub(crate) dragged_item: Option<crate::containers::draggable::DraggedItem>,
+
     #[cfg_attr(feature = "persistence", serde(skip))]
     everything_is_visible: bool,
 }
@@ -154,6 +160,16 @@ pub(crate) struct Focus {
 
     /// Set at the beginning of the frame, set to `false` when "used".
     pressed_shift_tab: bool,
+
+    /// Only widgets in layers of this order or above can get keyboard focus,
+    /// because a modal window was shown last frame.
+    modal_layer: Option<LayerId>,
+
+    /// Set by a modal window shown this frame.
+    modal_layer_next_frame: Option<LayerId>,
+
+    /// The next widget with this id can't get focus with tab, see [`Memory::skip_tab_stop`].
+    not_a_tab_stop: Option<Id>,
 }
 
 impl Interaction {
@@ -198,6 +214,7 @@ impl Focus {
 
         self.pressed_tab = false;
         self.pressed_shift_tab = false;
+        self.modal_layer = self.modal_layer_next_frame.take();
         for event in &new_input.events {
             if matches!(
                 event,
@@ -246,7 +263,12 @@ impl Focus {
     }
 
     fn interested_in_focus(&mut self, id: Id) {
-        if self.give_to_next && !self.had_focus_last_frame(id) {
+        let tab_stop = self.not_a_tab_stop != Some(id);
+        if !tab_stop {
+            self.not_a_tab_stop = None;
+        }
+
+        if tab_stop && self.give_to_next && !self.had_focus_last_frame(id) {
             self.id = Some(id);
             self.give_to_next = false;
         } else if self.id == Some(id) {
@@ -258,12 +280,14 @@ impl Focus {
                 self.id_next_frame = self.last_interested; // frame-delay so gained_focus works
                 self.pressed_shift_tab = false;
             }
-        } else if self.pressed_tab && self.id == None && !self.give_to_next {
+        } else if tab_stop && self.pressed_tab && self.id.is_none() && !self.give_to_next {
             // nothing has focus and the user pressed tab - give focus to the first widgets that wants it:
             self.id = Some(id);
         }
 
-        self.last_interested = Some(id);
+        if tab_stop {
+            self.last_interested = Some(id);
+        }
     }
 }
 
@@ -277,6 +301,7 @@ impl Memory {
 
         if !prev_input.pointer.any_down() {
             self.window_interaction = None;
+            self.dragged_item = None; // It was dropped last frame
         }
     }
 
@@ -346,6 +371,15 @@ impl Memory {
         }
     }
 
+    /// Move keyboard focus to the next (or previous) widget, like pressing tab (or shift-tab) does.
+    pub(crate) fn move_focus(&mut self, backwards: bool) {
+        if backwards {
+            self.interaction.focus.pressed_shift_tab = true;
+        } else {
+            self.interaction.focus.pressed_tab = true;
+        }
+    }
+
     /// Register this widget as being interested in getting keyboard focus.
     /// This will allow the user to select it with tab and shift-tab.
     #[inline(always)]
@@ -353,6 +387,27 @@ impl Memory {
         self.interaction.focus.interested_in_focus(id);
     }
 
+    /// The next widget with this id can still get focus when clicked, but tab skips over it.
+    /// Used for groups of widgets that are one focus stop, like [`Ui::radio_group`].
+    pub(crate) fn skip_tab_stop(&mut self, id: Id) {
+        self.interaction.focus.not_a_tab_stop = Some(id);
+    }
+
+    /// Keep keyboard focus out of the layers below the given one next frame,
+    /// see [`crate::Window::modal`].
+    pub(crate) fn trap_focus(&mut self, layer_id: LayerId) {
+        self.interaction.focus.modal_layer_next_frame = Some(layer_id);
+    }
+
+    /// Can widgets in this layer get keyboard focus and other input?
+    /// Not if they are behind a modal window.
+    pub(crate) fn allows_focus(&self, layer_id: LayerId) -> bool {
+        match self.interaction.focus.modal_layer {
+            Some(modal_layer) => layer_id.order >= modal_layer.order,
+            None => true,
+        }
+    }
+
     /// Stop editing of active `TextEdit` (if any).
     #[inline(always)]
     pub fn stop_text_input(&mut self) {
@@ -364,6 +419,11 @@ impl Memory {
         self.interaction.drag_id.is_some()
     }
 
+    /// The [`Id`] of the [`crate::Draggable`] being dragged (if any).
+    pub fn dragged_item(&self) -> Option<Id> {
+        self.dragged_item.map(|dragged_item| dragged_item.id)
+    }
+
     #[inline(always)]
     pub fn is_being_dragged(&self, id: Id) -> bool {
         self.interaction.drag_id == Some(id)
@@ -440,6 +500,9 @@ pub struct Areas {
     /// So if you close three windows and then reopen them all in one frame,
     /// they will all be sent to the top, but keep their previous internal order.
     wants_to_be_on_top: HashSet<LayerId>,
+
+    /// Like `wants_to_be_on_top`, but for layers that should go to the back.
+    wants_to_be_at_back: HashSet<LayerId>,
 }
 
 impl Areas {
@@ -507,6 +570,7 @@ impl Areas {
 
     pub fn move_to_top(&mut self, layer_id: LayerId) {
         self.visible_current_frame.insert(layer_id);
+        self.wants_to_be_at_back.remove(&layer_id);
         self.wants_to_be_on_top.insert(layer_id);
 
         if self.order.iter().find(|x| **x == layer_id).is_none() {
@@ -514,18 +578,136 @@ impl Areas {
         }
     }
 
+    pub fn send_to_back(&mut self, layer_id: LayerId) {
+        self.wants_to_be_on_top.remove(&layer_id);
+        self.wants_to_be_at_back.insert(layer_id);
+
+        if self.order.iter().find(|x| **x == layer_id).is_none() {
+            self.order.push(layer_id);
+        }
+    }
+
     pub(crate) fn end_frame(&mut self) {
         let Self {
             visible_last_frame,
             visible_current_frame,
             order,
             wants_to_be_on_top,
+            wants_to_be_at_back,
             ..
         } = self;
 
         *visible_last_frame = std::mem::take(visible_current_frame);
-        order.sort_by_key(|layer| (layer.order, wants_to_be_on_top.contains(layer)));
+        order.sort_by_key(|layer| {
+            (
+                layer.order,
+                !wants_to_be_at_back.contains(layer),
+                wants_to_be_on_top.contains(layer),
+            )
+        });
         wants_to_be_on_top.clear();
+        wants_to_be_at_back.clear();
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The positions, sizes and stacking order of all windows and areas,
+/// and whether each window is collapsed or maximized.
+///
+/// This is the part of [`Memory`] that describes the layout of the windows,
+/// so that it can be saved and restored on its own.
+/// See [`crate::Context::window_layout`].
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+pub struct WindowLayout {
+    areas: HashMap<Id, area::State>,
+    /// Top is last
+    order: Vec<LayerId>,
+    windows: HashMap<Id, WindowState>,
+}
+
+/// The parts of a [`crate::Window`] that are not stored in its [`Areas`] state.
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[cfg_attr(feature = "persistence", serde(default))]
+struct WindowState {
+    resize: Option<resize::State>,
+    collapsing: Option<collapsing_header::State>,
+    maximize: Option<window::MaximizeState>,
+}
+
+impl Memory {
+    pub(crate) fn window_layout(&mut self) -> WindowLayout {
+        let mut windows = HashMap::new();
+        let ids: Vec<Id> = self.areas.areas.keys().copied().collect();
+        for id in ids {
+            let state = WindowState {
+                resize: self.id_data.get(&id.with("resize")).copied(),
+                collapsing: self.id_data.get(&id.with("collapsing")).copied(),
+                maximize: self.id_data.get(&id.with("maximize")).copied(),
+            };
+            if state.resize.is_some() || state.collapsing.is_some() || state.maximize.is_some() {
+                windows.insert(id, state);
+            }
+        }
+
+        WindowLayout {
+            areas: self.areas.areas.clone(),
+            order: self.areas.order.clone(),
+            windows,
+        }
+    }
+
+    pub(crate) fn set_window_layout(&mut self, layout: WindowLayout) {
+        let WindowLayout {
+            areas,
+            order,
+            windows,
+        } = layout;
+
+        self.areas.areas.extend(areas);
+        let mut new_order = order;
+        for layer_id in &self.areas.order {
+            if !new_order.contains(layer_id) {
+                new_order.push(*layer_id);
+            }
+        }
+        self.areas.order = new_order;
+
+        for (id, state) in windows {
+            self.remove_window_state(id);
+            let WindowState {
+                resize,
+                collapsing,
+                maximize,
+            } = state;
+            if let Some(resize) = resize {
+                self.id_data.insert(id.with("resize"), resize);
+            }
+            if let Some(collapsing) = collapsing {
+                self.id_data.insert(id.with("collapsing"), collapsing);
+            }
+            if let Some(maximize) = maximize {
+                self.id_data.insert(id.with("maximize"), maximize);
+            }
+        }
+    }
+
+    pub(crate) fn reset_window_layout(&mut self) {
+        let ids: Vec<Id> = self.areas.areas.keys().copied().collect();
+        for id in ids {
+            self.remove_window_state(id);
+        }
+        self.areas.areas.clear();
+        self.areas.order.clear();
+    }
+
+    fn remove_window_state(&mut self, id: Id) {
+        self.id_data.remove(&id.with("resize"));
+        self.id_data.remove(&id.with("collapsing"));
+        self.id_data.remove(&id.with("maximize"));
     }
 }
 
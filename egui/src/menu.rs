@@ -14,8 +14,10 @@
 //!     });
 //! }
 //! ```
+//!
+//! Context menus are shown with [`Response::context_menu`].
 
-use crate::{widgets::*, *};
+use crate::{input_state::PointerEvent, widgets::*, *};
 use epaint::Stroke;
 
 /// What is saved between frames.
@@ -60,6 +62,12 @@ pub fn bar<R>(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResp
 }
 
 /// Construct a top level menu in a menu bar. This would be e.g. "File", "Edit" etc.
+///
+/// Inside of another menu this adds a sub-menu instead, which opens to the side
+/// when its item is hovered or clicked.
+///
+/// In an open menu, the up and down arrow keys move between the items,
+/// the right arrow opens a sub-menu and the left arrow closes it again.
 pub fn menu(ui: &mut Ui, title: impl ToString, add_contents: impl FnOnce(&mut Ui)) {
     menu_impl(ui, title, Box::new(add_contents))
 }
@@ -69,35 +77,127 @@ fn menu_impl<'c>(ui: &mut Ui, title: impl ToString, add_contents: Box<dyn FnOnce
     let title = title.to_string();
     let bar_id = ui.id();
     let menu_id = bar_id.with(&title);
+    let is_sub_menu = ui.ctx().frame_state().menu_depth > 0;
 
     let mut bar_state = BarState::load(ui.ctx(), &bar_id);
+    let was_open = bar_state.open_menu == Some(menu_id);
 
-    let mut button = Button::new(title);
+    let mut button = if is_sub_menu {
+        Button::new(format!("{} ⏵", title))
+    } else {
+        Button::new(title)
+    };
 
-    if bar_state.open_menu == Some(menu_id) {
+    if was_open {
         button = button.fill(Some(ui.visuals().selection.bg_fill));
     }
 
     let button_response = ui.add(button);
     if button_response.clicked() {
-        // Toggle
-        if bar_state.open_menu == Some(menu_id) {
+        // Toggle, except that clicking a sub-menu that opened on hover shouldn't close it:
+        if was_open && !is_sub_menu {
             bar_state.open_menu = None;
         } else {
             bar_state.open_menu = Some(menu_id);
         }
-    } else if button_response.hovered() && bar_state.open_menu.is_some() {
+    } else if button_response.hovered()
+        && !was_open
+        && (is_sub_menu || bar_state.open_menu.is_some())
+    {
+        // Don't switch to this menu while the pointer is on its way to the open sibling:
+        let open_rect = bar_state.open_menu.and_then(|open_menu| {
+            let area_id = Area::new(open_menu).layer().id;
+            ui.memory().areas.get(area_id).map(|state| state.rect())
+        });
+        let moving_towards_open = match open_rect {
+            Some(open_rect) => is_sub_menu && is_moving_towards(ui.ctx(), open_rect),
+            None => false,
+        };
+        if !moving_towards_open {
+            bar_state.open_menu = Some(menu_id);
+        }
+    } else if is_sub_menu
+        && !was_open
+        && button_response.has_focus()
+        && ui.input().key_pressed(Key::ArrowRight)
+    {
         bar_state.open_menu = Some(menu_id);
+        // Give the focus to the first item of the sub-menu:
+        let mut memory = ui.memory();
+        memory.surrender_focus(button_response.id);
+        memory.move_focus(false);
     }
 
+    let mut close = false;
     if bar_state.open_menu == Some(menu_id) || ui.ctx().memory().everything_is_visible() {
-        let area = Area::new(menu_id)
-            .order(Order::Foreground)
-            .fixed_pos(button_response.rect.left_bottom());
-        let frame = Frame::menu(ui.style());
+        let pos = if is_sub_menu {
+            button_response.rect.right_top()
+        } else {
+            button_response.rect.left_bottom()
+        };
+        let open_menu = menu_area(ui.ctx(), menu_id, pos, add_contents);
+
+        // Clicking an item in a sub-menu closes the sub-menu first, and then us:
+        close = ui.input().key_pressed(Key::Escape)
+            || button_response.clicked_elsewhere() && !open_menu.sub_menu_is_open;
+        if is_sub_menu
+            && was_open
+            && !open_menu.sub_menu_was_open
+            && ui.input().key_pressed(Key::ArrowLeft)
+        {
+            close = true;
+            ui.memory().request_focus(button_response.id);
+        }
+        if close {
+            BarState::default().save(ui.ctx(), open_menu.content_id);
+        }
+    }
+    if close {
+        bar_state.open_menu = None;
+    }
+
+    bar_state.save(ui.ctx(), bar_id);
+}
+
+/// Add the items of a menu, where any [`menu()`] is a sub-menu.
+fn add_menu_contents(ui: &mut Ui, add_contents: impl FnOnce(&mut Ui)) {
+    ui.ctx().frame_state().menu_depth += 1;
+    add_contents(ui);
+    ui.ctx().frame_state().menu_depth -= 1;
+}
+
+/// Is the pointer moving towards the left side of `rect`?
+fn is_moving_towards(ctx: &Context, rect: Rect) -> bool {
+    let pointer = &ctx.input().pointer;
+    let pos = match pointer.hover_pos() {
+        Some(pos) => pos,
+        None => return false,
+    };
+    let velocity = pointer.velocity();
+    let to_top = rect.left_top() - pos;
+    let to_bottom = rect.left_bottom() - pos;
+    // The velocity must lie between the directions to the two corners:
+    velocity.x > 0.0
+        && to_top.x * velocity.y - to_top.y * velocity.x >= 0.0
+        && velocity.x * to_bottom.y - velocity.y * to_bottom.x >= 0.0
+}
+
+struct OpenMenu {
+    /// The id of the [`Ui`] holding the items, which is where sub-menus remember if they are open.
+    content_id: Id,
+    sub_menu_was_open: bool,
+    sub_menu_is_open: bool,
+}
 
-        area.show(ui.ctx(), |ui| {
-            frame.show(ui, |ui| {
+/// Show the items of an open menu in a popup at `pos`.
+fn menu_area(ctx: &CtxRef, menu_id: Id, pos: Pos2, add_contents: impl FnOnce(&mut Ui)) -> OpenMenu {
+    let mut content_id = menu_id;
+    let mut sub_menu_was_open = false;
+    Area::new(menu_id)
+        .order(Order::Foreground)
+        .fixed_pos(pos)
+        .show(ctx, |ui| {
+            Frame::menu(ui.style()).show(ui, |ui| {
                 let mut style = (**ui.style()).clone();
                 style.spacing.button_padding = vec2(2.0, 0.0);
                 // style.visuals.widgets.active.bg_fill = Color32::TRANSPARENT;
@@ -107,15 +207,142 @@ fn menu_impl<'c>(ui: &mut Ui, title: impl ToString, add_contents: Box<dyn FnOnce
                 style.visuals.widgets.inactive.bg_fill = Color32::TRANSPARENT;
                 style.visuals.widgets.inactive.bg_stroke = Stroke::none();
                 ui.set_style(style);
-                ui.with_layout(Layout::top_down_justified(Align::LEFT), add_contents);
+                ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                    content_id = ui.id();
+                    sub_menu_was_open = BarState::load(ctx, &content_id).open_menu.is_some();
+
+                    // Only the innermost open menu reacts to the arrow keys:
+                    if !sub_menu_was_open {
+                        if ui.input().key_pressed(Key::ArrowDown) {
+                            ui.memory().move_focus(false);
+                        } else if ui.input().key_pressed(Key::ArrowUp) {
+                            ui.memory().move_focus(true);
+                        }
+                    }
+
+                    add_menu_contents(ui, add_contents);
+                });
             });
         });
 
-        // TODO: this prevents sub-menus in menus. We should fix that.
-        if ui.input().key_pressed(Key::Escape) || button_response.clicked_elsewhere() {
-            bar_state.open_menu = None;
+    OpenMenu {
+        content_id,
+        sub_menu_was_open,
+        sub_menu_is_open: BarState::load(ctx, &content_id).open_menu.is_some(),
+    }
+}
+
+/// Show a menu at the pointer when the widget is secondary-clicked (e.g. right-clicked).
+///
+/// The menu closes when one of its items is clicked, when clicking outside it, or on escape.
+/// Use [`crate::menu::menu()`] inside of it for sub-menus.
+///
+/// You would usually call this via [`Response::context_menu`].
+pub fn context_menu(response: &Response, add_contents: impl FnOnce(&mut Ui)) {
+    let ctx = &response.ctx;
+    let menu_id = response.id.with("__context_menu");
+
+    let secondary_clicked = response.secondary_clicked()
+        || response.hovered()
+            && ctx.input().pointer.pointer_events.iter().any(|event| {
+                matches!(
+                    event,
+                    PointerEvent::Released(Some(click)) if click.button == PointerButton::Secondary
+                )
+            });
+
+    let mut just_opened = false;
+    if secondary_clicked {
+        if let Some(pos) = ctx.input().pointer.interact_pos() {
+            let mut memory = ctx.memory();
+            memory.id_data_temp.insert(menu_id, pos);
+            memory.open_popup(menu_id);
+            just_opened = true;
         }
     }
 
-    bar_state.save(ui.ctx(), bar_id);
+    if !ctx.memory().is_popup_open(menu_id) {
+        return;
+    }
+    let pos = match ctx.memory().id_data_temp.get::<Pos2>(&menu_id).copied() {
+        Some(pos) => pos,
+        None => {
+            ctx.memory().close_popup();
+            return;
+        }
+    };
+
+    let open_menu = menu_area(ctx, menu_id, pos, add_contents);
+
+    let close = ctx.input().key_pressed(Key::Escape)
+        || ctx.input().pointer.any_click() && !open_menu.sub_menu_is_open;
+    if !just_opened && close {
+        ctx.memory().close_popup();
+        BarState::default().save(ctx, open_menu.content_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key_press(key: Key) -> RawInput {
+        RawInput {
+            events: vec![Event::Key {
+                key,
+                pressed: true,
+                modifiers: Default::default(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn shortcuts_of_closed_menu() {
+        let delete = KeyboardShortcut::new(Default::default(), Key::Delete);
+        let mut ctx = CtxRef::default();
+        ctx.begin_frame(key_press(Key::Delete));
+        let mut do_delete = ctx.shortcut_pressed(delete);
+        let mut menu_shown = false;
+        CentralPanel::default().show(&ctx, |ui| {
+            bar(ui, |ui| {
+                menu(ui, "Edit", |ui| {
+                    menu_shown = true;
+                    do_delete |= ui.add(Button::new("Delete").shortcut(delete)).clicked();
+                });
+            });
+        });
+        let _ = ctx.end_frame();
+
+        assert!(!menu_shown, "the items of a closed menu are not added");
+        assert!(do_delete, "a shortcut without modifiers works");
+    }
+
+    #[test]
+    fn vertical_menu_outside_menu_is_not_sub_menu() {
+        let mut ctx = CtxRef::default();
+        let run = |ctx: &mut CtxRef, input: RawInput| {
+            let mut bar = None;
+            ctx.begin_frame(input);
+            CentralPanel::default().show(ctx, |ui| {
+                ui.vertical(|ui| {
+                    menu(ui, "File", |ui| {
+                        ui.label("Open");
+                    });
+                    bar = Some((ui.id(), ui.min_rect()));
+                });
+            });
+            let _ = ctx.end_frame();
+            bar.unwrap()
+        };
+
+        let (bar_id, rect) = run(&mut ctx, Default::default());
+        let hover = RawInput {
+            events: vec![Event::PointerMoved(rect.center())],
+            ..Default::default()
+        };
+        run(&mut ctx, hover);
+        // A sub-menu would open on hover, but a top level menu waits for a click:
+        assert!(BarState::load(&ctx, &bar_id).open_menu.is_none());
+    }
 }
@@ -33,6 +33,12 @@ impl Placer {
         self.grid.as_ref()
     }
 
+    pub(crate) fn set_grid_span(&mut self, columns: usize) {
+        if let Some(grid) = &mut self.grid {
+            grid.set_span(columns);
+        }
+    }
+
     #[inline(always)]
     pub(crate) fn is_grid(&self) -> bool {
         self.grid.is_some()
@@ -68,10 +74,20 @@ impl Placer {
         self.region.min_rect = min_rect;
     }
 
+    #[inline(always)]
+    pub(crate) fn force_set_max_rect(&mut self, max_rect: Rect) {
+        self.region.max_rect = max_rect;
+    }
+
     #[inline(always)]
     pub(crate) fn cursor(&self) -> Rect {
         self.region.cursor
     }
+
+    #[inline(always)]
+    pub(crate) fn set_cursor(&mut self, cursor: Rect) {
+        self.region.cursor = cursor;
+    }
 }
 
 impl Placer {
@@ -1,5 +1,5 @@
 use crate::{
-    emath::{lerp, Align, Pos2, Rect, Vec2},
+    emath::{Align, Pos2, Rect, Vec2},
     CursorIcon, PointerButton, NUM_POINTER_BUTTONS,
 };
 use crate::{CtxRef, Id, LayerId, Sense, Ui};
@@ -293,15 +293,32 @@ impl Response {
     ///
     /// The text will not be visible if the widget is not enabled.
     /// If you call this multiple times the tooltips will stack underneath the previous ones.
+    ///
+    /// See [`crate::Tooltip`] for more options, e.g. a delay or interactive contents.
     pub fn on_hover_ui(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
-        if self.should_show_hover_ui() {
-            crate::containers::show_tooltip_under(
-                &self.ctx,
-                self.id.with("__tooltip"),
-                &self.rect,
-                add_contents,
-            );
-        }
+        crate::Tooltip::new().show(&self, add_contents);
+        self
+    }
+
+    /// Show a menu at the pointer when the widget is secondary-clicked (e.g. right-clicked).
+    ///
+    /// See [`crate::menu::context_menu`] for details.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// ui.label("Right-click me").context_menu(|ui| {
+    ///     if ui.button("Copy").clicked() {
+    ///         // …
+    ///     }
+    ///     egui::menu::menu(ui, "More", |ui| {
+    ///         if ui.button("Delete").clicked() {
+    ///             // …
+    ///         }
+    ///     });
+    /// });
+    /// ```
+    pub fn context_menu(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
+        crate::menu::context_menu(&self, add_contents);
         self
     }
 
@@ -320,17 +337,11 @@ impl Response {
 
     /// Like `on_hover_ui`, but show the ui next to cursor.
     pub fn on_hover_ui_at_pointer(self, add_contents: impl FnOnce(&mut Ui)) -> Self {
-        if self.should_show_hover_ui() {
-            crate::containers::show_tooltip_at_pointer(
-                &self.ctx,
-                self.id.with("__tooltip"),
-                add_contents,
-            );
-        }
+        crate::Tooltip::new().at_pointer().show(&self, add_contents);
         self
     }
 
-    fn should_show_hover_ui(&self) -> bool {
+    pub(crate) fn should_show_hover_ui(&self) -> bool {
         if self.ctx.memory().everything_is_visible() {
             true
         } else if self.hovered && self.ctx.input().pointer.has_pointer() {
@@ -420,8 +431,7 @@ impl Response {
     /// });
     /// ```
     pub fn scroll_to_me(&self, align: Align) {
-        let scroll_target = lerp(self.rect.y_range(), align.to_factor());
-        self.ctx.frame_state().scroll_target = Some((scroll_target, align));
+        self.ctx.frame_state().scroll_to_rect(self.rect, align);
     }
 
     /// For accessibility.
@@ -45,6 +45,10 @@ pub struct Style {
     /// How many seconds a typical animation should last.
     pub animation_time: f32,
 
+    /// If `false`, everything that is normally animated (windows opening and closing,
+    /// collapsing headers, …) will change instantly.
+    pub animations: bool,
+
     /// Options to help debug why egui behaves strangely.
     pub debug: DebugOptions,
 }
@@ -149,6 +153,10 @@ pub struct Interaction {
 
     /// If `false`, tooltips will show up anytime you hover anything, even is mouse is still moving
     pub show_tooltips_only_when_still: bool,
+
+    /// How many seconds a widget must be hovered before its tooltip shows.
+    /// Can be overridden with [`crate::Tooltip::delay`].
+    pub tooltip_delay: f32,
 }
 
 /// Controls the visual style (colors etc) of egui.
@@ -333,6 +341,7 @@ impl Default for Style {
             interaction: Interaction::default(),
             visuals: Visuals::default(),
             animation_time: 1.0 / 12.0,
+            animations: true,
             debug: Default::default(),
         }
     }
@@ -361,6 +370,7 @@ impl Default for Interaction {
             resize_grab_radius_side: 5.0,
             resize_grab_radius_corner: 10.0,
             show_tooltips_only_when_still: false,
+            tooltip_delay: 0.0,
         }
     }
 }
@@ -515,6 +525,7 @@ impl Style {
             interaction,
             visuals,
             animation_time,
+            animations,
             debug,
         } = self;
 
@@ -539,6 +550,7 @@ impl Style {
                 }
             });
 
+        ui.checkbox(animations, "Animations");
         ui.add(
             Slider::new(animation_time, 0.0..=1.0)
                 .text("animation durations")
@@ -591,6 +603,7 @@ impl Interaction {
             resize_grab_radius_side,
             resize_grab_radius_corner,
             show_tooltips_only_when_still,
+            tooltip_delay,
         } = self;
         ui.add(Slider::new(resize_grab_radius_side, 0.0..=20.0).text("resize_grab_radius_side"));
         ui.add(
@@ -600,6 +613,7 @@ impl Interaction {
             show_tooltips_only_when_still,
             "Only show tooltips if mouse is still",
         );
+        ui.add(Slider::new(tooltip_delay, 0.0..=2.0).text("tooltip_delay"));
 
         ui.vertical_centered(|ui| reset_button(ui, self));
     }
@@ -0,0 +1,265 @@
+//! Notifications shown in a corner of the screen, see [`Toast`] and [`Context::notify`].
+
+use crate::*;
+
+/// What kind of notification a [`Toast`] is. Decides its icon.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ToastKind {
+    Info,
+    Success,
+    Warning,
+    Error,
+}
+
+impl ToastKind {
+    fn icon(self) -> (&'static str, Color32) {
+        match self {
+            ToastKind::Info => ("ℹ", Color32::LIGHT_BLUE),
+            ToastKind::Success => ("✔", Color32::GREEN),
+            ToastKind::Warning => ("⚠", Color32::YELLOW),
+            ToastKind::Error => ("❗", Color32::RED),
+        }
+    }
+}
+
+/// Identifies a [`Toast`] after it has been queued with [`Context::notify`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub struct ToastId(u64);
+
+/// A notification, shown in a corner of the screen with [`Context::notify`].
+///
+/// Toasts stack on top of each other, fade in and out,
+/// and can be closed by the user or with [`Context::dismiss_toast`].
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// # let ctx = &ctx;
+/// ctx.notify(egui::Toast::info("Saved").duration(3.0));
+///
+/// let toast = ctx.notify(egui::Toast::warning("File deleted").button("Undo"));
+/// // later frames:
+/// if ctx.toast_button_clicked(toast) == Some(0) {
+///     // undo…
+/// }
+/// ```
+#[derive(Clone, Debug)]
+#[must_use = "You should pass this to `Context::notify`"]
+pub struct Toast {
+    kind: ToastKind,
+    text: String,
+    duration: f32,
+    buttons: Vec<String>,
+}
+
+impl Toast {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(kind: ToastKind, text: impl ToString) -> Self {
+        Self {
+            kind,
+            text: text.to_string(),
+            duration: 5.0,
+            buttons: Vec::new(),
+        }
+    }
+
+    pub fn info(text: impl ToString) -> Self {
+        Self::new(ToastKind::Info, text)
+    }
+
+    pub fn success(text: impl ToString) -> Self {
+        Self::new(ToastKind::Success, text)
+    }
+
+    pub fn warning(text: impl ToString) -> Self {
+        Self::new(ToastKind::Warning, text)
+    }
+
+    pub fn error(text: impl ToString) -> Self {
+        Self::new(ToastKind::Error, text)
+    }
+
+    /// How many seconds to show the toast before it fades out.
+    /// Use `f32::INFINITY` to keep it until it is closed.
+    /// Default: 5 seconds.
+    pub fn duration(mut self, seconds: f32) -> Self {
+        self.duration = seconds;
+        self
+    }
+
+    /// Add a button to the toast. Clicking it closes the toast.
+    ///
+    /// See [`Context::toast_button_clicked`].
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn button(mut self, text: impl ToString) -> Self {
+        self.buttons.push(text.to_string());
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+struct ActiveToast {
+    id: ToastId,
+    toast: Toast,
+    /// When the toast was first shown, in [`InputState::time`].
+    shown_at: Option<f64>,
+    /// When the toast started fading out, e.g. because it was closed.
+    closed_at: Option<f64>,
+}
+
+/// The toasts of a [`Context`].
+pub(crate) struct Toasts {
+    anchor: Align2,
+    next_id: u64,
+    toasts: Vec<ActiveToast>,
+    /// The buttons clicked this frame.
+    clicked: Vec<(ToastId, usize)>,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self {
+            anchor: Align2::RIGHT_BOTTOM,
+            next_id: 0,
+            toasts: Vec::new(),
+            clicked: Vec::new(),
+        }
+    }
+}
+
+impl Toasts {
+    pub(crate) fn add(&mut self, toast: Toast) -> ToastId {
+        let id = ToastId(self.next_id);
+        self.next_id += 1;
+        self.toasts.push(ActiveToast {
+            id,
+            toast,
+            shown_at: None,
+            closed_at: None,
+        });
+        id
+    }
+
+    pub(crate) fn dismiss(&mut self, id: ToastId, time: f64) {
+        for toast in &mut self.toasts {
+            if toast.id == id && toast.closed_at.is_none() {
+                toast.closed_at = Some(time);
+            }
+        }
+    }
+
+    pub(crate) fn set_anchor(&mut self, anchor: Align2) {
+        self.anchor = anchor;
+    }
+
+    pub(crate) fn button_clicked(&self, id: ToastId) -> Option<usize> {
+        self.clicked
+            .iter()
+            .find(|(toast_id, _)| *toast_id == id)
+            .map(|(_, button)| *button)
+    }
+}
+
+/// Show all toasts. Called at the start of each frame.
+pub(crate) fn show(ctx: &CtxRef) {
+    let time = ctx.input().time;
+    let animation_time = if ctx.style().animations {
+        ctx.style().animation_time
+    } else {
+        0.0
+    };
+
+    // Take the toasts out, so that the user can call `Context::notify` from a toast.
+    let (anchor, mut toasts) = {
+        let mut state = ctx.toasts();
+        state.clicked.clear();
+        (state.anchor, std::mem::take(&mut state.toasts))
+    };
+    toasts.retain(|toast| match toast.closed_at {
+        Some(closed_at) => time - closed_at < animation_time as f64,
+        None => true,
+    });
+    if toasts.is_empty() {
+        // Put back any toasts added in the meantime:
+        let mut state = ctx.toasts();
+        toasts.append(&mut state.toasts);
+        state.toasts = toasts;
+        return;
+    }
+
+    let margin = ctx.style().spacing.window_padding;
+    let screen = ctx.available_rect().shrink2(margin);
+    let mut offset = 0.0;
+    let mut clicked = Vec::new();
+
+    for toast in &mut toasts {
+        let shown_at = *toast.shown_at.get_or_insert(time);
+        if toast.closed_at.is_none() && time - shown_at > toast.toast.duration as f64 {
+            toast.closed_at = Some(shown_at + toast.toast.duration as f64);
+        }
+
+        let fade_in = ((time - shown_at) as f32 / animation_time).min(1.0);
+        let fade_out = match toast.closed_at {
+            Some(closed_at) => 1.0 - ((time - closed_at) as f32 / animation_time).min(1.0),
+            None => 1.0,
+        };
+        let opacity = fade_in.min(fade_out);
+
+        let area_id = Id::new("__egui::toast").with(toast.id);
+        let size = ctx
+            .memory()
+            .areas
+            .get(area_id)
+            .map_or(Vec2::ZERO, |state| state.size);
+        let mut pos = anchor.align_size_within_rect(size, screen).min;
+        // Stack away from the corner, and slide in from the side:
+        let stack_dir = if anchor.y() == Align::Min { 1.0 } else { -1.0 };
+        pos.y += stack_dir * offset;
+        let slide_dir = if anchor.x() == Align::Min { -1.0 } else { 1.0 };
+        pos.x += slide_dir * (1.0 - opacity) * 0.5 * size.x;
+        offset += opacity * (size.y + margin.y);
+
+        let response = Area::new(area_id)
+            .order(Order::Tooltip)
+            .fixed_pos(pos)
+            .show(ctx, |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.set_max_width(ui.spacing().tooltip_width);
+                    ui.horizontal(|ui| {
+                        let (icon, color) = toast.toast.kind.icon();
+                        ui.colored_label(color, icon);
+                        ui.label(&toast.toast.text);
+                        if ui.small_button("🗙").clicked() {
+                            toast.closed_at = Some(time);
+                        }
+                    });
+                    if !toast.toast.buttons.is_empty() {
+                        ui.horizontal(|ui| {
+                            for (i, button) in toast.toast.buttons.iter().enumerate() {
+                                if ui.button(button).clicked() {
+                                    clicked.push((toast.id, i));
+                                    toast.closed_at = Some(time);
+                                }
+                            }
+                        });
+                    }
+                });
+            });
+
+        if opacity < 1.0 {
+            ctx.graphics()
+                .list(response.layer_id)
+                .lock()
+                .multiply_with_opacity(opacity);
+        }
+    }
+
+    // Toasts are timed, so keep repainting while there are any:
+    ctx.request_repaint();
+
+    let mut state = ctx.toasts();
+    toasts.append(&mut state.toasts);
+    state.toasts = toasts;
+    state.clicked = clicked;
+}
@@ -333,6 +333,12 @@ impl Ui {
         self.placer.force_set_min_rect(min_rect)
     }
 
+    /// Used by panels to take a part of the `Ui`. Unlike [`Self::set_max_width`] this can make
+    /// `max_rect` smaller than `min_rect`, so that the following widgets don't overlap the panel.
+    pub(crate) fn force_set_max_rect(&mut self, max_rect: Rect) {
+        self.placer.force_set_max_rect(max_rect)
+    }
+
     // ------------------------------------------------------------------------
 
     /// Set the maximum size of the ui.
@@ -685,6 +691,10 @@ impl Ui {
         self.placer.cursor()
     }
 
+    pub(crate) fn set_cursor(&mut self, cursor: Rect) {
+        self.placer.set_cursor(cursor)
+    }
+
     /// Where do we expect a zero-sized widget to be placed?
     pub(crate) fn next_widget_position(&self) -> Pos2 {
         self.placer.next_widget_position()
@@ -798,6 +808,8 @@ impl Ui {
 
     /// Move the scroll to this cursor position with the specified alignment.
     ///
+    /// In a horizontal layout this scrolls horizontally, otherwise vertically.
+    ///
     /// ```
     /// # use egui::Align;
     /// # let mut ui = &mut egui::Ui::__test();
@@ -813,8 +825,30 @@ impl Ui {
     /// });
     /// ```
     pub fn scroll_to_cursor(&mut self, align: Align) {
-        let target_y = self.next_widget_position().y;
-        self.ctx().frame_state().scroll_target = Some((target_y, align));
+        let d = if self.layout().is_horizontal() { 0 } else { 1 };
+        let target = self.next_widget_position()[d];
+        self.ctx().frame_state().scroll_target[d] = Some((target, align));
+    }
+
+    /// Scroll the surrounding [`ScrollArea`] so that `rect` is visible,
+    /// e.g. to keep the selected row of a list in view.
+    ///
+    /// `align` decides where `rect` ends up: at the top/left ([`Align::Min`]),
+    /// in the center, or at the bottom/right ([`Align::Max`]) of the visible area.
+    ///
+    /// ```
+    /// # use egui::Align;
+    /// # let mut ui = &mut egui::Ui::__test();
+    /// # let search_result_rect = egui::Rect::from_min_size(egui::pos2(0.0, 500.0), egui::vec2(100.0, 20.0));
+    /// egui::ScrollArea::auto_sized().show(ui, |ui| {
+    ///     for i in 0..100 {
+    ///         ui.label(format!("Line {}", i));
+    ///     }
+    ///     ui.scroll_to_rect(search_result_rect, Align::Center);
+    /// });
+    /// ```
+    pub fn scroll_to_rect(&self, rect: Rect, align: Align) {
+        self.ctx().frame_state().scroll_to_rect(rect, align);
     }
 }
 
@@ -1043,6 +1077,79 @@ impl Ui {
         response
     }
 
+    /// Show a [`RadioButton`] for each `(value, text)` option, in the current layout.
+    /// The one with `*current_value == value` is selected, and clicking one selects it.
+    ///
+    /// The whole group is one stop when moving focus with tab.
+    /// Press the arrow keys to move the selection within the group.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// #[derive(PartialEq)]
+    /// enum Quality { Low, Medium, High }
+    /// let mut quality = Quality::Medium;
+    ///
+    /// ui.horizontal(|ui| {
+    ///     ui.radio_group(
+    ///         &mut quality,
+    ///         vec![(Quality::Low, "Low"), (Quality::Medium, "Medium"), (Quality::High, "High")],
+    ///     );
+    /// });
+    /// ```
+    pub fn radio_group<Value: PartialEq, Text: ToString>(
+        &mut self,
+        current_value: &mut Value,
+        options: impl IntoIterator<Item = (Value, Text)>,
+    ) -> Response {
+        let options: Vec<(Value, Text)> = options.into_iter().collect();
+        let selected = options
+            .iter()
+            .position(|(value, _)| *value == *current_value);
+        // Only the selected button (or the first one) can get focus with tab:
+        let tab_stop = selected.unwrap_or(0);
+
+        let responses: Vec<Response> = options
+            .iter()
+            .enumerate()
+            .map(|(index, (_, text))| {
+                RadioButton::new(selected == Some(index), text.to_string())
+                    .tab_stop(index == tab_stop)
+                    .ui(self)
+            })
+            .collect();
+
+        let mut new_selection = responses.iter().position(|response| response.clicked());
+        if let Some(index) = responses.iter().position(|response| response.has_focus()) {
+            let input = self.input();
+            let (back, forward) = if self.layout().prefer_right_to_left() {
+                (Key::ArrowRight, Key::ArrowLeft)
+            } else {
+                (Key::ArrowLeft, Key::ArrowRight)
+            };
+            let len = responses.len();
+            if input.key_pressed(forward) || input.key_pressed(Key::ArrowDown) {
+                new_selection = Some((index + 1) % len);
+            } else if input.key_pressed(back) || input.key_pressed(Key::ArrowUp) {
+                new_selection = Some((index + len - 1) % len);
+            }
+        }
+
+        let mut response = match responses.iter().cloned().reduce(|a, b| a.union(b)) {
+            Some(response) => response,
+            None => return self.allocate_response(Vec2::ZERO, Sense::hover()),
+        };
+        if let Some(index) = new_selection {
+            responses[index].request_focus();
+            if selected != Some(index) {
+                if let Some((value, _)) = options.into_iter().nth(index) {
+                    *current_value = value;
+                    response.mark_changed();
+                }
+            }
+        }
+        response
+    }
+
     /// Show a label which can be selected or not.
     ///
     /// See also [`SelectableLabel`].
@@ -1216,6 +1323,8 @@ impl Ui {
     ///     ui.label("Within a frame");
     /// });
     /// ```
+    ///
+    /// See also [`GroupBox`], for a group with a caption.
     pub fn group<R>(&mut self, add_contents: impl FnOnce(&mut Ui) -> R) -> InnerResponse<R> {
         crate::Frame::group(self.style()).show(self, add_contents)
     }
@@ -1566,6 +1675,12 @@ impl Ui {
             .end_row(self.spacing().item_spacing, &self.painter().clone());
     }
 
+    /// In a [`Grid`], let the next cell span this many columns.
+    /// Otherwise does nothing.
+    pub fn span_columns(&mut self, columns: usize) {
+        self.placer.set_grid_span(columns);
+    }
+
     /// Set row height in horizontal wrapping layout.
     pub fn set_row_height(&mut self, height: f32) {
         self.placer.set_row_height(height);
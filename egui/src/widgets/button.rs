@@ -1,6 +1,12 @@
 use crate::*;
 
-/// Clickable button with text.
+/// The icon of a [`Button`], see [`Button::image`] and [`Button::icon`].
+enum ButtonIcon {
+    Image(TextureId, Vec2),
+    Glyph(String),
+}
+
+/// Clickable button with text, and optionally an icon before the text.
 ///
 /// See also [`Ui::button`].
 ///
@@ -9,6 +15,14 @@ use crate::*;
 /// if ui.add(egui::Button::new("Click mew")).clicked() {
 ///     do_stuff();
 /// }
+///
+/// # let save_texture_id = egui::TextureId::Egui;
+/// if ui.add(egui::Button::new("Save").image(save_texture_id, [16.0, 16.0])).clicked() {
+///     do_stuff();
+/// }
+/// if ui.add(egui::Button::new("Undo").icon("⟲").large()).clicked() {
+///     do_stuff();
+/// }
 /// # fn do_stuff() {}
 /// ```
 #[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
@@ -20,9 +34,14 @@ pub struct Button {
     fill: Option<Color32>,
     sense: Sense,
     small: bool,
+    large: bool,
     frame: bool,
     wrap: Option<bool>,
     min_size: Vec2,
+    shortcut: Option<KeyboardShortcut>,
+    icon: Option<ButtonIcon>,
+    icon_spacing: Option<f32>,
+    align: Option<Align>,
 }
 
 impl Button {
@@ -35,9 +54,14 @@ impl Button {
             fill: Default::default(),
             sense: Sense::click(),
             small: false,
+            large: false,
             frame: true,
             wrap: None,
             min_size: Vec2::ZERO,
+            shortcut: None,
+            icon: None,
+            icon_spacing: None,
+            align: None,
         }
     }
 
@@ -65,6 +89,40 @@ impl Button {
     pub fn small(mut self) -> Self {
         self.text_style = Some(TextStyle::Body);
         self.small = true;
+        self.large = false;
+        self
+    }
+
+    /// Make this a large button, with twice the usual padding, e.g. for a toolbar.
+    pub fn large(mut self) -> Self {
+        self.small = false;
+        self.large = true;
+        self
+    }
+
+    /// Show an image before the text.
+    pub fn image(mut self, texture_id: TextureId, size: impl Into<Vec2>) -> Self {
+        self.icon = Some(ButtonIcon::Image(texture_id, size.into()));
+        self
+    }
+
+    /// Show a glyph (e.g. an emoji) before the text, in the same text style as the text.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn icon(mut self, icon: impl ToString) -> Self {
+        self.icon = Some(ButtonIcon::Glyph(icon.to_string()));
+        self
+    }
+
+    /// The space between the icon and the text. Default: [`crate::style::Spacing::item_spacing`]`.x`.
+    pub fn icon_spacing(mut self, icon_spacing: f32) -> Self {
+        self.icon_spacing = Some(icon_spacing);
+        self
+    }
+
+    /// Where to put the icon and text when the button is wider than them.
+    /// Default: the horizontal alignment of the [`Layout`].
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = Some(align);
         self
     }
 
@@ -103,6 +161,29 @@ impl Button {
         self
     }
 
+    /// Show this keyboard shortcut right-aligned in the button,
+    /// and report the button as clicked when it is pressed (see [`Context::shortcut_pressed`]).
+    ///
+    /// The items of a closed [`crate::menu`] aren't added, so check the shortcut with
+    /// [`Context::shortcut_pressed`] as well. Each press is only reported once:
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::{Key, KeyboardShortcut, Modifiers};
+    /// let save = KeyboardShortcut::new(Modifiers { command: true, ..Default::default() }, Key::S);
+    /// let mut do_save = ui.ctx().shortcut_pressed(save);
+    /// egui::menu::menu(ui, "File", |ui| {
+    ///     do_save |= ui.add(egui::Button::new("Save").shortcut(save)).clicked();
+    /// });
+    /// if do_save {
+    ///     // save…
+    /// }
+    /// ```
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+
     pub(crate) fn min_size(mut self, min_size: Vec2) -> Self {
         self.min_size = min_size;
         self
@@ -118,9 +199,14 @@ impl Button {
             fill,
             sense,
             small,
+            large,
             frame,
             wrap,
             min_size,
+            shortcut,
+            icon,
+            icon_spacing,
+            align,
         } = self;
 
         let text_style = text_style
@@ -131,7 +217,28 @@ impl Button {
         if small {
             button_padding.y = 0.0;
         }
-        let total_extra = button_padding + button_padding;
+        if large {
+            button_padding *= 2.0;
+        }
+
+        let icon_galley = match &icon {
+            Some(ButtonIcon::Glyph(glyph)) => {
+                Some(ui.fonts().layout_no_wrap(text_style, glyph.clone()))
+            }
+            _ => None,
+        };
+        let icon_size = match (&icon, &icon_galley) {
+            (Some(ButtonIcon::Image(_, size)), _) => *size,
+            (_, Some(icon_galley)) => icon_galley.size,
+            _ => Vec2::ZERO,
+        };
+        let icon_spacing = if icon.is_some() && !text.is_empty() {
+            icon_spacing.unwrap_or_else(|| ui.spacing().item_spacing.x)
+        } else {
+            0.0
+        };
+        let icon_extra = vec2(icon_size.x + icon_spacing, 0.0);
+        let total_extra = button_padding + icon_extra + button_padding;
 
         let wrap = wrap.unwrap_or_else(|| ui.wrap_text());
         let galley = if wrap {
@@ -141,21 +248,47 @@ impl Button {
             ui.fonts().layout_no_wrap(text_style, text)
         };
 
-        let mut desired_size = galley.size + 2.0 * button_padding;
+        let shortcut_galley =
+            shortcut.map(|shortcut| ui.fonts().layout_no_wrap(text_style, shortcut.to_string()));
+
+        let content_size = vec2(icon_extra.x + galley.size.x, galley.size.y.max(icon_size.y));
+        let mut desired_size = content_size + 2.0 * button_padding;
+        if let Some(shortcut_galley) = &shortcut_galley {
+            desired_size.x += ui.spacing().item_spacing.x + shortcut_galley.size.x;
+        }
         if !small {
             desired_size.y = desired_size.y.at_least(ui.spacing().interact_size.y);
         }
         desired_size = desired_size.at_least(min_size);
 
-        let (rect, response) = ui.allocate_at_least(desired_size, sense);
+        let (rect, mut response) = ui.allocate_at_least(desired_size, sense);
+        if let Some(shortcut) = shortcut {
+            if sense.click && ui.enabled() && ui.ctx().shortcut_pressed(shortcut) {
+                response.clicked[PointerButton::Primary as usize] = true;
+            }
+        }
         response.widget_info(|| WidgetInfo::labeled(WidgetType::Button, &galley.text));
 
         if ui.clip_rect().intersects(rect) {
             let visuals = ui.style().interact(&response);
-            let text_pos = ui
-                .layout()
-                .align_size_within_rect(galley.size, rect.shrink2(button_padding))
-                .min;
+            let inner_rect = rect.shrink2(button_padding);
+            let content_rect = match align {
+                Some(align) => {
+                    Align2([align, Align::Center]).align_size_within_rect(content_size, inner_rect)
+                }
+                None => ui.layout().align_size_within_rect(content_size, inner_rect),
+            };
+            let text_pos = pos2(
+                content_rect.min.x + icon_extra.x,
+                content_rect.center().y - 0.5 * galley.size.y,
+            );
+            let icon_rect = Rect::from_min_size(
+                pos2(
+                    content_rect.min.x,
+                    content_rect.center().y - 0.5 * icon_size.y,
+                ),
+                icon_size,
+            );
 
             if frame {
                 let fill = fill.unwrap_or(visuals.bg_fill);
@@ -170,7 +303,26 @@ impl Button {
             let text_color = text_color
                 .or(ui.visuals().override_text_color)
                 .unwrap_or_else(|| visuals.text_color());
+            match icon {
+                Some(ButtonIcon::Image(texture_id, _)) => {
+                    Image::new(texture_id, icon_size).paint_at(ui, icon_rect);
+                }
+                Some(ButtonIcon::Glyph(_)) => {
+                    if let Some(icon_galley) = icon_galley {
+                        ui.painter().galley(icon_rect.min, icon_galley, text_color);
+                    }
+                }
+                None => {}
+            }
             ui.painter().galley(text_pos, galley, text_color);
+
+            if let Some(shortcut_galley) = shortcut_galley {
+                let shortcut_pos = Align2::RIGHT_CENTER
+                    .align_size_within_rect(shortcut_galley.size, rect.shrink2(button_padding))
+                    .min;
+                let color = ui.visuals().weak_text_color();
+                ui.painter().galley(shortcut_pos, shortcut_galley, color);
+            }
         }
 
         response
@@ -336,6 +488,7 @@ pub struct RadioButton {
     text: String,
     text_color: Option<Color32>,
     text_style: Option<TextStyle>,
+    tab_stop: bool,
 }
 
 impl RadioButton {
@@ -346,6 +499,7 @@ impl RadioButton {
             text: text.to_string(),
             text_color: None,
             text_style: None,
+            tab_stop: true,
         }
     }
 
@@ -358,6 +512,12 @@ impl RadioButton {
         self.text_style = Some(text_style);
         self
     }
+
+    /// Can this get focus with tab? Default: `true`.
+    pub(crate) fn tab_stop(mut self, tab_stop: bool) -> Self {
+        self.tab_stop = tab_stop;
+        self
+    }
 }
 
 impl Widget for RadioButton {
@@ -367,6 +527,7 @@ impl Widget for RadioButton {
             text,
             text_color,
             text_style,
+            tab_stop,
         } = self;
 
         let text_style = text_style
@@ -388,6 +549,10 @@ impl Widget for RadioButton {
         let mut desired_size = total_extra + galley.size;
         desired_size = desired_size.at_least(ui.spacing().interact_size);
         desired_size.y = desired_size.y.max(icon_width);
+        if !tab_stop {
+            let id = ui.next_auto_id();
+            ui.memory().skip_tab_stop(id);
+        }
         let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
         response
             .widget_info(|| WidgetInfo::selected(WidgetType::RadioButton, checked, &galley.text));
@@ -0,0 +1,326 @@
+use std::{hash::Hash, ops::Range, sync::Arc};
+
+use crate::*;
+use epaint::Galley;
+
+type Highlighter<'a> = Box<dyn 'a + Fn(&str) -> Vec<(Range<usize>, Color32)>>;
+
+enum Highlighting<'a> {
+    None,
+    Simple,
+    Custom(Highlighter<'a>),
+}
+
+/// Show some source code, with line numbers and a button to copy it.
+///
+/// Long lines scroll horizontally instead of wrapping.
+/// Color the code with [`Self::simple_highlighting`] or your own [`Self::highlighter`].
+///
+/// To edit code, use a [`TextEdit::code_editor`] instead.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// let code = "fn main() {\n    println!(\"Hello world!\"); // greet\n}";
+/// ui.add(egui::CodeView::new(code).simple_highlighting());
+///
+/// // Your own highlighter gets one line at a time, and returns colored byte ranges:
+/// ui.add(egui::CodeView::new("TODO: write docs").highlighter(|line| {
+///     match line.find("TODO") {
+///         Some(start) => vec![(start..start + 4, egui::Color32::YELLOW)],
+///         None => vec![],
+///     }
+/// }));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct CodeView<'a> {
+    code: &'a str,
+    id_source: Option<Id>,
+    line_numbers: bool,
+    copy_button: bool,
+    highlighting: Highlighting<'a>,
+}
+
+impl<'a> CodeView<'a> {
+    pub fn new(code: &'a str) -> Self {
+        Self {
+            code,
+            id_source: None,
+            line_numbers: true,
+            copy_button: true,
+            highlighting: Highlighting::None,
+        }
+    }
+
+    /// Use this to remember the horizontal scroll position when the layout around the code changes.
+    pub fn id_source(mut self, id_source: impl Hash) -> Self {
+        self.id_source = Some(Id::new(id_source));
+        self
+    }
+
+    /// Show the line numbers to the left of the code. Default: `true`.
+    pub fn line_numbers(mut self, line_numbers: bool) -> Self {
+        self.line_numbers = line_numbers;
+        self
+    }
+
+    /// Show a button which copies all of the code. Default: `true`.
+    pub fn copy_button(mut self, copy_button: bool) -> Self {
+        self.copy_button = copy_button;
+        self
+    }
+
+    /// Color keywords, strings, numbers and `//` comments, for most C-like languages.
+    pub fn simple_highlighting(mut self) -> Self {
+        self.highlighting = Highlighting::Simple;
+        self
+    }
+
+    /// Color each line with your own function, returning the byte ranges to color.
+    /// The rest of the line gets the normal text color.
+    ///
+    /// Tabs are turned into four spaces before the line is given to the function.
+    pub fn highlighter(
+        mut self,
+        highlighter: impl 'a + Fn(&str) -> Vec<(Range<usize>, Color32)>,
+    ) -> Self {
+        self.highlighting = Highlighting::Custom(Box::new(highlighter));
+        self
+    }
+}
+
+impl<'a> Widget for CodeView<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            code,
+            id_source,
+            line_numbers,
+            copy_button,
+            highlighting,
+        } = self;
+
+        let id = match id_source {
+            Some(id_source) => ui.make_persistent_id(id_source),
+            None => ui.auto_id_with("code_view"),
+        };
+        let text_style = TextStyle::Monospace;
+        let row_height = ui.fonts().row_height(text_style);
+        let text_color = ui.visuals().text_color();
+        let colors = HighlightColors::new(ui.visuals());
+
+        let lines: Vec<Vec<(Arc<Galley>, Color32)>> = code
+            .lines()
+            .map(|line| {
+                let line = line.replace('\t', "    ");
+                let ranges = match &highlighting {
+                    Highlighting::None => vec![],
+                    Highlighting::Simple => simple_highlight(&line, &colors),
+                    Highlighting::Custom(highlighter) => highlighter(&line),
+                };
+                colored_pieces(&line, ranges, text_color)
+                    .into_iter()
+                    .map(|(piece, color)| (ui.fonts().layout_no_wrap(text_style, piece), color))
+                    .collect()
+            })
+            .collect();
+        let code_width = lines
+            .iter()
+            .map(|pieces| pieces.iter().map(|(galley, _)| galley.size.x).sum())
+            .fold(0.0, f32::max);
+        let code_size = vec2(code_width, row_height * lines.len().max(1) as f32);
+
+        let frame = Frame {
+            margin: vec2(4.0, 2.0),
+            corner_radius: 2.0,
+            fill: ui.visuals().code_bg_color,
+            ..Frame::none()
+        };
+        let response = frame
+            .show(ui, |ui| {
+                let layout = Layout::left_to_right().with_cross_align(Align::Min);
+                ui.with_layout(layout, |ui| {
+                    if line_numbers {
+                        let digits = lines.len().max(1).to_string().len();
+                        let width = ui.fonts().glyph_width(text_style, '0') * digits as f32;
+                        let (rect, _) =
+                            ui.allocate_exact_size(vec2(width, code_size.y), Sense::hover());
+                        let color = ui.visuals().weak_text_color();
+                        for number in 1..=lines.len() {
+                            let pos =
+                                rect.right_top() + vec2(0.0, row_height * (number - 1) as f32);
+                            ui.painter()
+                                .text(pos, Align2::RIGHT_TOP, number, text_style, color);
+                        }
+                    }
+
+                    ScrollArea::horizontal().id_source(id).show(ui, |ui| {
+                        let (rect, _) = ui.allocate_exact_size(code_size, Sense::hover());
+                        for (row, pieces) in lines.into_iter().enumerate() {
+                            let mut pos = rect.min + vec2(0.0, row_height * row as f32);
+                            for (galley, color) in pieces {
+                                let width = galley.size.x;
+                                ui.painter().galley(pos, galley, color);
+                                pos.x += width;
+                            }
+                        }
+                    });
+                });
+            })
+            .response;
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, code));
+
+        if copy_button {
+            let button_rect = Rect::from_min_size(
+                response.rect.min,
+                vec2(response.rect.width(), ui.spacing().interact_size.y),
+            );
+            let mut button_ui = ui.child_ui(button_rect.shrink(2.0), Layout::right_to_left());
+            let copy = button_ui
+                .add(Button::new("Copy").small())
+                .on_hover_text("Copy the code");
+            if copy.clicked() {
+                ui.output().copied_text = code.to_owned();
+            }
+        }
+
+        response
+    }
+}
+
+/// Split a line into pieces of text with their color. Overlapping or invalid ranges are skipped.
+fn colored_pieces(
+    line: &str,
+    mut ranges: Vec<(Range<usize>, Color32)>,
+    text_color: Color32,
+) -> Vec<(String, Color32)> {
+    ranges.sort_by_key(|(range, _)| range.start);
+
+    let mut pieces = vec![];
+    let mut end = 0;
+    for (range, color) in ranges {
+        if range.start < end || line.get(range.clone()).is_none() {
+            continue;
+        }
+        if range.start > end {
+            pieces.push((line[end..range.start].to_owned(), text_color));
+        }
+        pieces.push((line[range.clone()].to_owned(), color));
+        end = range.end;
+    }
+    if end < line.len() {
+        pieces.push((line[end..].to_owned(), text_color));
+    }
+    pieces
+}
+
+// ----------------------------------------------------------------------------
+
+struct HighlightColors {
+    keyword: Color32,
+    string: Color32,
+    number: Color32,
+    comment: Color32,
+}
+
+impl HighlightColors {
+    fn new(visuals: &Visuals) -> Self {
+        if visuals.dark_mode {
+            Self {
+                keyword: Color32::from_rgb(255, 100, 100),
+                string: Color32::from_rgb(140, 210, 120),
+                number: Color32::from_rgb(120, 180, 255),
+                comment: visuals.weak_text_color(),
+            }
+        } else {
+            Self {
+                keyword: Color32::from_rgb(200, 0, 0),
+                string: Color32::from_rgb(0, 130, 0),
+                number: Color32::from_rgb(0, 80, 200),
+                comment: visuals.weak_text_color(),
+            }
+        }
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "case", "class", "const", "continue", "def", "default", "do",
+    "else", "enum", "export", "extern", "false", "fn", "for", "from", "function", "if", "impl",
+    "import", "in", "let", "loop", "match", "mod", "mut", "new", "null", "pub", "return", "self",
+    "Self", "static", "struct", "switch", "trait", "true", "type", "use", "var", "where", "while",
+];
+
+fn simple_highlight(line: &str, colors: &HighlightColors) -> Vec<(Range<usize>, Color32)> {
+    let mut ranges = vec![];
+    let mut chars = line.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        if c == '/' && line[start..].starts_with("//") {
+            ranges.push((start..line.len(), colors.comment));
+            break;
+        } else if c == '"' {
+            let mut end = line.len();
+            let mut escaped = false;
+            for (index, c) in &mut chars {
+                if c == '"' && !escaped {
+                    end = index + 1;
+                    break;
+                }
+                escaped = c == '\\' && !escaped;
+            }
+            ranges.push((start..end, colors.string));
+        } else if c.is_alphanumeric() || c == '_' {
+            let mut end = start + c.len_utf8();
+            while let Some(&(index, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_') {
+                    break;
+                }
+                end = index + c.len_utf8();
+                chars.next();
+            }
+            let word = &line[start..end];
+            if c.is_ascii_digit() {
+                ranges.push((start..end, colors.number));
+            } else if KEYWORDS.contains(&word) {
+                ranges.push((start..end, colors.keyword));
+            }
+        }
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn highlight_line() {
+        let colors = HighlightColors::new(&Visuals::dark());
+        let line = r#"let s = "a \" b"; x2 = 42 // done"#;
+        let ranges = simple_highlight(line, &colors);
+        let pieces: Vec<(&str, Color32)> = ranges
+            .iter()
+            .map(|(range, color)| (&line[range.clone()], *color))
+            .collect();
+        assert_eq!(
+            pieces,
+            vec![
+                ("let", colors.keyword),
+                (r#""a \" b""#, colors.string),
+                ("42", colors.number),
+                ("// done", colors.comment),
+            ]
+        );
+
+        let text_color = Color32::WHITE;
+        assert_eq!(
+            colored_pieces(
+                "ab cd",
+                vec![(3..5, Color32::RED), (0..1, Color32::RED)],
+                text_color
+            ),
+            vec![
+                ("a".to_owned(), Color32::RED),
+                ("b ".to_owned(), text_color),
+                ("cd".to_owned(), Color32::RED),
+            ]
+        );
+    }
+}
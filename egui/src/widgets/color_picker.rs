@@ -207,23 +207,387 @@ pub enum Alpha {
     BlendOrAdditive,
 }
 
-fn color_text_ui(ui: &mut Ui, color: impl Into<Color32>) {
-    let color = color.into();
-    ui.horizontal(|ui| {
+/// How the color picker shows and reads the color values, shared by all color pickers.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ColorPickerSettings {
+    /// Show the color channels multiplied by the alpha, like [`Color32`] stores them.
+    premultiplied: bool,
+    entry_mode: EntryMode,
+}
+
+impl Default for ColorPickerSettings {
+    fn default() -> Self {
+        Self {
+            premultiplied: true,
+            entry_mode: EntryMode::Rgb,
+        }
+    }
+}
+
+/// What numbers to type in to the color picker.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum EntryMode {
+    /// sRGB, 0-255.
+    Rgb,
+    /// Hue, saturation and value of the sRGB color.
+    Hsv,
+    /// Hue, saturation and lightness of the sRGB color.
+    Hsl,
+}
+
+/// The last numbers shown in HSV or HSL mode, so that e.g. the hue of a gray isn't lost.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct EntryCache {
+    srgb: [u8; 3],
+    entry_mode: EntryMode,
+    values: [f32; 3],
+}
+
+/// The color swatches shown at the bottom of the color picker popups:
+/// a palette set by the app, and the colors picked most recently.
+///
+/// Stored in [`Memory::data`], so with the `persistence` feature the recent colors are remembered
+/// between runs.
+///
+/// ```
+/// # let mut ctx = egui::CtxRef::default();
+/// # ctx.begin_frame(Default::default());
+/// let palette = vec![egui::Color32::RED, egui::Color32::GREEN, egui::Color32::BLUE];
+/// egui::color_picker::set_palette(&ctx, palette);
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct ColorSwatches {
+    /// Colors provided by the app.
+    pub palette: Vec<Color32>,
+    /// The most recently picked colors, newest first.
+    pub recent: Vec<Color32>,
+}
+
+/// How many recently picked colors to remember.
+const MAX_RECENT_COLORS: usize = 12;
+
+impl ColorSwatches {
+    fn add_recent(&mut self, color: Color32) {
+        self.recent.retain(|&recent| recent != color);
+        self.recent.insert(0, color);
+        self.recent.truncate(MAX_RECENT_COLORS);
+    }
+}
+
+/// Show these colors in all color pickers, for quick access.
+pub fn set_palette(ctx: &CtxRef, palette: Vec<Color32>) {
+    ctx.memory()
+        .data
+        .get_mut_or_default::<ColorSwatches>()
+        .palette = palette;
+}
+
+fn swatches_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    let ColorSwatches { palette, recent } =
+        ui.memory().data.get_or_default::<ColorSwatches>().clone();
+
+    for (label, colors) in [("Palette", palette), ("Recent", recent)].iter() {
+        if colors.is_empty() {
+            continue;
+        }
+        ui.label(*label);
+        ui.horizontal_wrapped(|ui| {
+            ui.spacing_mut().item_spacing = Vec2::splat(ui.spacing().item_spacing.y);
+            for &color in colors {
+                if swatch(ui, color).clicked() {
+                    *hsva = color.into();
+                    if alpha == Alpha::Opaque {
+                        hsva.a = 1.0;
+                    }
+                }
+            }
+        });
+    }
+}
+
+/// A small color button, that shows the color larger when hovered.
+fn swatch(ui: &mut Ui, color: Color32) -> Response {
+    let size = Vec2::splat(ui.spacing().interact_size.y);
+    let (rect, response) = ui.allocate_exact_size(size, Sense::click());
+    response.widget_info(|| WidgetInfo::new(WidgetType::ColorButton));
+    let visuals = ui.style().interact(&response);
+    let rect = rect.expand(visuals.expansion);
+
+    background_checkers(ui.painter(), rect);
+    ui.painter().rect_filled(rect, 0.0, color);
+    ui.painter().rect_stroke(rect, 0.0, visuals.bg_stroke);
+
+    response.on_hover_ui(|ui| {
+        let size = vec2(
+            ui.spacing().slider_width,
+            ui.spacing().interact_size.y * 2.0,
+        );
+        show_color(ui, color, size);
         let [r, g, b, a] = color.to_array();
+        ui.label(format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a));
+    })
+}
+
+fn to_srgba(hsva: Hsva, premultiplied: bool) -> [u8; 4] {
+    if premultiplied {
+        hsva.to_srgba_premultiplied()
+    } else {
+        hsva.to_srgba_unmultiplied()
+    }
+}
+
+fn from_srgba(srgba: [u8; 4], premultiplied: bool) -> Hsva {
+    if premultiplied {
+        Hsva::from_srgba_premultiplied(srgba)
+    } else {
+        Hsva::from_srgba_unmultiplied(srgba)
+    }
+}
+
+fn color_text_ui(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
+    let mut settings = *ui
+        .memory()
+        .data_temp
+        .get_or_default::<ColorPickerSettings>();
+    let srgba = to_srgba(Hsva::from(*hsva), settings.premultiplied);
+
+    ui.horizontal(|ui| {
+        let [r, g, b, a] = srgba;
+        let multiplied = if settings.premultiplied {
+            "premultiplied"
+        } else {
+            "unmultiplied"
+        };
         ui.label(format!(
-            "RGBA (premultiplied): rgba({}, {}, {}, {})",
-            r, g, b, a
+            "RGBA ({}): rgba({}, {}, {}, {})",
+            multiplied, r, g, b, a
         ));
 
         if ui.button("📋").on_hover_text("Click to copy").clicked() {
             ui.output().copied_text = format!("{}, {}, {}, {}", r, g, b, a);
         }
     });
+
+    ui.horizontal(|ui| {
+        ui.label("Hex:");
+        if let Some(new_srgba) = hex_edit(ui, srgba, alpha != Alpha::Opaque) {
+            *hsva = from_srgba(new_srgba, settings.premultiplied).into();
+        }
+        if alpha != Alpha::Opaque {
+            ui.checkbox(&mut settings.premultiplied, "Premultiplied alpha")
+                .on_hover_text(
+                    "Whether the color channels are shown multiplied by the alpha, or as is.",
+                );
+        }
+    });
+
+    numeric_entry_ui(ui, hsva, alpha, &mut settings);
+
+    ui.memory().data_temp.insert(settings);
+}
+
+/// Fields for typing in the exact numbers of a color, in the chosen [`EntryMode`].
+fn numeric_entry_ui(
+    ui: &mut Ui,
+    hsva: &mut HsvaGamma,
+    alpha: Alpha,
+    settings: &mut ColorPickerSettings,
+) {
+    ui.horizontal(|ui| {
+        ui.selectable_value(&mut settings.entry_mode, EntryMode::Rgb, "RGB");
+        ui.selectable_value(&mut settings.entry_mode, EntryMode::Hsv, "HSV");
+        ui.selectable_value(&mut settings.entry_mode, EntryMode::Hsl, "HSL");
+    });
+
+    let current = Hsva::from(*hsva);
+    let additive = current.a < 0.0;
+    let show_alpha = alpha != Alpha::Opaque && !additive;
+
+    ui.horizontal(|ui| match settings.entry_mode {
+        EntryMode::Rgb => {
+            let mut srgba = to_srgba(current, settings.premultiplied);
+            let mut changed = false;
+            for (label, channel) in ["R", "G", "B"].iter().zip(srgba.iter_mut()) {
+                ui.label(*label);
+                changed |= ui.add(DragValue::new(channel)).changed();
+            }
+            if show_alpha {
+                ui.label("A");
+                changed |= ui.add(DragValue::new(&mut srgba[3])).changed();
+            }
+            if changed {
+                let mut new = from_srgba(srgba, settings.premultiplied);
+                if additive {
+                    new.a = current.a;
+                }
+                *hsva = new.into();
+            }
+        }
+        EntryMode::Hsv | EntryMode::Hsl => {
+            let [r, g, b, a] = current.to_srgba_unmultiplied();
+            let srgb = [r, g, b];
+            let rgb = [r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0];
+            let entry_mode = settings.entry_mode;
+            let cached = ui.memory().data_temp.get::<EntryCache>().copied();
+            let mut values = match cached {
+                Some(cache) if cache.srgb == srgb && cache.entry_mode == entry_mode => cache.values,
+                _ if entry_mode == EntryMode::Hsv => {
+                    let (h, s, v) = hsv_from_rgb(rgb);
+                    [h, s, v]
+                }
+                _ => hsl_from_rgb(rgb),
+            };
+
+            let last = if entry_mode == EntryMode::Hsv {
+                "V"
+            } else {
+                "L"
+            };
+            let mut changed = false;
+            for (i, label) in ["H", "S", last].iter().enumerate() {
+                ui.label(*label);
+                let (max, suffix) = if i == 0 { (360.0, "°") } else { (100.0, "%") };
+                let mut value = values[i] * max;
+                let response = ui.add(
+                    DragValue::new(&mut value)
+                        .clamp_range(0.0..=max)
+                        .max_decimals(1)
+                        .suffix(suffix),
+                );
+                if response.changed() {
+                    values[i] = value / max;
+                    changed = true;
+                }
+            }
+            let mut a = a;
+            if show_alpha {
+                ui.label("A");
+                changed |= ui.add(DragValue::new(&mut a)).changed();
+            }
+
+            let srgb = if changed {
+                let [r, g, b] = if entry_mode == EntryMode::Hsv {
+                    rgb_from_hsv((values[0], values[1], values[2]))
+                } else {
+                    rgb_from_hsl(values)
+                };
+                let to_u8 = |x: f32| (x * 255.0).round() as u8;
+                let srgba = [to_u8(r), to_u8(g), to_u8(b), a];
+                let mut new = Hsva::from_srgba_unmultiplied(srgba);
+                if additive {
+                    new.a = current.a;
+                }
+                *hsva = new.into();
+                [srgba[0], srgba[1], srgba[2]]
+            } else {
+                srgb
+            };
+            ui.memory().data_temp.insert(EntryCache {
+                srgb,
+                entry_mode,
+                values,
+            });
+        }
+    });
+}
+
+/// All ranges in 0-1.
+fn hsl_from_rgb(rgb: [f32; 3]) -> [f32; 3] {
+    let (h, s_v, v) = hsv_from_rgb(rgb);
+    let l = v * (1.0 - 0.5 * s_v);
+    let s = if l <= 0.0 || l >= 1.0 {
+        0.0
+    } else {
+        (v - l) / l.min(1.0 - l)
+    };
+    [h, s, l]
+}
+
+/// All ranges in 0-1.
+fn rgb_from_hsl([h, s, l]: [f32; 3]) -> [f32; 3] {
+    let v = l + s * l.min(1.0 - l);
+    let s_v = if v <= 0.0 { 0.0 } else { 2.0 * (1.0 - l / v) };
+    rgb_from_hsv((h, s_v, v))
+}
+
+/// A text field with the color as `#RRGGBBAA`, or `#RRGGBB` without alpha.
+/// Returns the new color, if the user changed it.
+fn hex_edit(ui: &mut Ui, srgba: [u8; 4], with_alpha: bool) -> Option<[u8; 4]> {
+    let [r, g, b, a] = srgba;
+    let hex = if with_alpha {
+        format!("#{:02X}{:02X}{:02X}{:02X}", r, g, b, a)
+    } else {
+        format!("#{:02X}{:02X}{:02X}", r, g, b)
+    };
+
+    let id = ui.auto_id_with("hex");
+    let mut text = if ui.memory().has_focus(id) {
+        ui.memory()
+            .id_data_temp
+            .get::<String>(&id)
+            .cloned()
+            .unwrap_or(hex)
+    } else {
+        hex
+    };
+
+    let text_color = if parse_hex(&text).is_none() {
+        Some(Color32::RED)
+    } else {
+        None
+    };
+    let response = ui.add(
+        TextEdit::singleline(&mut text)
+            .id(id)
+            .text_style(TextStyle::Monospace)
+            .text_color_opt(text_color)
+            .desired_width(ui.fonts().glyph_width(TextStyle::Monospace, '0') * 9.0),
+    );
+
+    if response.changed() {
+        let parsed = parse_hex(&text);
+        ui.memory().id_data_temp.insert(id, text);
+        match parsed {
+            // Don't touch the color if nothing changed, to not lose e.g. the hue of a gray:
+            Some([r, g, b, a]) if [r, g, b, a] != srgba => {
+                Some([r, g, b, if with_alpha { a } else { srgba[3] }])
+            }
+            _ => None,
+        }
+    } else {
+        if !response.has_focus() {
+            ui.memory().id_data_temp.remove(&id);
+        }
+        None
+    }
+}
+
+/// Parse `#RGB`, `#RGBA`, `#RRGGBB` or `#RRGGBBAA` (the `#` is optional).
+fn parse_hex(text: &str) -> Option<[u8; 4]> {
+    let hex = text.trim().trim_start_matches('#');
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize, len: usize| -> u8 {
+        let value = u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).unwrap_or_default();
+        if len == 1 {
+            value * 17 // e.g. F -> FF
+        } else {
+            value
+        }
+    };
+    match hex.len() {
+        3 => Some([channel(0, 1), channel(1, 1), channel(2, 1), 255]),
+        4 => Some([channel(0, 1), channel(1, 1), channel(2, 1), channel(3, 1)]),
+        6 => Some([channel(0, 2), channel(1, 2), channel(2, 2), 255]),
+        8 => Some([channel(0, 2), channel(1, 2), channel(2, 2), channel(3, 2)]),
+        _ => None,
+    }
 }
 
 fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
-    color_text_ui(ui, *hsva);
+    color_text_ui(ui, hsva, alpha);
 
     if alpha == Alpha::BlendOrAdditive {
         // We signal additive blending by storing a negative alpha (a bit ironic).
@@ -318,6 +682,8 @@ fn color_picker_hsvag_2d(ui: &mut Ui, hsva: &mut HsvaGamma, alpha: Alpha) {
         ui.label("Value / Saturation");
         ui.end_row();
     });
+
+    swatches_ui(ui, hsva, alpha);
 }
 
 /// return true on change
@@ -361,6 +727,34 @@ pub fn color_edit_button_hsva(ui: &mut Ui, hsva: &mut Hsva, alpha: Alpha) -> Res
         }
     }
 
+    // Remember the picked color once the popup is closed, however that happens:
+    let initial_id = pupup_id.with("initial");
+    let color = Color32::from(*hsva);
+    if ui.memory().is_popup_open(pupup_id) {
+        if ui
+            .memory()
+            .id_data_temp
+            .get::<Color32>(&initial_id)
+            .is_none()
+        {
+            ui.memory().id_data_temp.insert(initial_id, color);
+        }
+    } else if let Some(initial) = ui
+        .memory()
+        .id_data_temp
+        .get::<Color32>(&initial_id)
+        .copied()
+    {
+        let mut memory = ui.memory();
+        memory.id_data_temp.remove(&initial_id);
+        if color != initial {
+            memory
+                .data
+                .get_mut_or_default::<ColorSwatches>()
+                .add_recent(color);
+        }
+    }
+
     button_response
 }
 
@@ -39,8 +39,33 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
     (get_set_value)(Some(value));
 }
 
+/// How a [`DragValue`] keeps its value within [`DragValue::clamp_range`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClampMode {
+    /// Clamp incoming values, and every change while dragging or typing.
+    Always,
+    /// Let the user drag or type values outside the range,
+    /// and clamp when the drag is released or the text edit loses focus.
+    OnEditEnd,
+    /// Ignore the clamp range.
+    Never,
+}
+
+/// Drag or step slower with Shift held, and faster with Ctrl (⌘ on Mac).
+fn speed_factor(modifiers: Modifiers) -> f64 {
+    if modifiers.shift_only() {
+        0.1
+    } else if modifiers.command && !modifiers.shift && !modifiers.alt {
+        10.0
+    } else {
+        1.0
+    }
+}
+
 /// A numeric value that you can change by dragging the number. More compact than a [`Slider`].
 ///
+/// Hold Shift while dragging for finer control, or Ctrl (⌘ on Mac) to change the value faster.
+///
 /// ```
 /// # let ui = &mut egui::Ui::__test();
 /// # let mut my_f32: f32 = 0.0;
@@ -53,6 +78,7 @@ pub struct DragValue<'a> {
     prefix: String,
     suffix: String,
     clamp_range: RangeInclusive<f64>,
+    clamp_mode: ClampMode,
     min_decimals: usize,
     max_decimals: Option<usize>,
 }
@@ -130,6 +156,7 @@ impl<'a> DragValue<'a> {
             prefix: Default::default(),
             suffix: Default::default(),
             clamp_range: f64::NEG_INFINITY..=f64::INFINITY,
+            clamp_mode: ClampMode::Always,
             min_decimals: 0,
             max_decimals: None,
         }
@@ -142,11 +169,29 @@ impl<'a> DragValue<'a> {
     }
 
     /// Clamp incoming and outgoing values to this range.
+    ///
+    /// See also [`Self::clamp_mode`].
     pub fn clamp_range<Num: emath::Numeric>(mut self, clamp_range: RangeInclusive<Num>) -> Self {
         self.clamp_range = clamp_range.start().to_f64()..=clamp_range.end().to_f64();
         self
     }
 
+    /// When to clamp the value to [`Self::clamp_range`]. Default: [`ClampMode::Always`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut percent: f32 = 50.0;
+    /// ui.add(
+    ///     egui::DragValue::new(&mut percent)
+    ///         .clamp_range(0.0..=100.0)
+    ///         .clamp_mode(egui::ClampMode::OnEditEnd),
+    /// );
+    /// ```
+    pub fn clamp_mode(mut self, clamp_mode: ClampMode) -> Self {
+        self.clamp_mode = clamp_mode;
+        self
+    }
+
     #[deprecated = "Use clamp_range"]
     pub fn clamp_range_f64(mut self, clamp_range: RangeInclusive<f64>) -> Self {
         self.clamp_range = clamp_range;
@@ -211,17 +256,28 @@ impl<'a> Widget for DragValue<'a> {
             mut get_set_value,
             speed,
             clamp_range,
+            clamp_mode,
             prefix,
             suffix,
             min_decimals,
             max_decimals,
         } = self;
 
+        let modifiers = ui.input().modifiers;
         let is_slow_speed =
-            ui.input().modifiers.shift_only() && ui.memory().is_being_dragged(ui.next_auto_id());
+            modifiers.shift_only() && ui.memory().is_being_dragged(ui.next_auto_id());
+
+        // Clamps changes made while editing:
+        let clamp_edit = |value: f64| {
+            if clamp_mode == ClampMode::Always {
+                clamp_to_range(value, clamp_range.clone())
+            } else {
+                value
+            }
+        };
 
         let old_value = get(&mut get_set_value);
-        let value = clamp_to_range(old_value, clamp_range.clone());
+        let value = clamp_edit(old_value);
         if old_value != value {
             set(&mut get_set_value, value);
         }
@@ -256,8 +312,7 @@ impl<'a> Widget for DragValue<'a> {
                     .text_style(TextStyle::Monospace),
             );
             if let Ok(parsed_value) = value_text.parse() {
-                let parsed_value = clamp_to_range(parsed_value, clamp_range);
-                set(&mut get_set_value, parsed_value)
+                set(&mut get_set_value, clamp_edit(parsed_value))
             }
             if ui.input().key_pressed(Key::Enter) {
                 ui.memory().surrender_focus(kb_edit_id);
@@ -277,7 +332,7 @@ impl<'a> Widget for DragValue<'a> {
             let response = response
                 .on_hover_cursor(CursorIcon::ResizeHorizontal)
                 .on_hover_text(format!(
-                    "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control.",
+                    "{}{}{}\nDrag to edit or click to enter a value.\nPress 'Shift' while dragging for better control, or 'Ctrl' to go faster.",
                     prefix,
                     value as f32, // Show full precision value on-hover. TODO: figure out f64 vs f32
                     suffix
@@ -292,7 +347,7 @@ impl<'a> Widget for DragValue<'a> {
                 let mdelta = response.drag_delta();
                 let delta_points = mdelta.x - mdelta.y; // Increase to the right and up
 
-                let speed = if is_slow_speed { speed / 10.0 } else { speed };
+                let speed = speed * speed_factor(modifiers);
 
                 let delta_value = delta_points as f64 * speed;
 
@@ -313,8 +368,7 @@ impl<'a> Widget for DragValue<'a> {
                     );
                     let rounded_new_value =
                         emath::round_to_decimals(rounded_new_value, auto_decimals);
-                    let rounded_new_value = clamp_to_range(rounded_new_value, clamp_range);
-                    set(&mut get_set_value, rounded_new_value);
+                    set(&mut get_set_value, clamp_edit(rounded_new_value));
 
                     drag_state.last_dragged_id = Some(response.id);
                     drag_state.last_dragged_value = Some(stored_value);
@@ -327,9 +381,14 @@ impl<'a> Widget for DragValue<'a> {
                     - ui.input().num_presses(Key::ArrowLeft) as f64;
 
                 if change != 0.0 {
-                    let new_value = value + speed * change;
+                    let new_value = value + speed * speed_factor(modifiers) * change;
                     let new_value = emath::round_to_decimals(new_value, auto_decimals);
-                    let new_value = clamp_to_range(new_value, clamp_range);
+                    // Each key press is an edit of its own:
+                    let new_value = if clamp_mode == ClampMode::Never {
+                        new_value
+                    } else {
+                        clamp_to_range(new_value, clamp_range.clone())
+                    };
                     set(&mut get_set_value, new_value);
                 }
             }
@@ -337,6 +396,13 @@ impl<'a> Widget for DragValue<'a> {
             response
         };
 
+        if clamp_mode == ClampMode::OnEditEnd
+            && (response.drag_released() || ui.memory().lost_focus(kb_edit_id))
+        {
+            let value = get(&mut get_set_value);
+            set(&mut get_set_value, clamp_to_range(value, clamp_range));
+        }
+
         response.changed = get(&mut get_set_value) != old_value;
 
         response.widget_info(|| WidgetInfo::drag_value(value));
@@ -4,6 +4,9 @@ use crate::*;
 ///
 /// See also [`Ui::hyperlink`] and [`Ui::hyperlink_to`].
 ///
+/// Clicking the link opens it with [`Context::open_url`],
+/// so you can handle it within your app with [`Context::set_open_url_handler`].
+///
 /// ```
 /// # let ui = &mut egui::Ui::__test();
 /// ui.hyperlink("https://github.com/emilk/egui");
@@ -63,13 +66,13 @@ impl Widget for Hyperlink {
         }
         if response.clicked() {
             let modifiers = ui.ctx().input().modifiers;
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
+            ui.ctx().open_url(crate::output::OpenUrl {
                 url: url.clone(),
                 new_tab: modifiers.any(),
             });
         }
         if response.middle_clicked() {
-            ui.ctx().output().open_url = Some(crate::output::OpenUrl {
+            ui.ctx().open_url(crate::output::OpenUrl {
                 url: url.clone(),
                 new_tab: true,
             });
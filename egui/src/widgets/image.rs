@@ -1,5 +1,18 @@
 use crate::*;
 
+/// How an [`Image`] is sized, see [`Image::fit`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFit {
+    /// The size given to [`Image::new`].
+    Original,
+    /// As large as fits in the available space, keeping the aspect ratio.
+    Fit,
+    /// Cover all of the available space, keeping the aspect ratio by cropping the image.
+    Fill,
+    /// All of the available space, stretching the image.
+    Stretch,
+}
+
 /// An widget to show an image of a given size.
 ///
 /// ```
@@ -9,6 +22,14 @@ use crate::*;
 ///
 /// // Shorter version:
 /// ui.image(my_texture_id, [640.0, 480.0]);
+///
+/// // The left half of a sprite sheet, tinted red, filling the available space:
+/// ui.add(
+///     egui::Image::new(my_texture_id, [640.0, 480.0])
+///         .uv(egui::Rect::from_min_max(egui::pos2(0.0, 0.0), egui::pos2(0.5, 1.0)))
+///         .tint(egui::Color32::RED)
+///         .fit(egui::ImageFit::Fill),
+/// );
 /// ```
 ///
 /// Se also [`crate::ImageButton`].
@@ -21,6 +42,8 @@ pub struct Image {
     bg_fill: Color32,
     tint: Color32,
     sense: Sense,
+    rotation: Option<(emath::Rot2, Vec2)>,
+    fit: ImageFit,
 }
 
 impl Image {
@@ -32,10 +55,14 @@ impl Image {
             bg_fill: Default::default(),
             tint: Color32::WHITE,
             sense: Sense::hover(),
+            rotation: None,
+            fit: ImageFit::Original,
         }
     }
 
     /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    ///
+    /// Use this to crop the image, or to show one sprite of a sprite sheet.
     pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
         self.uv = uv.into();
         self
@@ -53,6 +80,22 @@ impl Image {
         self
     }
 
+    /// Rotate the image by `angle` radians (clockwise) around `origin`.
+    ///
+    /// `origin` is a position in the image, from `(0, 0)` in the top left to `(1, 1)` in the
+    /// bottom right, e.g. `Vec2::splat(0.5)` for the center.
+    /// Only the painted image is rotated: it takes up the same space in the layout.
+    pub fn rotate(mut self, angle: f32, origin: Vec2) -> Self {
+        self.rotation = Some((emath::Rot2::from_angle(angle), origin));
+        self
+    }
+
+    /// How to size the image relative to the available space. Default: [`ImageFit::Original`].
+    pub fn fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+
     /// Make the image respond to clicks and/or drags.
     ///
     /// Consider using [`ImageButton`] instead, for an on-hover effect.
@@ -68,26 +111,40 @@ impl Image {
     }
 
     pub fn paint_at(&self, ui: &mut Ui, rect: Rect) {
+        self.paint_with_uv(ui, rect, self.uv);
+    }
+
+    fn paint_with_uv(&self, ui: &mut Ui, rect: Rect, uv: Rect) {
         use epaint::*;
         let Self {
             texture_id,
-            uv,
+            uv: _,
             size: _,
             bg_fill,
             tint,
             sense: _,
+            rotation,
+            fit: _,
         } = self;
 
+        let rotate = |mesh: &mut Mesh| {
+            if let Some((rot, origin)) = rotation {
+                mesh.rotate(*rot, rect.min + *origin * rect.size());
+            }
+        };
+
         if *bg_fill != Default::default() {
             let mut mesh = Mesh::default();
             mesh.add_colored_rect(rect, *bg_fill);
+            rotate(&mut mesh);
             ui.painter().add(Shape::mesh(mesh));
         }
 
         {
             // TODO: builder pattern for Mesh
             let mut mesh = Mesh::with_texture(*texture_id);
-            mesh.add_rect_with_uv(rect, *uv, *tint);
+            mesh.add_rect_with_uv(rect, uv, *tint);
+            rotate(&mut mesh);
             ui.painter().add(Shape::mesh(mesh));
         }
     }
@@ -95,8 +152,32 @@ impl Image {
 
 impl Widget for Image {
     fn ui(self, ui: &mut Ui) -> Response {
-        let (rect, response) = ui.allocate_exact_size(self.size, self.sense);
-        self.paint_at(ui, rect);
+        let available = ui.available_size();
+        let available = if available.x.is_finite() && available.y.is_finite() {
+            available
+        } else {
+            self.size // e.g. in a `ScrollArea`
+        };
+
+        let mut uv = self.uv;
+        let size = match self.fit {
+            // Nothing to scale, e.g. a texture that isn't loaded yet:
+            _ if self.size.x <= 0.0 || self.size.y <= 0.0 => self.size,
+            ImageFit::Original => self.size,
+            ImageFit::Fit => self.size * (available.x / self.size.x).min(available.y / self.size.y),
+            ImageFit::Fill => {
+                // Crop the image to the aspect ratio of the available space:
+                let scale = (available.x / self.size.x).max(available.y / self.size.y);
+                let shown = available / (self.size * scale); // fraction of the image
+                let center = uv.center();
+                uv = Rect::from_center_size(center, uv.size() * shown.min(Vec2::splat(1.0)));
+                available
+            }
+            ImageFit::Stretch => available,
+        };
+
+        let (rect, response) = ui.allocate_exact_size(size, self.sense);
+        self.paint_with_uv(ui, rect, uv);
         response
     }
 }
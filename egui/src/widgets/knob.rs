@@ -0,0 +1,434 @@
+#![allow(clippy::needless_pass_by_value)] // False positives with `impl ToString`
+
+use std::{f32::consts::TAU, ops::RangeInclusive};
+
+use crate::*;
+
+/// Combined into one function (rather than two) to make it easier
+/// for the borrow checker.
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+
+/// How dragging the pointer turns a [`Knob`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum KnobMode {
+    /// The knob points towards the pointer.
+    Circular,
+    /// Drag up to turn the knob clockwise, and down to turn it back.
+    Vertical,
+}
+
+/// A rotary knob for a number in a range, for when a [`Slider`] takes up too much room,
+/// e.g. the parameters of a synthesizer.
+///
+/// The value is shown below the knob, followed by an optional text.
+/// With keyboard focus, the arrow keys turn the knob.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut volume: f32 = 0.5;
+/// # let mut waveform: u8 = 0;
+/// ui.add(egui::Knob::new(&mut volume, 0.0..=1.0).text("Volume"));
+/// ui.add(
+///     egui::Knob::new(&mut waveform, 0..=3)
+///         .mode(egui::KnobMode::Circular)
+///         .arc(-0.25 * std::f32::consts::TAU, 0.25 * std::f32::consts::TAU)
+///         .text("Waveform"),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Knob<'a> {
+    get_set_value: GetSetValue<'a>,
+    range: RangeInclusive<f64>,
+    mode: KnobMode,
+    /// Angles of the ends of the range, clockwise from straight up.
+    arc: (f32, f32),
+    detents: Option<usize>,
+    diameter: Option<f32>,
+    drag_distance: f32,
+    show_value: bool,
+    prefix: String,
+    suffix: String,
+    text: String,
+    min_decimals: usize,
+    max_decimals: usize,
+}
+
+impl<'a> Knob<'a> {
+    pub fn new<Num: emath::Numeric>(value: &'a mut Num, range: RangeInclusive<Num>) -> Self {
+        let range_f64 = range.start().to_f64()..=range.end().to_f64();
+        let slf = Self::from_get_set(range_f64, move |v: Option<f64>| {
+            if let Some(v) = v {
+                *value = Num::from_f64(v)
+            }
+            value.to_f64()
+        });
+
+        if Num::INTEGRAL {
+            // One detent per integer, unless there are too many to show:
+            let steps = (range.end().to_f64() - range.start().to_f64()).abs();
+            if steps <= 64.0 {
+                slf.detents(steps as usize + 1)
+            } else {
+                slf.max_decimals(0)
+            }
+        } else {
+            slf
+        }
+    }
+
+    pub fn from_get_set(
+        range: RangeInclusive<f64>,
+        get_set_value: impl 'a + FnMut(Option<f64>) -> f64,
+    ) -> Self {
+        Self {
+            get_set_value: Box::new(get_set_value),
+            range,
+            mode: KnobMode::Vertical,
+            arc: (-0.375 * TAU, 0.375 * TAU),
+            detents: None,
+            diameter: None,
+            drag_distance: 200.0,
+            show_value: true,
+            prefix: Default::default(),
+            suffix: Default::default(),
+            text: Default::default(),
+            min_decimals: 0,
+            max_decimals: 2,
+        }
+    }
+
+    /// How dragging turns the knob. Default: [`KnobMode::Vertical`].
+    pub fn mode(mut self, mode: KnobMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// The angles (in radians, clockwise from straight up) of the start and end of the range.
+    /// Default: from `-0.375 * TAU` to `0.375 * TAU`, i.e. all but the bottom quarter.
+    ///
+    /// If `end` is smaller than `start` the knob turns counter-clockwise.
+    pub fn arc(mut self, start: f32, end: f32) -> Self {
+        self.arc = (start, end);
+        self
+    }
+
+    /// Snap to this many evenly spaced positions along the arc, including both ends,
+    /// and mark each of them.
+    /// For integers, there is one detent per integer by default (if there are at most 65).
+    pub fn detents(mut self, detents: usize) -> Self {
+        self.detents = Some(detents.at_least(2));
+        self
+    }
+
+    /// Default: twice the height of a button, see [`crate::style::Spacing::interact_size`].
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// In [`KnobMode::Vertical`]: how many points to drag to go through the whole range.
+    /// Default: `200.0`.
+    pub fn drag_distance(mut self, drag_distance: f32) -> Self {
+        self.drag_distance = drag_distance.at_least(1.0);
+        self
+    }
+
+    /// Show the value below the knob. Default: `true`.
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a prefix before the number, e.g. "x: "
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the number, this can be e.g. a unit ("°" or " dB")
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show a text below the value.
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    /// Show at least this many decimals of the value. Default: `0`.
+    pub fn min_decimals(mut self, min_decimals: usize) -> Self {
+        self.min_decimals = min_decimals;
+        self
+    }
+
+    /// Show at most this many decimals of the value. Default: `2`.
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = max_decimals;
+        self
+    }
+
+    fn get_value(&mut self) -> f64 {
+        (self.get_set_value)(None)
+    }
+
+    fn set_value(&mut self, value: f64) {
+        (self.get_set_value)(Some(value));
+    }
+
+    /// How far along the range a value is, from zero to one.
+    fn fraction_from_value(&self, value: f64) -> f64 {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        if start == end {
+            0.0
+        } else {
+            remap_clamp(value, start..=end, 0.0..=1.0)
+        }
+    }
+
+    fn value_from_fraction(&self, fraction: f64) -> f64 {
+        lerp(self.range.clone(), self.snap(fraction))
+    }
+
+    fn snap(&self, fraction: f64) -> f64 {
+        let fraction = fraction.clamp(0.0, 1.0);
+        match self.detents {
+            Some(detents) => {
+                let steps = (detents - 1) as f64;
+                (fraction * steps).round() / steps
+            }
+            None => fraction,
+        }
+    }
+
+    fn angle_from_fraction(&self, fraction: f64) -> f32 {
+        let (start, end) = self.arc;
+        lerp(start..=end, fraction as f32)
+    }
+
+    /// How much one press of an arrow key turns the knob.
+    fn key_step(&self) -> f64 {
+        match self.detents {
+            Some(detents) => 1.0 / (detents - 1) as f64,
+            None => 0.01,
+        }
+    }
+}
+
+/// Where along the arc from `start` to `end` the `angle` is, from zero to one.
+///
+/// Angles outside the arc go to the closest end of it.
+fn fraction_from_angle(angle: f32, (start, end): (f32, f32)) -> f32 {
+    let sweep = end - start;
+    if sweep == 0.0 {
+        return 0.0;
+    }
+    // Measure the angle in the direction of the arc, starting halfway through the gap
+    // at the ends of the arc, so that angles in the gap go to the closest end:
+    let gap = (TAU - sweep.abs()).at_least(0.0);
+    let along = (sweep.signum() * (angle - start) + 0.5 * gap).rem_euclid(TAU) - 0.5 * gap;
+    (along / sweep.abs()).clamp(0.0, 1.0)
+}
+
+/// The direction of an angle measured clockwise from straight up.
+fn direction(angle: f32) -> Vec2 {
+    vec2(angle.sin(), -angle.cos())
+}
+
+impl<'a> Widget for Knob<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let diameter = self.diameter.unwrap_or(2.0 * ui.spacing().interact_size.y);
+        let old_value = self.get_value();
+
+        let value_galley = if self.show_value {
+            let text = format!(
+                "{}{}{}",
+                self.prefix,
+                emath::format_with_decimals_in_range(
+                    old_value,
+                    self.min_decimals..=self.max_decimals.at_least(self.min_decimals)
+                ),
+                self.suffix
+            );
+            Some(ui.fonts().layout_no_wrap(TextStyle::Body, text))
+        } else {
+            None
+        };
+        let text_galley = if self.text.is_empty() {
+            None
+        } else {
+            Some(
+                ui.fonts()
+                    .layout_no_wrap(TextStyle::Body, self.text.clone()),
+            )
+        };
+        let galleys: Vec<_> = value_galley.iter().chain(text_galley.iter()).collect();
+
+        let size = vec2(
+            galleys
+                .iter()
+                .map(|galley| galley.size.x)
+                .fold(diameter, f32::max),
+            diameter + galleys.iter().map(|galley| galley.size.y).sum::<f32>(),
+        );
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        let center = pos2(rect.center().x, rect.top() + 0.5 * diameter);
+        let radius = 0.5 * diameter;
+
+        if self.mode == KnobMode::Vertical && (response.hovered() || response.dragged()) {
+            ui.output().cursor_icon = CursorIcon::ResizeVertical;
+        }
+
+        let fraction = self.fraction_from_value(old_value);
+        match self.mode {
+            KnobMode::Circular => {
+                if let Some(pointer_pos) = response.interact_pointer_pos() {
+                    let delta = pointer_pos - center;
+                    if delta.length() > 0.1 * radius {
+                        let angle = delta.x.atan2(-delta.y);
+                        let fraction = fraction_from_angle(angle, self.arc);
+                        let value = self.value_from_fraction(fraction as f64);
+                        self.set_value(value);
+                    }
+                }
+            }
+            KnobMode::Vertical => {
+                // Remember the fraction without snapping, so that slow drags can reach the next detent:
+                let drag_id = response.id.with("drag_fraction");
+                if response.drag_started() {
+                    ui.memory().id_data_temp.insert(drag_id, fraction);
+                }
+                if response.dragged() {
+                    let delta = (-response.drag_delta().y / self.drag_distance) as f64;
+                    let drag_fraction = {
+                        let mut memory = ui.memory();
+                        let drag_fraction = memory
+                            .id_data_temp
+                            .get_mut_or_insert_with(drag_id, || fraction);
+                        *drag_fraction = (*drag_fraction + delta).clamp(0.0, 1.0);
+                        *drag_fraction
+                    };
+                    let value = self.value_from_fraction(drag_fraction);
+                    self.set_value(value);
+                } else {
+                    ui.memory().id_data_temp.remove(&drag_id);
+                }
+            }
+        }
+
+        if response.has_focus() {
+            let steps = {
+                let input = ui.input();
+                input.num_presses(Key::ArrowUp) as f64 + input.num_presses(Key::ArrowRight) as f64
+                    - input.num_presses(Key::ArrowDown) as f64
+                    - input.num_presses(Key::ArrowLeft) as f64
+            };
+            if steps != 0.0 {
+                let value = self.get_value();
+                let fraction = self.fraction_from_value(value);
+                let value = self.value_from_fraction(fraction + steps * self.key_step());
+                self.set_value(value);
+            }
+        }
+
+        let value = self.get_value();
+        response.widget_info(|| WidgetInfo::slider(value, &self.text));
+        if value != old_value {
+            response.mark_changed();
+        }
+
+        if ui.clip_rect().intersects(rect) {
+            self.paint(ui, &response, center, radius, value);
+
+            let mut y = rect.top() + diameter;
+            let text_color = ui.visuals().text_color();
+            for galley in galleys {
+                let pos = pos2(center.x - 0.5 * galley.size.x, y);
+                y += galley.size.y;
+                ui.painter().galley(pos, galley.clone(), text_color);
+            }
+        }
+
+        response
+    }
+}
+
+impl<'a> Knob<'a> {
+    fn paint(&self, ui: &Ui, response: &Response, center: Pos2, radius: f32, value: f64) {
+        let visuals = ui.style().interact(response);
+        let painter = ui.painter();
+
+        // The track along the arc, filled up to the value:
+        let track_radius = 0.85 * radius;
+        let track_width = (0.12 * radius).at_least(2.0);
+        let arc_points = |to: f64| -> Vec<Pos2> {
+            let angle_span = (self.angle_from_fraction(to) - self.arc.0).abs();
+            let segments = ((angle_span / TAU * 64.0).ceil() as usize).at_least(1);
+            (0..=segments)
+                .map(|i| {
+                    let fraction = to * i as f64 / segments as f64;
+                    center + track_radius * direction(self.angle_from_fraction(fraction))
+                })
+                .collect()
+        };
+        painter.add(Shape::line(
+            arc_points(1.0),
+            (track_width, ui.visuals().widgets.inactive.bg_fill),
+        ));
+        let fraction = self.fraction_from_value(value);
+        if fraction > 0.0 {
+            painter.add(Shape::line(
+                arc_points(fraction),
+                (track_width, ui.visuals().selection.bg_fill),
+            ));
+        }
+
+        if let Some(detents) = self.detents {
+            let stroke = ui.visuals().widgets.noninteractive.fg_stroke;
+            for i in 0..detents {
+                let dir = direction(self.angle_from_fraction(i as f64 / (detents - 1) as f64));
+                painter.line_segment(
+                    [
+                        center + (track_radius + 0.5 * track_width) * dir,
+                        center + radius * dir,
+                    ],
+                    stroke,
+                );
+            }
+        }
+
+        // The knob itself, with a line pointing at the value:
+        let knob_radius = 0.6 * radius + visuals.expansion;
+        painter.circle(center, knob_radius, visuals.bg_fill, visuals.bg_stroke);
+        let dir = direction(self.angle_from_fraction(fraction));
+        painter.line_segment(
+            [center + 0.3 * knob_radius * dir, center + knob_radius * dir],
+            visuals.fg_stroke,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angle_to_fraction() {
+        let arc = (-0.375 * TAU, 0.375 * TAU);
+        let close = |a: f32, b: f32| (a - b).abs() < 1e-5;
+        assert!(close(fraction_from_angle(-0.375 * TAU, arc), 0.0));
+        assert!(close(fraction_from_angle(0.0, arc), 0.5));
+        assert!(close(fraction_from_angle(0.375 * TAU, arc), 1.0));
+        // In the gap at the bottom, go to the closest end:
+        assert!(close(fraction_from_angle(0.45 * TAU, arc), 1.0));
+        assert!(close(fraction_from_angle(-0.45 * TAU, arc), 0.0));
+        assert!(close(fraction_from_angle(0.55 * TAU, arc), 0.0));
+
+        // Counter-clockwise:
+        let arc = (0.25 * TAU, -0.25 * TAU);
+        assert!(close(fraction_from_angle(0.25 * TAU, arc), 0.0));
+        assert!(close(fraction_from_angle(0.0, arc), 0.5));
+        assert!(close(fraction_from_angle(0.45 * TAU, arc), 0.0));
+    }
+}
@@ -200,7 +200,7 @@ impl Label {
         self.paint_galley_impl(ui, pos, galley, false, ui.visuals().text_color())
     }
 
-    fn paint_galley_impl(
+    pub(crate) fn paint_galley_impl(
         &self,
         ui: &mut Ui,
         pos: Pos2,
@@ -277,14 +277,7 @@ impl Label {
     }
 
     fn should_wrap(&self, ui: &Ui) -> bool {
-        self.wrap.or(ui.style().wrap).unwrap_or_else(|| {
-            if let Some(grid) = ui.grid() {
-                grid.wrap_text()
-            } else {
-                let layout = ui.layout();
-                layout.is_vertical() || layout.is_horizontal() && layout.main_wrap()
-            }
-        })
+        should_wrap(ui, self.wrap)
     }
 
     fn valign_galley(
@@ -383,6 +376,18 @@ impl Widget for Label {
     }
 }
 
+/// Should text wrap, unless `wrap` says otherwise?
+pub(crate) fn should_wrap(ui: &Ui, wrap: Option<bool>) -> bool {
+    wrap.or(ui.style().wrap).unwrap_or_else(|| {
+        if let Some(grid) = ui.grid() {
+            grid.wrap_text()
+        } else {
+            let layout = ui.layout();
+            layout.is_vertical() || layout.is_horizontal() && layout.main_wrap()
+        }
+    })
+}
+
 impl From<&str> for Label {
     fn from(s: &str) -> Label {
         Label::new(s)
@@ -0,0 +1,532 @@
+use crate::*;
+
+type LoadImage<'a> = dyn 'a + Fn(&str) -> Option<(TextureId, Vec2)>;
+
+type ImageLoader<'a> = Box<LoadImage<'a>>;
+
+/// Show text written in (a subset of) [Markdown](https://commonmark.org/).
+///
+/// Supports headings, paragraphs, `*emphasis*`, `**strong**`, `~~strikethrough~~`,
+/// `` `inline code` ``, fenced code blocks, bullet and numbered lists, block quotes,
+/// horizontal rules, `[links](url)` and `![images](url)`.
+///
+/// Links are opened with [`Context::open_url`].
+/// Images are only shown if you give a [`Self::image_loader`], otherwise their alt text is shown.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.add(egui::Markdown::new(
+///     "# Release notes\n\
+///      This release has **many** improvements:\n\
+///      * Faster startup\n\
+///      * A new `--quiet` flag\n\n\
+///      See [the website](https://github.com/emilk/egui) for more.",
+/// ));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct Markdown<'a> {
+    text: &'a str,
+    image_loader: Option<ImageLoader<'a>>,
+}
+
+impl<'a> Markdown<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Self {
+            text,
+            image_loader: None,
+        }
+    }
+
+    /// Get the texture and size of an image from its url, or `None` to show its alt text.
+    ///
+    /// Images wider than the available space are scaled down.
+    pub fn image_loader(
+        mut self,
+        image_loader: impl 'a + Fn(&str) -> Option<(TextureId, Vec2)>,
+    ) -> Self {
+        self.image_loader = Some(Box::new(image_loader));
+        self
+    }
+}
+
+impl<'a> Widget for Markdown<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let image_loader = self.image_loader.as_deref();
+        ui.vertical(|ui| {
+            for (index, block) in blocks(self.text).into_iter().enumerate() {
+                block_ui(ui, block, index == 0, image_loader);
+            }
+        })
+        .response
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug, PartialEq)]
+enum Block {
+    /// Level (1-6) and text.
+    Heading(usize, String),
+    Paragraph(String),
+    /// Indentation (in spaces), the number (or `None` for a bullet point) and text.
+    ListItem(usize, Option<String>, String),
+    Quote(String),
+    Code(String),
+    Rule,
+}
+
+impl Block {
+    /// Can following lines be added to this?
+    fn is_open(&self) -> bool {
+        matches!(
+            self,
+            Block::Paragraph(..) | Block::ListItem(..) | Block::Quote(..)
+        )
+    }
+
+    /// The text that following lines are added to, if this isn't finished.
+    fn text_mut(&mut self) -> Option<&mut String> {
+        match self {
+            Block::Paragraph(text) | Block::ListItem(_, _, text) | Block::Quote(text) => Some(text),
+            _ => None,
+        }
+    }
+}
+
+fn blocks(markdown: &str) -> Vec<Block> {
+    let mut blocks = vec![];
+    let mut current: Option<Block> = None;
+    let mut code: Option<String> = None;
+
+    for line in markdown.lines() {
+        if let Some(code_text) = &mut code {
+            if line.trim_start().starts_with("```") {
+                blocks.push(Block::Code(std::mem::take(code_text)));
+                code = None;
+            } else {
+                code_text.push_str(line);
+                code_text.push('\n');
+            }
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        let indentation = line.len() - trimmed.len();
+
+        let new_block = if trimmed.is_empty() {
+            None
+        } else if trimmed.starts_with("```") {
+            code = Some(String::new());
+            None
+        } else if is_rule(trimmed) {
+            Some(Block::Rule)
+        } else if let Some((level, text)) = heading(trimmed) {
+            Some(Block::Heading(level, text.to_owned()))
+        } else if let Some(text) = trimmed.strip_prefix('>') {
+            if let Some(Block::Quote(quote)) = &mut current {
+                append_line(quote, text);
+                continue;
+            }
+            Some(Block::Quote(text.trim_start().to_owned()))
+        } else if let Some((number, text)) = list_marker(trimmed) {
+            Some(Block::ListItem(
+                indentation,
+                number.map(ToOwned::to_owned),
+                text.to_owned(),
+            ))
+        } else if let Some(text) = current.as_mut().and_then(Block::text_mut) {
+            append_line(text, trimmed);
+            continue;
+        } else {
+            Some(Block::Paragraph(trimmed.to_owned()))
+        };
+
+        if let Some(mut block) = current.take() {
+            if let Some(text) = block.text_mut() {
+                *text = text.trim_end().to_owned();
+            }
+            blocks.push(block);
+        }
+        match new_block {
+            Some(block) if !block.is_open() => blocks.push(block),
+            new_block => current = new_block,
+        }
+    }
+
+    if let Some(code) = code {
+        blocks.push(Block::Code(code)); // unclosed code block
+    }
+    if let Some(mut block) = current {
+        if let Some(text) = block.text_mut() {
+            *text = text.trim_end().to_owned();
+        }
+        blocks.push(block);
+    }
+    blocks
+}
+
+/// Continue a paragraph on the next line. A line ending with two spaces or `\` is a line break.
+fn append_line(text: &mut String, line: &str) {
+    if text.ends_with("  ") {
+        *text = text.trim_end().to_owned();
+        text.push('\n');
+    } else if text.ends_with('\\') {
+        text.pop();
+        text.push('\n');
+    } else {
+        *text = text.trim_end().to_owned();
+        text.push(' ');
+    }
+    text.push_str(line.trim_start());
+}
+
+/// `---`, `***` or `___`.
+fn is_rule(line: &str) -> bool {
+    let mut chars = line.chars().filter(|c| !c.is_whitespace());
+    match chars.next() {
+        Some(first) if "-*_".contains(first) => {
+            let count = 1 + chars.clone().count();
+            count >= 3 && chars.all(|c| c == first)
+        }
+        _ => false,
+    }
+}
+
+/// `# Heading`
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let rest = &line[level..];
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+        Some((level, rest.trim().trim_end_matches('#').trim_end()))
+    } else {
+        None
+    }
+}
+
+/// `- item`, `* item`, `+ item`, `1. item` or `1) item`.
+fn list_marker(line: &str) -> Option<(Option<&str>, &str)> {
+    for bullet in &["- ", "* ", "+ "] {
+        if let Some(rest) = line.strip_prefix(bullet) {
+            return Some((None, rest));
+        }
+    }
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if (1..=9).contains(&digits) {
+        let rest = &line[digits..];
+        if let Some(rest) = rest.strip_prefix(". ").or_else(|| rest.strip_prefix(") ")) {
+            return Some((Some(&line[..digits]), rest));
+        }
+    }
+    None
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+struct InlineStyle {
+    heading: bool,
+    weak: bool,
+    strong: bool,
+    italics: bool,
+    strikethrough: bool,
+    code: bool,
+}
+
+impl InlineStyle {
+    fn label(self, text: String) -> Label {
+        let mut label = Label::new(text);
+        if self.heading {
+            label = label.heading();
+        }
+        if self.code {
+            label = label.code();
+        }
+        if self.strong {
+            label = label.strong();
+        } else if self.weak {
+            label = label.weak();
+        }
+        if self.italics {
+            label = label.italics();
+        }
+        if self.strikethrough {
+            label = label.strikethrough();
+        }
+        label
+    }
+
+    fn flag(&mut self, delimiter: &str) -> &mut bool {
+        match delimiter {
+            "**" | "__" => &mut self.strong,
+            "~~" => &mut self.strikethrough,
+            _ => &mut self.italics,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Inline {
+    Text(String, InlineStyle),
+    /// Text, style and url.
+    Link(String, InlineStyle, String),
+    /// Alt text and url.
+    Image(String, String),
+}
+
+fn inlines(text: &str, mut style: InlineStyle) -> Vec<Inline> {
+    let mut inlines = vec![];
+    let mut current = String::new();
+    let mut rest = text;
+    let mut previous = None;
+
+    let flush = |inlines: &mut Vec<Inline>, current: &mut String, style: InlineStyle| {
+        if !current.is_empty() {
+            inlines.push(Inline::Text(std::mem::take(current), style));
+        }
+    };
+
+    'outer: while let Some(c) = rest.chars().next() {
+        let after = &rest[c.len_utf8()..];
+
+        if c == '\\' {
+            if let Some(escaped) = after.chars().next().filter(char::is_ascii_punctuation) {
+                current.push(escaped);
+                rest = &after[escaped.len_utf8()..];
+                previous = Some(escaped);
+                continue;
+            }
+        }
+        if c == '`' {
+            if let Some(end) = after.find('`') {
+                flush(&mut inlines, &mut current, style);
+                let code = InlineStyle {
+                    code: true,
+                    ..style
+                };
+                inlines.push(Inline::Text(after[..end].to_owned(), code));
+                rest = &after[end + 1..];
+                previous = Some('`');
+                continue;
+            }
+        }
+        if c == '!' {
+            if let Some((alt, url, len)) = link(after) {
+                flush(&mut inlines, &mut current, style);
+                inlines.push(Inline::Image(alt.to_owned(), url.to_owned()));
+                rest = &after[len..];
+                previous = Some(')');
+                continue;
+            }
+        }
+        if c == '[' {
+            if let Some((label, url, len)) = link(rest) {
+                flush(&mut inlines, &mut current, style);
+                inlines.push(Inline::Link(label.to_owned(), style, url.to_owned()));
+                rest = &rest[len..];
+                previous = Some(')');
+                continue;
+            }
+        }
+
+        for &delimiter in &["**", "__", "~~", "*", "_"] {
+            if let Some(after_delimiter) = rest.strip_prefix(delimiter) {
+                let next = after_delimiter.chars().next();
+                let intraword = delimiter.starts_with('_')
+                    && matches!(previous, Some(p) if p.is_alphanumeric())
+                    && matches!(next, Some(n) if n.is_alphanumeric());
+                let is_on = *style.flag(delimiter);
+                // Only start emphasis that is also ended:
+                if !intraword && (is_on || after_delimiter.contains(delimiter)) {
+                    flush(&mut inlines, &mut current, style);
+                    *style.flag(delimiter) = !is_on;
+                    rest = after_delimiter;
+                    previous = delimiter.chars().last();
+                    continue 'outer;
+                }
+            }
+        }
+
+        current.push(c);
+        rest = after;
+        previous = Some(c);
+    }
+    flush(&mut inlines, &mut current, style);
+    inlines
+}
+
+/// `[text](url)`. Returns the text, url and the length of it all.
+fn link(text: &str) -> Option<(&str, &str, usize)> {
+    let text_end = text.strip_prefix('[')?.find(']')? + 1;
+    let after = text[text_end + 1..].strip_prefix('(')?;
+    let url_end = after.find(')')?;
+    let url = after[..url_end]
+        .split_whitespace()
+        .next()
+        .unwrap_or_default();
+    Some((&text[1..text_end], url, text_end + 2 + url_end + 1))
+}
+
+// ----------------------------------------------------------------------------
+
+fn block_ui(ui: &mut Ui, block: Block, first: bool, image_loader: Option<&LoadImage<'_>>) {
+    let row_height = ui.fonts().row_height(TextStyle::Body);
+    match block {
+        Block::Heading(level, text) => {
+            if !first {
+                ui.add_space(0.5 * row_height);
+            }
+            let style = InlineStyle {
+                heading: level <= 2,
+                strong: true,
+                ..Default::default()
+            };
+            paragraph_ui(ui, inlines(&text, style), image_loader);
+        }
+        Block::Paragraph(text) => {
+            paragraph_ui(ui, inlines(&text, Default::default()), image_loader);
+        }
+        Block::ListItem(indentation, number, text) => {
+            ui.horizontal(|ui| {
+                ui.add_space(indentation as f32 * 0.5 * ui.spacing().indent);
+                let marker_width = ui.spacing().indent;
+                let (rect, _) =
+                    ui.allocate_exact_size(vec2(marker_width, row_height), Sense::hover());
+                let color = ui.visuals().strong_text_color();
+                match number {
+                    Some(number) => {
+                        ui.painter().text(
+                            rect.right_center(),
+                            Align2::RIGHT_CENTER,
+                            format!("{}.", number),
+                            TextStyle::Body,
+                            color,
+                        );
+                    }
+                    None => {
+                        ui.painter()
+                            .circle_filled(rect.center(), rect.height() / 8.0, color);
+                    }
+                }
+                ui.vertical(|ui| {
+                    paragraph_ui(ui, inlines(&text, Default::default()), image_loader);
+                });
+            });
+        }
+        Block::Quote(text) => {
+            ui.horizontal(|ui| {
+                let (bar_rect, _) =
+                    ui.allocate_exact_size(vec2(ui.spacing().indent, row_height), Sense::hover());
+                let quote_rect = ui
+                    .vertical(|ui| {
+                        let style = InlineStyle {
+                            weak: true,
+                            ..Default::default()
+                        };
+                        paragraph_ui(ui, inlines(&text, style), image_loader);
+                    })
+                    .response
+                    .rect;
+                let x = bar_rect.center().x;
+                ui.painter().line_segment(
+                    [pos2(x, quote_rect.top()), pos2(x, quote_rect.bottom())],
+                    (2.0, ui.visuals().weak_text_color()),
+                );
+            });
+        }
+        Block::Code(code) => {
+            let frame = Frame {
+                margin: vec2(4.0, 2.0),
+                corner_radius: 2.0,
+                fill: ui.visuals().code_bg_color,
+                ..Frame::none()
+            };
+            frame.show(ui, |ui| {
+                ui.set_min_width(ui.available_width());
+                ui.add(
+                    Label::new(code.trim_end_matches('\n'))
+                        .monospace()
+                        .wrap(false),
+                );
+            });
+        }
+        Block::Rule => {
+            ui.separator();
+        }
+    }
+}
+
+fn paragraph_ui(ui: &mut Ui, inlines: Vec<Inline>, image_loader: Option<&LoadImage<'_>>) {
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing = Vec2::ZERO;
+        for inline in inlines {
+            match inline {
+                Inline::Text(text, style) => {
+                    ui.add(style.label(text));
+                }
+                Inline::Link(text, style, url) => {
+                    ui.add(Hyperlink::from_label_and_url(style.label(text), url));
+                }
+                Inline::Image(alt, url) => match image_loader.and_then(|load| load(&url)) {
+                    Some((texture_id, size)) => {
+                        let scale = (ui.available_width() / size.x).min(1.0);
+                        ui.image(texture_id, size * scale).on_hover_text(alt);
+                    }
+                    None => {
+                        ui.add(Label::new(alt).weak());
+                    }
+                },
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_blocks() {
+        let markdown = "# Title #\nSome text\ncontinued.  \nNew line.\n\n\
+                        - one\n  1. two\n---\n> quoted\n> more\n```rust\nlet x = 1;\n```";
+        assert_eq!(
+            blocks(markdown),
+            vec![
+                Block::Heading(1, "Title".to_owned()),
+                Block::Paragraph("Some text continued.\nNew line.".to_owned()),
+                Block::ListItem(0, None, "one".to_owned()),
+                Block::ListItem(2, Some("1".to_owned()), "two".to_owned()),
+                Block::Rule,
+                Block::Quote("quoted more".to_owned()),
+                Block::Code("let x = 1;\n".to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_inlines() {
+        let strong = InlineStyle {
+            strong: true,
+            ..Default::default()
+        };
+        let code = InlineStyle {
+            code: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            inlines(
+                "a **b** `c*` snake_case 2*3 [d](url \"title\") ![e](img.png)",
+                Default::default()
+            ),
+            vec![
+                Inline::Text("a ".to_owned(), Default::default()),
+                Inline::Text("b".to_owned(), strong),
+                Inline::Text(" ".to_owned(), Default::default()),
+                Inline::Text("c*".to_owned(), code),
+                Inline::Text(" snake_case 2*3 ".to_owned(), Default::default()),
+                Inline::Link("d".to_owned(), Default::default(), "url".to_owned()),
+                Inline::Text(" ".to_owned(), Default::default()),
+                Inline::Image("e".to_owned(), "img.png".to_owned()),
+            ]
+        );
+    }
+}
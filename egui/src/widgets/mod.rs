@@ -7,22 +7,44 @@
 use crate::*;
 
 mod button;
+mod code_view;
 pub mod color_picker;
 pub(crate) mod drag_value;
 mod hyperlink;
 mod image;
-mod label;
+mod knob;
+pub(crate) mod label;
+mod markdown;
+mod number_edit;
 pub mod plot;
+mod rich_text;
+mod segmented_control;
 mod selected_label;
 mod separator;
 mod slider;
+mod spinner;
 pub(crate) mod text_edit;
+mod time_edit;
 
 pub use hyperlink::*;
 pub use label::*;
 pub use selected_label::*;
 pub use separator::*;
-pub use {button::*, drag_value::DragValue, image::Image, slider::*, text_edit::*};
+pub use {
+    button::*,
+    code_view::CodeView,
+    drag_value::{ClampMode, DragValue},
+    image::{Image, ImageFit},
+    knob::{Knob, KnobMode},
+    markdown::Markdown,
+    number_edit::NumberEdit,
+    rich_text::RichText,
+    segmented_control::SegmentedControl,
+    slider::*,
+    spinner::Spinner,
+    text_edit::*,
+    time_edit::TimeEdit,
+};
 
 // ----------------------------------------------------------------------------
 
@@ -107,7 +129,11 @@ pub fn stroke_ui(ui: &mut crate::Ui, stroke: &mut epaint::Stroke, text: &str) {
 }
 
 pub(crate) fn shadow_ui(ui: &mut Ui, shadow: &mut epaint::Shadow, text: &str) {
-    let epaint::Shadow { extrusion, color } = shadow;
+    let epaint::Shadow {
+        extrusion,
+        color,
+        offset,
+    } = shadow;
     ui.horizontal(|ui| {
         ui.label(text);
         ui.add(
@@ -116,6 +142,10 @@ pub(crate) fn shadow_ui(ui: &mut Ui, shadow: &mut epaint::Shadow, text: &str) {
                 .clamp_range(0.0..=100.0),
         )
         .on_hover_text("Extrusion");
+        ui.add(DragValue::new(&mut offset.x).speed(1.0).prefix("x: "))
+            .on_hover_text("Offset");
+        ui.add(DragValue::new(&mut offset.y).speed(1.0).prefix("y: "))
+            .on_hover_text("Offset");
         ui.color_edit_button_srgba(color);
     });
 }
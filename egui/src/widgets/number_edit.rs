@@ -0,0 +1,180 @@
+use std::ops::RangeInclusive;
+
+use crate::*;
+
+type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
+
+type Validator<'a> = Box<dyn 'a + Fn(f64) -> Result<(), String>>;
+
+/// Show a value as the type it was given as, so that e.g. `0.1_f32` isn't shown as `0.10000000149011612`.
+type Format = fn(f64) -> String;
+
+/// A text field for typing in an exact number.
+///
+/// The text is checked as you type. While it isn't valid the field gets a red outline,
+/// and a tooltip on hover tells what is wrong.
+/// The value is only changed when you press enter or leave the field.
+/// Leaving the field with invalid text puts back the old value.
+///
+/// For numbers that don't have to be exact, see [`DragValue`] and [`Slider`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut port: u16 = 8080;
+/// # let mut gain: f64 = 1.0;
+/// ui.add(egui::NumberEdit::new(&mut port).range(1024..=65535));
+/// ui.add(egui::NumberEdit::new(&mut gain).validate(|gain| {
+///     if gain > 0.0 {
+///         Ok(())
+///     } else {
+///         Err("The gain must be positive".to_owned())
+///     }
+/// }));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct NumberEdit<'a> {
+    get_set_value: GetSetValue<'a>,
+    range: RangeInclusive<f64>,
+    integer: bool,
+    validator: Option<Validator<'a>>,
+    desired_width: Option<f32>,
+    format: Format,
+}
+
+impl<'a> NumberEdit<'a> {
+    /// Integer types only accept whole numbers in the range of the type.
+    pub fn new<Num: emath::Numeric + std::fmt::Display>(value: &'a mut Num) -> Self {
+        Self {
+            get_set_value: Box::new(move |v: Option<f64>| {
+                if let Some(v) = v {
+                    *value = Num::from_f64(v)
+                }
+                value.to_f64()
+            }),
+            range: Num::MIN.to_f64()..=Num::MAX.to_f64(),
+            integer: Num::INTEGRAL,
+            validator: None,
+            desired_width: None,
+            format: |value| Num::from_f64(value).to_string(),
+        }
+    }
+
+    /// Only accept values in this range.
+    pub fn range<Num: emath::Numeric>(mut self, range: RangeInclusive<Num>) -> Self {
+        self.range = range.start().to_f64()..=range.end().to_f64();
+        self
+    }
+
+    /// Only accept whole numbers. Default: `true` for integer types.
+    pub fn integer(mut self, integer: bool) -> Self {
+        self.integer = integer;
+        self
+    }
+
+    /// Check the number with your own rules, returning a message for the user when it isn't valid.
+    /// Called after checking the [`Self::range`].
+    pub fn validate(mut self, validator: impl 'a + Fn(f64) -> Result<(), String>) -> Self {
+        self.validator = Some(Box::new(validator));
+        self
+    }
+
+    /// Default: [`crate::style::Spacing::interact_size`]`.x`.
+    pub fn desired_width(mut self, desired_width: f32) -> Self {
+        self.desired_width = Some(desired_width);
+        self
+    }
+}
+
+impl<'a> NumberEdit<'a> {
+    fn parse(&self, text: &str) -> Result<f64, String> {
+        let value: f64 = match text.trim().parse() {
+            Ok(value) => value,
+            Err(_) => return Err("Not a number".to_owned()),
+        };
+        if self.integer && value.fract() != 0.0 {
+            return Err("Must be a whole number".to_owned());
+        }
+        if !self.range.contains(&value) {
+            return Err(format!(
+                "Must be between {} and {}",
+                (self.format)(*self.range.start()),
+                (self.format)(*self.range.end())
+            ));
+        }
+        if let Some(validator) = &self.validator {
+            validator(value)?;
+        }
+        Ok(value)
+    }
+}
+
+impl<'a> Widget for NumberEdit<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let value = (self.get_set_value)(None);
+        let id = ui.auto_id_with("number_edit");
+
+        let mut text = if ui.memory().has_focus(id) {
+            ui.memory()
+                .id_data_temp
+                .get::<String>(&id)
+                .cloned()
+                .unwrap_or_else(|| (self.format)(value))
+        } else {
+            (self.format)(value)
+        };
+
+        let desired_width = self
+            .desired_width
+            .unwrap_or_else(|| ui.spacing().interact_size.x);
+        let mut response = ui.add(
+            TextEdit::singleline(&mut text)
+                .id(id)
+                .desired_width(desired_width),
+        );
+        let parsed = self.parse(&text);
+
+        // Only enter (which also leaves the field) and leaving the field commit the value:
+        response.changed = false;
+        if response.lost_focus() {
+            if let Ok(new_value) = parsed {
+                if new_value != value {
+                    (self.get_set_value)(Some(new_value));
+                    response.mark_changed();
+                }
+            }
+            ui.memory().id_data_temp.remove(&id);
+        } else if response.has_focus() {
+            ui.memory().id_data_temp.insert(id, text);
+        }
+
+        if let Err(error) = &parsed {
+            let visuals = ui.style().interact(&response);
+            ui.painter().rect_stroke(
+                response.rect.expand(visuals.expansion),
+                visuals.corner_radius,
+                Stroke::new(1.0, Color32::RED),
+            );
+            Tooltip::new().show(&response, |ui| {
+                ui.colored_label(Color32::RED, error);
+            });
+        }
+
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn f32_is_shown_without_noise() {
+        let mut value = 0.1_f32;
+        let edit = NumberEdit::new(&mut value).range(0.0_f32..=0.5);
+        assert_eq!((edit.format)(0.1_f32 as f64), "0.1");
+        assert_eq!(
+            edit.parse("0.7"),
+            Err("Must be between 0 and 0.5".to_owned())
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Contour lines of a scalar field.
+
+use std::ops::RangeInclusive;
+
+use super::items::Value;
+use super::transform::Bounds;
+use crate::*;
+
+/// Contour lines of a scalar field sampled on a regular grid, e.g. to show an optimization
+/// landscape. The lines are found with marching squares and drawn in plot coordinates.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Contour, Plot};
+/// let contour = Contour::from_callback(|x, y| x * x + y * y, -1.0..=1.0, -1.0..=1.0, [32, 32])
+///     .levels(vec![0.25, 0.5, 0.75]);
+/// ui.add(Plot::new("Landscape").contour(contour).data_aspect(1.0));
+/// ```
+pub struct Contour {
+    /// The samples, row by row, starting at the bottom.
+    samples: Vec<Vec<f64>>,
+    x_range: RangeInclusive<f64>,
+    y_range: RangeInclusive<f64>,
+    pub(crate) levels: Vec<(f64, Color32)>,
+    pub(crate) stroke_width: f32,
+    pub(crate) show_labels: bool,
+    pub(crate) name: String,
+}
+
+impl Contour {
+    /// Contour lines of the given samples, spread evenly over the given ranges.
+    ///
+    /// `samples[row][column]` is the value at the `column`-th x-value and the `row`-th y-value,
+    /// with row zero at the start of `y_range`. All rows should have the same length.
+    pub fn new(
+        samples: Vec<Vec<f64>>,
+        x_range: RangeInclusive<f64>,
+        y_range: RangeInclusive<f64>,
+    ) -> Self {
+        Self {
+            samples,
+            x_range,
+            y_range,
+            levels: Vec::new(),
+            stroke_width: 1.0,
+            show_labels: false,
+            name: Default::default(),
+        }
+    }
+
+    /// Sample `function(x, y)` on a grid with the given number of `[columns, rows]`.
+    pub fn from_callback(
+        function: impl Fn(f64, f64) -> f64,
+        x_range: RangeInclusive<f64>,
+        y_range: RangeInclusive<f64>,
+        resolution: [usize; 2],
+    ) -> Self {
+        let [columns, rows] = [resolution[0].at_least(2), resolution[1].at_least(2)];
+        let samples = (0..rows)
+            .map(|row| {
+                let y = lerp(y_range.clone(), row as f64 / (rows - 1) as f64);
+                (0..columns)
+                    .map(|column| {
+                        let x = lerp(x_range.clone(), column as f64 / (columns - 1) as f64);
+                        function(x, y)
+                    })
+                    .collect()
+            })
+            .collect();
+        Self::new(samples, x_range, y_range)
+    }
+
+    /// Draw a contour line where the samples equal `value`.
+    /// A `Color32::TRANSPARENT` color means a color will be auto-assigned.
+    pub fn level(mut self, value: f64, color: impl Into<Color32>) -> Self {
+        self.levels.push((value, color.into()));
+        self
+    }
+
+    /// Draw contour lines at all of these values, with auto-assigned colors.
+    pub fn levels(mut self, values: impl IntoIterator<Item = f64>) -> Self {
+        self.levels.extend(
+            values
+                .into_iter()
+                .map(|value| (value, Color32::TRANSPARENT)),
+        );
+        self
+    }
+
+    /// Width of the contour lines. Default: `1.0`.
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Show the value of each level next to its contour line. Default: `false`.
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// Name of the contour.
+    ///
+    /// If a contour is given a name it will show up in the plot legend
+    /// (if legends are turned on), where it can be hidden like a curve.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The area covered by the samples.
+    pub(crate) fn bounds(&self) -> Bounds {
+        let mut bounds = Bounds::NOTHING;
+        if !self.samples.is_empty() {
+            bounds.extend_with(&Value::new(*self.x_range.start(), *self.y_range.start()));
+            bounds.extend_with(&Value::new(*self.x_range.end(), *self.y_range.end()));
+        }
+        bounds
+    }
+
+    /// The line segments of the contour line at `level`, using marching squares.
+    pub(crate) fn segments(&self, level: f64) -> Vec<[Value; 2]> {
+        let rows = self.samples.len();
+        let columns = self.samples.iter().map(Vec::len).min().unwrap_or(0);
+        let mut segments = Vec::new();
+        if rows < 2 || columns < 2 {
+            return segments;
+        }
+
+        let x = |column: f64| lerp(self.x_range.clone(), column / (columns - 1) as f64);
+        let y = |row: f64| lerp(self.y_range.clone(), row / (rows - 1) as f64);
+        // Where between `a` and `b` the level is crossed.
+        let t = |a: f64, b: f64| ((level - a) / (b - a)).clamp(0.0, 1.0);
+
+        for row in 0..rows - 1 {
+            for column in 0..columns - 1 {
+                // The corners, counter-clockwise from the bottom left.
+                let v = [
+                    self.samples[row][column],
+                    self.samples[row][column + 1],
+                    self.samples[row + 1][column + 1],
+                    self.samples[row + 1][column],
+                ];
+                if !v.iter().all(|v| v.is_finite()) {
+                    continue;
+                }
+
+                let (c, r) = (column as f64, row as f64);
+                let edge = |index: usize| match index {
+                    0 => Value::new(x(c + t(v[0], v[1])), y(r)), // bottom
+                    1 => Value::new(x(c + 1.0), y(r + t(v[1], v[2]))), // right
+                    2 => Value::new(x(c + t(v[3], v[2])), y(r + 1.0)), // top
+                    _ => Value::new(x(c), y(r + t(v[0], v[3]))), // left
+                };
+
+                let case = (0..4)
+                    .filter(|&i| v[i] > level)
+                    .fold(0, |case, i| case | (1 << i));
+                let center_above = v.iter().sum::<f64>() / 4.0 > level;
+
+                // Pairs of edges to connect. A case and its complement have the same lines.
+                let edges: &[(usize, usize)] = match case {
+                    1 | 14 => &[(3, 0)],
+                    2 | 13 => &[(0, 1)],
+                    3 | 12 => &[(3, 1)],
+                    4 | 11 => &[(1, 2)],
+                    6 | 9 => &[(0, 2)],
+                    7 | 8 => &[(3, 2)],
+                    // Saddles: the center decides which corners are connected.
+                    5 if center_above => &[(0, 1), (2, 3)],
+                    5 => &[(3, 0), (1, 2)],
+                    10 if center_above => &[(3, 0), (1, 2)],
+                    10 => &[(0, 1), (2, 3)],
+                    _ => &[],
+                };
+                segments.extend(edges.iter().map(|&(a, b)| [edge(a), edge(b)]));
+            }
+        }
+        segments
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_contour() {
+        let contour = Contour::from_callback(
+            |x: f64, y: f64| x.hypot(y),
+            -2.0..=2.0,
+            -2.0..=2.0,
+            [41, 41],
+        );
+        let segments = contour.segments(1.0);
+        assert!(!segments.is_empty());
+        for value in segments.iter().flatten() {
+            let radius = value.x.hypot(value.y);
+            assert!((radius - 1.0).abs() < 0.02, "radius {}", radius);
+        }
+    }
+}
@@ -1,8 +1,8 @@
 //! Contains items that can be added to a plot.
 
-use std::ops::RangeInclusive;
+use std::{collections::VecDeque, ops::RangeInclusive};
 
-use super::transform::Bounds;
+use super::transform::{Bounds, ScreenTransform};
 use crate::*;
 
 /// A value in the value-space of the plot.
@@ -30,11 +30,105 @@ impl Value {
 
 // ----------------------------------------------------------------------------
 
+/// Keeps the most recent values of a live data source, e.g. for an oscilloscope-like plot.
+///
+/// Push new values each frame and plot them with [`Self::curve`].
+/// Combine with [`super::Plot::rolling_window`] to have the view follow the newest values.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Plot, RollingBuffer, Value};
+/// let mut buffer = RollingBuffer::new(1000);
+/// let time = ui.input().time;
+/// buffer.push(Value::new(time, time.sin()));
+/// ui.add(Plot::new("Live").curve(buffer.curve()).rolling_window(10.0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollingBuffer {
+    values: VecDeque<Value>,
+    max_len: usize,
+}
+
+impl Default for RollingBuffer {
+    /// A buffer that keeps the last 1000 values.
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl RollingBuffer {
+    /// A buffer that keeps at most `max_len` values, dropping the oldest ones first.
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(max_len),
+            max_len,
+        }
+    }
+
+    /// Add a value, dropping the oldest one if the buffer is full.
+    pub fn push(&mut self, value: Value) {
+        while self.values.len() >= self.max_len.max(1) {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// Drop all values with an x-value more than `x_span` below the newest one.
+    pub fn drop_older_than(&mut self, x_span: f64) {
+        if let Some(newest) = self.values.back() {
+            let min_x = newest.x - x_span;
+            while let Some(oldest) = self.values.front() {
+                if oldest.x >= min_x {
+                    break;
+                }
+                self.values.pop_front();
+            }
+        }
+    }
+
+    /// Drop all values.
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+
+    /// The number of values in the buffer.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Are there no values in the buffer?
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The values, from oldest to newest.
+    pub fn iter(&self) -> impl Iterator<Item = &Value> {
+        self.values.iter()
+    }
+
+    /// A curve of the values currently in the buffer.
+    pub fn curve(&self) -> Curve {
+        Curve::from_values_iter(self.values.iter().copied())
+    }
+}
+
+impl Extend<Value> for RollingBuffer {
+    fn extend<T: IntoIterator<Item = Value>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 /// A horizontal line in a plot, filling the full width
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct HLine {
     pub(crate) y: f64,
     pub(crate) stroke: Stroke,
+    pub(crate) name: String,
+    pub(crate) label: String,
 }
 
 impl HLine {
@@ -42,15 +136,36 @@ impl HLine {
         Self {
             y: y.into(),
             stroke: stroke.into(),
+            name: Default::default(),
+            label: Default::default(),
         }
     }
+
+    /// Name of this line.
+    ///
+    /// If a line is given a name it will show up in the plot legend
+    /// (if legends are turned on), where it can be hidden like a curve.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Text to show next to the line, e.g. to describe a threshold.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
 }
 
 /// A vertical line in a plot, filling the full width
-#[derive(Clone, Copy, Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct VLine {
     pub(crate) x: f64,
     pub(crate) stroke: Stroke,
+    pub(crate) name: String,
+    pub(crate) label: String,
 }
 
 impl VLine {
@@ -58,8 +173,191 @@ impl VLine {
         Self {
             x: x.into(),
             stroke: stroke.into(),
+            name: Default::default(),
+            label: Default::default(),
+        }
+    }
+
+    /// Name of this line.
+    ///
+    /// If a line is given a name it will show up in the plot legend
+    /// (if legends are turned on), where it can be hidden like a curve.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Text to show next to the line, e.g. to describe a threshold.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = label.to_string();
+        self
+    }
+}
+
+/// A horizontal band in a plot, filling the full width of the plot.
+/// Can be useful e.g. to mark an alarm range.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HSpan {
+    pub(crate) y_range: RangeInclusive<f64>,
+    pub(crate) fill: Color32,
+    pub(crate) stroke: Stroke,
+    pub(crate) name: String,
+}
+
+impl HSpan {
+    /// A band covering the given range of y-values.
+    pub fn new(y_range: RangeInclusive<f64>) -> Self {
+        Self {
+            y_range,
+            fill: Color32::TRANSPARENT,
+            stroke: Stroke::none(),
+            name: Default::default(),
         }
     }
+
+    /// Fill color. Default is `Color32::TRANSPARENT` which means a translucent color will be
+    /// auto-assigned.
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = fill.into();
+        self
+    }
+
+    /// Stroke of the two borders of the band. Default: no border.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Name of this band.
+    ///
+    /// If a band is given a name it will show up in the plot legend
+    /// (if legends are turned on), where it can be hidden like a curve.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+/// A vertical band in a plot, filling the full height of the plot.
+/// Can be useful e.g. to mark a time window.
+#[derive(Clone, Debug, PartialEq)]
+pub struct VSpan {
+    pub(crate) x_range: RangeInclusive<f64>,
+    pub(crate) fill: Color32,
+    pub(crate) stroke: Stroke,
+    pub(crate) name: String,
+}
+
+impl VSpan {
+    /// A band covering the given range of x-values.
+    pub fn new(x_range: RangeInclusive<f64>) -> Self {
+        Self {
+            x_range,
+            fill: Color32::TRANSPARENT,
+            stroke: Stroke::none(),
+            name: Default::default(),
+        }
+    }
+
+    /// Fill color. Default is `Color32::TRANSPARENT` which means a translucent color will be
+    /// auto-assigned.
+    pub fn fill(mut self, fill: impl Into<Color32>) -> Self {
+        self.fill = fill.into();
+        self
+    }
+
+    /// Stroke of the two borders of the band. Default: no border.
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Name of this band.
+    ///
+    /// If a band is given a name it will show up in the plot legend
+    /// (if legends are turned on), where it can be hidden like a curve.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+/// An image placed in plot coordinates, e.g. a map or a precomputed heatmap to show behind the
+/// curves. It pans and zooms with the rest of the plot.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let my_texture_id = egui::TextureId::User(0);
+/// use egui::plot::{Bounds, Plot, PlotImage};
+/// let map = PlotImage::new(my_texture_id, Bounds::from_min_max([0.0, 0.0], [4.0, 3.0]));
+/// ui.add(Plot::new("Map").image(map));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotImage {
+    pub(crate) texture_id: TextureId,
+    pub(crate) bounds: Bounds,
+    pub(crate) uv: Rect,
+    pub(crate) tint: Color32,
+    pub(crate) name: String,
+}
+
+impl PlotImage {
+    /// Show the texture stretched over the given area of the plot.
+    pub fn new(texture_id: TextureId, bounds: Bounds) -> Self {
+        Self {
+            texture_id,
+            bounds,
+            uv: Rect::from_min_max(pos2(0.0, 0.0), pos2(1.0, 1.0)),
+            tint: Color32::WHITE,
+            name: Default::default(),
+        }
+    }
+
+    /// Select UV range. Default is (0,0) in top-left, (1,1) bottom right.
+    pub fn uv(mut self, uv: impl Into<Rect>) -> Self {
+        self.uv = uv.into();
+        self
+    }
+
+    /// Multiply image color with this. Default is WHITE (no tint).
+    pub fn tint(mut self, tint: impl Into<Color32>) -> Self {
+        self.tint = tint.into();
+        self
+    }
+
+    /// Name of this image.
+    ///
+    /// If an image is given a name it will show up in the plot legend
+    /// (if legends are turned on), where it can be hidden like a curve.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// Which y-axis a curve is plotted against.
+///
+/// Curves on the [`YAxis::Right`] axis get their own y bounds and auto-scaling,
+/// which is useful when showing two quantities with different units on the same x axis.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum YAxis {
+    /// The primary axis, with labels on the left side of the plot.
+    Left,
+    /// The secondary axis, with labels on the right side of the plot.
+    Right,
+}
+
+impl Default for YAxis {
+    fn default() -> Self {
+        Self::Left
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -69,6 +367,58 @@ struct ExplicitGenerator {
     function: Box<dyn Fn(f64) -> f64>,
     x_range: RangeInclusive<f64>,
     points: usize,
+    /// If set, refine where the function bends, up to this many points in total.
+    max_points: Option<usize>,
+}
+
+impl ExplicitGenerator {
+    /// Insert points where linear interpolation between neighbors is a poor approximation of
+    /// the function, until the curve is smooth or `max_points` is reached.
+    fn refine(&self, mut values: Vec<Value>, max_points: usize) -> Vec<Value> {
+        let (min_y, max_y) = values
+            .iter()
+            .filter(|value| value.y.is_finite())
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), value| {
+                (min.min(value.y), max.max(value.y))
+            });
+        // A thousandth of the height of the curve is below a pixel for most plots.
+        let tolerance = 1e-3 * (max_y - min_y).max(f64::MIN_POSITIVE);
+
+        const MAX_PASSES: usize = 16;
+        for _ in 0..MAX_PASSES {
+            let mut budget = max_points.saturating_sub(values.len());
+            if budget == 0 || values.len() < 2 {
+                break;
+            }
+            let mut refined = Vec::with_capacity(values.len() + budget.min(values.len()));
+            for pair in values.windows(2) {
+                refined.push(pair[0]);
+                if budget == 0 {
+                    continue;
+                }
+                let (a, b) = (pair[0], pair[1]);
+                let x = (a.x + b.x) / 2.0;
+                let y = (self.function)(x);
+                let needs_refinement = if a.y.is_finite() && b.y.is_finite() && y.is_finite() {
+                    (y - (a.y + b.y) / 2.0).abs() > tolerance
+                } else {
+                    // Find where the function stops being finite, e.g. at poles.
+                    a.y.is_finite() != b.y.is_finite()
+                };
+                if needs_refinement {
+                    refined.push(Value { x, y });
+                    budget -= 1;
+                }
+            }
+            refined.extend(values.last().copied());
+            let done = refined.len() == values.len();
+            values = refined;
+            if done {
+                break;
+            }
+        }
+        values
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -76,20 +426,28 @@ struct ExplicitGenerator {
 /// A series of values forming a path.
 pub struct Curve {
     pub(crate) values: Vec<Value>,
+    /// Optional per-point metadata, shown when hovering the point with the same index.
+    pub(crate) labels: Vec<String>,
     generator: Option<ExplicitGenerator>,
     pub(crate) bounds: Bounds,
     pub(crate) stroke: Stroke,
     pub(crate) name: String,
+    pub(crate) y_axis: YAxis,
+    /// If set, draw a stem from this baseline to each point instead of connecting the points.
+    pub(crate) stems: Option<f64>,
 }
 
 impl Curve {
     fn empty() -> Self {
         Self {
             values: Vec::new(),
+            labels: Vec::new(),
             generator: None,
             bounds: Bounds::NOTHING,
             stroke: Stroke::new(2.0, Color32::TRANSPARENT),
             name: Default::default(),
+            y_axis: YAxis::Left,
+            stems: None,
         }
     }
 
@@ -125,6 +483,7 @@ impl Curve {
             function: Box::new(function),
             x_range,
             points,
+            max_points: None,
         };
 
         Self {
@@ -154,6 +513,23 @@ impl Curve {
         self.generator.is_none() && self.values.is_empty()
     }
 
+    /// The bounds of the values within the given x-range.
+    /// For curves generated from a function, this is just the bounds of the curve.
+    pub(crate) fn bounds_in_x_range(&self, x_range: &RangeInclusive<f64>) -> Bounds {
+        if self.values.is_empty() {
+            return self.bounds;
+        }
+        let mut bounds = Bounds::NOTHING;
+        self.values
+            .iter()
+            .filter(|value| x_range.contains(&value.x))
+            .for_each(|value| bounds.extend_with(value));
+        if let Some(baseline) = self.stems {
+            bounds.extend_with_y(baseline);
+        }
+        bounds
+    }
+
     /// Returns the intersection of two ranges if they intersect.
     fn range_intersection(
         range1: &RangeInclusive<f64>,
@@ -178,10 +554,58 @@ impl Curve {
                         Value { x, y }
                     })
                     .collect();
+                if let Some(max_points) = generator.max_points {
+                    self.values = generator.refine(std::mem::take(&mut self.values), max_points);
+                }
             }
         }
     }
 
+    /// The screen positions of the values, reduced to what is visible on screen.
+    ///
+    /// If the x-values are sorted and there are many more values than pixel columns,
+    /// each pixel column is reduced to its first, lowest, highest and last point.
+    /// This keeps huge curves interactive without losing any peaks.
+    pub(crate) fn screen_positions(&self, transform: &ScreenTransform) -> Vec<Pos2> {
+        let values = &self.values;
+        let max_points_per_column = 2;
+        let columns = transform.frame().width().max(1.0) as usize;
+        let is_sorted = values.windows(2).all(|pair| pair[0].x <= pair[1].x);
+        if values.len() <= max_points_per_column * columns || !is_sorted {
+            return values
+                .iter()
+                .map(|v| transform.position_from_value(v))
+                .collect();
+        }
+
+        // Only look at the visible values, plus one on each side to connect to the edges.
+        let bounds = transform.bounds();
+        let start = values
+            .partition_point(|v| v.x < bounds.min[0])
+            .saturating_sub(1);
+        let end = (values.partition_point(|v| v.x <= bounds.max[0]) + 1).min(values.len());
+
+        let mut positions = Vec::with_capacity(4 * columns);
+        let mut column: Option<PixelColumn> = None;
+        for (index, value) in values[start..end].iter().enumerate() {
+            let pos = transform.position_from_value(value);
+            let x = pos.x.floor() as i64;
+            match &mut column {
+                Some(column) if column.x == x => column.add(index, pos),
+                _ => {
+                    if let Some(column) = column.take() {
+                        column.flush(&mut positions);
+                    }
+                    column = Some(PixelColumn::new(x, index, pos));
+                }
+            }
+        }
+        if let Some(column) = column {
+            column.flush(&mut positions);
+        }
+        positions
+    }
+
     /// From a series of y-values.
     /// The x-values will be the indices of these values
     pub fn from_ys_f32(ys: &[f32]) -> Self {
@@ -223,4 +647,136 @@ impl Curve {
         self.name = name.to_string();
         self
     }
+
+    /// Attach a label to each point, e.g. `"sample #1423, sensor B"`.
+    ///
+    /// The label of a point is shown when hovering it,
+    /// and is available in [`super::PlotResponse::hovered_point`].
+    /// The n-th label belongs to the n-th value. Points without a label show no extra text.
+    pub fn point_labels(mut self, labels: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.labels = labels.into_iter().map(|label| label.to_string()).collect();
+        self
+    }
+
+    /// The label of the point with the given index, if any.
+    pub(crate) fn point_label(&self, index: usize) -> Option<&str> {
+        self.labels
+            .get(index)
+            .map(String::as_str)
+            .filter(|label| !label.is_empty())
+    }
+
+    /// Which y-axis this curve is plotted against. Default: [`YAxis::Left`].
+    ///
+    /// Curves on the right axis are scaled independently of the ones on the left axis,
+    /// but share the same x axis.
+    pub fn y_axis(mut self, y_axis: YAxis) -> Self {
+        self.y_axis = y_axis;
+        self
+    }
+
+    /// For curves from [`Self::from_explicit_callback`]: add points where the function bends
+    /// sharply, until it is smooth or there are `max_points` points in total.
+    ///
+    /// This keeps e.g. narrow peaks and `tan(x)` accurate at any zoom level,
+    /// without sampling the whole range densely.
+    pub fn adaptive_sampling(mut self, max_points: usize) -> Self {
+        if let Some(generator) = &mut self.generator {
+            generator.max_points = Some(max_points);
+        }
+        self
+    }
+
+    /// Draw the curve as a stem plot: a vertical line from `baseline` to each point,
+    /// with a marker at the tip, instead of a line connecting the points.
+    /// This is the usual way to show discrete-time signals.
+    pub fn stems(mut self, baseline: impl Into<f64>) -> Self {
+        let baseline = baseline.into();
+        self.stems = Some(baseline);
+        self.bounds.extend_with_y(baseline);
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// The points of a curve that fall into the same pixel column, see [`Curve::screen_positions`].
+struct PixelColumn {
+    x: i64,
+    first: (usize, Pos2),
+    min: (usize, Pos2),
+    max: (usize, Pos2),
+    last: (usize, Pos2),
+}
+
+impl PixelColumn {
+    fn new(x: i64, index: usize, pos: Pos2) -> Self {
+        Self {
+            x,
+            first: (index, pos),
+            min: (index, pos),
+            max: (index, pos),
+            last: (index, pos),
+        }
+    }
+
+    fn add(&mut self, index: usize, pos: Pos2) {
+        if pos.y < self.min.1.y {
+            self.min = (index, pos);
+        }
+        if pos.y > self.max.1.y {
+            self.max = (index, pos);
+        }
+        self.last = (index, pos);
+    }
+
+    /// Add the points of this column in their original order, without duplicates.
+    fn flush(self, positions: &mut Vec<Pos2>) {
+        let mut points = [self.first, self.min, self.max, self.last];
+        points.sort_by_key(|(index, _)| *index);
+        let mut previous = None;
+        for (index, pos) in points.iter().copied() {
+            if previous != Some(index) {
+                positions.push(pos);
+                previous = Some(index);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn downsampling_keeps_peaks() {
+        let n = 10_000;
+        let mut ys = vec![0.0_f32; n];
+        ys[1234] = 1.0;
+        ys[5678] = -1.0;
+        let curve = Curve::from_ys_f32(&ys);
+
+        let frame = Rect::from_min_size(Pos2::ZERO, vec2(100.0, 100.0));
+        let bounds = Bounds::from_min_max([0.0, -1.0], [n as f64, 1.0]);
+        let transform = ScreenTransform::new(frame, bounds, false, false);
+
+        let positions = curve.screen_positions(&transform);
+        assert!(positions.len() <= 4 * 101);
+        assert!(positions.iter().any(|pos| pos.y == frame.top()));
+        assert!(positions.iter().any(|pos| pos.y == frame.bottom()));
+    }
+
+    #[test]
+    fn adaptive_sampling_refines_peaks() {
+        let peak = |x: f64| 1.0 / (1.0 + (100.0 * x).powi(2));
+        let mut curve = Curve::from_explicit_callback(peak, -1.0..=1.0, 21).adaptive_sampling(500);
+        curve.generate_points(-1.0..=1.0);
+
+        let values = &curve.values;
+        assert!(values.len() > 21 && values.len() <= 500);
+        assert!(values.windows(2).all(|pair| pair[0].x < pair[1].x));
+        let near_peak = values.iter().filter(|value| value.x.abs() < 0.05).count();
+        let far_from_peak = values.iter().filter(|value| value.x > 0.5).count();
+        assert!(near_peak > 4 * far_from_peak);
+    }
 }
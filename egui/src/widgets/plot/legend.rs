@@ -1,46 +1,144 @@
-use std::string::String;
+use std::{
+    collections::{BTreeMap, HashSet},
+    string::String,
+    sync::Arc,
+};
 
 use crate::*;
+use epaint::text::Galley;
 
-pub(crate) struct LegendEntry {
-    pub text: String,
-    pub color: Color32,
-    pub checked: bool,
-    pub hovered: bool,
+/// Where to place the plot legend.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Corner {
+    LeftTop,
+    RightTop,
+    LeftBottom,
+    RightBottom,
+}
+
+impl Corner {
+    pub fn all() -> impl Iterator<Item = Corner> {
+        [
+            Corner::LeftTop,
+            Corner::RightTop,
+            Corner::LeftBottom,
+            Corner::RightBottom,
+        ]
+        .iter()
+        .copied()
+    }
+
+    fn is_left(self) -> bool {
+        matches!(self, Corner::LeftTop | Corner::LeftBottom)
+    }
+
+    fn is_top(self) -> bool {
+        matches!(self, Corner::LeftTop | Corner::RightTop)
+    }
+}
+
+/// The configuration for a plot legend.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Corner, Legend, Plot};
+/// ui.add(Plot::new("Test Plot").legend(Legend::default().position(Corner::RightTop)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Legend {
+    pub text_style: TextStyle,
+    pub position: Corner,
+    pub outside: bool,
+    pub background: Color32,
+    pub columns: usize,
+}
+
+impl Default for Legend {
+    fn default() -> Self {
+        Self {
+            text_style: TextStyle::Button,
+            position: Corner::LeftTop,
+            outside: false,
+            background: Color32::TRANSPARENT,
+            columns: 1,
+        }
+    }
+}
+
+impl Legend {
+    /// Which text style to use for the legend. Default: `TextStyle::Button`.
+    pub fn text_style(mut self, style: TextStyle) -> Self {
+        self.text_style = style;
+        self
+    }
+
+    /// In which corner to place the legend. Default: `Corner::LeftTop`.
+    pub fn position(mut self, corner: Corner) -> Self {
+        self.position = corner;
+        self
+    }
+
+    /// Place the legend next to the plot frame instead of on top of the data.
+    /// The plot frame will shrink to make room for it. Default: `false`.
+    pub fn outside(mut self, outside: bool) -> Self {
+        self.outside = outside;
+        self
+    }
+
+    /// Fill the area behind the legend with this color.
+    /// Default: `Color32::TRANSPARENT`, i.e. no background.
+    pub fn background(mut self, fill: impl Into<Color32>) -> Self {
+        self.background = fill.into();
+        self
+    }
+
+    /// Lay out the legend entries in this many columns. Default: `1`.
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.at_least(1);
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+struct LegendEntry {
+    color: Color32,
+    checked: bool,
+    hovered: bool,
 }
 
 impl LegendEntry {
-    pub fn new(text: String, color: Color32, checked: bool) -> Self {
+    fn new(color: Color32, checked: bool) -> Self {
         Self {
-            text,
             color,
             checked,
             hovered: false,
         }
     }
-}
 
-impl Widget for &mut LegendEntry {
-    fn ui(self, ui: &mut Ui) -> Response {
-        let LegendEntry {
-            checked,
-            text,
-            color,
-            ..
-        } = self;
+    fn padding() -> Vec2 {
+        vec2(2.0, 2.0)
+    }
+
+    fn desired_size(ui: &Ui, galley: &Galley) -> Vec2 {
         let icon_width = ui.spacing().icon_width;
         let icon_spacing = ui.spacing().icon_spacing;
-        let padding = vec2(2.0, 2.0);
+        let padding = Self::padding();
         let total_extra = padding + vec2(icon_width + icon_spacing, 0.0) + padding;
 
-        let text_style = TextStyle::Button;
-        let galley = ui.fonts().layout_no_wrap(text_style, text.clone());
-
         let mut desired_size = total_extra + galley.size;
         desired_size = desired_size.at_least(ui.spacing().interact_size);
         desired_size.y = desired_size.y.at_least(icon_width);
+        desired_size
+    }
 
-        let (rect, response) = ui.allocate_exact_size(desired_size, Sense::click());
+    fn ui(&mut self, ui: &mut Ui, id: Id, rect: Rect, galley: Arc<Galley>) -> Response {
+        let LegendEntry { checked, color, .. } = self;
+        let icon_width = ui.spacing().icon_width;
+        let icon_spacing = ui.spacing().icon_spacing;
+        let padding = Self::padding();
+
+        let response = ui.interact(rect, id, Sense::click());
         let rect = rect.shrink2(padding);
 
         response.widget_info(|| WidgetInfo::selected(WidgetType::Checkbox, *checked, &galley.text));
@@ -79,3 +177,167 @@ impl Widget for &mut LegendEntry {
         response
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// Shows the legend of a plot and keeps track of which curves are hidden or hovered.
+pub(crate) struct LegendWidget {
+    id: Id,
+    config: Legend,
+    entries: BTreeMap<String, LegendEntry>,
+}
+
+impl LegendWidget {
+    /// Create a new legend from the names and colors of the plot items.
+    /// Returns `None` if there are no named items to show.
+    pub fn try_new<'a>(
+        id: Id,
+        config: Legend,
+        items: impl Iterator<Item = (&'a String, Color32)>,
+        hidden_curves: &HashSet<String>,
+        neutral_color: Color32,
+    ) -> Option<Self> {
+        // Collect the legend entries. If multiple items have the same name, they share a
+        // checkbox. If their colors don't match, we pick a neutral color for the checkbox.
+        let mut entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
+        items
+            .filter(|(name, _)| !name.is_empty())
+            .for_each(|(name, color)| {
+                let checked = !hidden_curves.contains(name);
+                entries
+                    .entry(name.clone())
+                    .and_modify(|entry| {
+                        if entry.color != color {
+                            entry.color = neutral_color
+                        }
+                    })
+                    .or_insert_with(|| LegendEntry::new(color, checked));
+            });
+        if entries.is_empty() {
+            None
+        } else {
+            Some(Self {
+                id,
+                config,
+                entries,
+            })
+        }
+    }
+
+    /// Names of the curves the user has hidden by unchecking their legend entry.
+    pub fn hidden_curves(&self) -> HashSet<String> {
+        self.entries
+            .iter()
+            .filter(|(_, entry)| !entry.checked)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Name of the currently hovered entry, if any.
+    pub fn hovered_entry_name(&self) -> Option<String> {
+        self.entries
+            .iter()
+            .find(|(_, entry)| entry.hovered)
+            .map(|(name, _)| name.clone())
+    }
+
+    fn margin() -> Vec2 {
+        vec2(4.0, 4.0)
+    }
+
+    fn galleys(&self, ui: &Ui) -> Vec<Arc<Galley>> {
+        self.entries
+            .keys()
+            .map(|name| {
+                ui.fonts()
+                    .layout_no_wrap(self.config.text_style, name.clone())
+            })
+            .collect()
+    }
+
+    /// Column widths and row heights of the entry grid.
+    fn grid_sizes(&self, ui: &Ui, galleys: &[Arc<Galley>]) -> (Vec<f32>, Vec<f32>) {
+        let columns = self
+            .config
+            .columns
+            .at_least(1)
+            .at_most(galleys.len().max(1));
+        let mut column_widths = vec![0.0_f32; columns];
+        let mut row_heights = Vec::new();
+        for (i, galley) in galleys.iter().enumerate() {
+            if i % columns == 0 {
+                row_heights.push(0.0_f32);
+            }
+            let size = LegendEntry::desired_size(ui, galley);
+            column_widths[i % columns] = column_widths[i % columns].max(size.x);
+            row_heights[i / columns] = row_heights[i / columns].max(size.y);
+        }
+        (column_widths, row_heights)
+    }
+
+    /// The size of the legend, including its margin.
+    pub fn size(&self, ui: &Ui) -> Vec2 {
+        let (column_widths, row_heights) = self.grid_sizes(ui, &self.galleys(ui));
+        vec2(column_widths.iter().sum(), row_heights.iter().sum()) + 2.0 * Self::margin()
+    }
+
+    /// Shrink the given plot rectangle to make room for a legend placed outside of it.
+    pub fn shrink_frame(&self, ui: &Ui, rect: Rect) -> Rect {
+        let mut frame = rect;
+        if self.config.outside {
+            let width = self.size(ui).x + ui.spacing().item_spacing.x;
+            if self.config.position.is_left() {
+                frame.min.x = (frame.min.x + width).at_most(frame.max.x);
+            } else {
+                frame.max.x = (frame.max.x - width).at_least(frame.min.x);
+            }
+        }
+        frame
+    }
+
+    /// Show the legend in the corner of the given plot frame.
+    pub fn ui(&mut self, ui: &mut Ui, frame: Rect) {
+        let galleys = self.galleys(ui);
+        let (column_widths, row_heights) = self.grid_sizes(ui, &galleys);
+        let margin = Self::margin();
+        let size = vec2(column_widths.iter().sum(), row_heights.iter().sum()) + 2.0 * margin;
+
+        let Legend {
+            position,
+            outside,
+            background,
+            ..
+        } = self.config;
+
+        let x = match (position.is_left(), outside) {
+            (true, false) => frame.left(),
+            (false, false) => frame.right() - size.x,
+            (true, true) => frame.left() - size.x - ui.spacing().item_spacing.x,
+            (false, true) => frame.right() + ui.spacing().item_spacing.x,
+        };
+        let y = if position.is_top() {
+            frame.top()
+        } else {
+            frame.bottom() - size.y
+        };
+        let legend_rect = Rect::from_min_size(pos2(x, y), size);
+
+        if background != Color32::TRANSPARENT {
+            ui.painter().rect_filled(legend_rect, 2.0, background);
+        }
+
+        let columns = column_widths.len();
+        let id = self.id;
+        let mut pos = legend_rect.min + margin;
+        for (i, ((name, entry), galley)) in self.entries.iter_mut().zip(galleys).enumerate() {
+            let (column, row) = (i % columns, i / columns);
+            if column == 0 && row > 0 {
+                pos.x = legend_rect.min.x + margin.x;
+                pos.y += row_heights[row - 1];
+            }
+            let rect = Rect::from_min_size(pos, vec2(column_widths[column], row_heights[row]));
+            entry.ui(ui, id.with(name), rect, galley);
+            pos.x += column_widths[column];
+        }
+    }
+}
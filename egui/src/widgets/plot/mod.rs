@@ -1,31 +1,123 @@
 //! Simple plotting library.
 
+mod contour;
 mod items;
 mod legend;
+mod pie;
+mod polar;
 mod transform;
+mod vector_field;
 
-use std::collections::{BTreeMap, HashSet};
+use std::collections::HashSet;
 
-pub use items::{Curve, Value};
-pub use items::{HLine, VLine};
-use transform::{Bounds, ScreenTransform};
+pub use contour::Contour;
+pub use items::{Curve, RollingBuffer, Value, YAxis};
+pub use items::{HLine, HSpan, PlotImage, VLine, VSpan};
+pub use legend::{Corner, Legend};
+pub use pie::{PieChart, Slice};
+pub use polar::PolarPlot;
+pub use transform::Bounds;
+use transform::ScreenTransform;
+pub use vector_field::VectorField;
 
 use crate::*;
 use color::Hsva;
+use epaint::Mesh;
 
-use self::legend::LegendEntry;
+use self::legend::LegendWidget;
 
 // ----------------------------------------------------------------------------
 
+/// The `index`-th of a series of distinct colors, for items that were not given a color.
+fn auto_color(index: usize) -> Color32 {
+    let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
+    let h = index as f32 * golden_ratio;
+    Hsva::new(h, 0.85, 0.5, 1.0).into() // TODO: OkLab or some other perspective color space
+}
+
+// ----------------------------------------------------------------------------
+
+/// The x-value of the pointer in one of the plots linked with [`Plot::link_cursor`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct LinkedCursor {
+    /// The plot that is hovered.
+    source: Id,
+    x: f64,
+}
+
+/// The unit of a plot axis, shown in its labels and in the hover readout.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct AxisUnit {
+    unit: String,
+    si_prefixes: bool,
+}
+
+impl AxisUnit {
+    /// Format a value with this unit, e.g. `12 µV`.
+    /// Without `decimals`, the value is rounded to a few decimals to hide floating point noise.
+    fn format(&self, value: f64, decimals: Option<usize>) -> String {
+        let format_number = |value: f64, decimals: Option<usize>| match decimals {
+            Some(decimals) => format!("{:.*}", decimals, value),
+            None => emath::round_to_decimals(value, 5).to_string(),
+        };
+        if self.unit.is_empty() {
+            return format_number(value, decimals);
+        }
+
+        if self.si_prefixes && value != 0.0 && value.is_finite() {
+            const PREFIXES: [&str; 17] = [
+                "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+            ];
+            let exponent = ((value.abs().log10() / 3.0).floor() as i32).clamp(-8, 8);
+            let scaled = value / 10_f64.powi(3 * exponent);
+            let decimals =
+                decimals.map(|decimals| (decimals as i32 + 3 * exponent).max(0) as usize);
+            format!(
+                "{} {}{}",
+                format_number(scaled, decimals),
+                PREFIXES[(exponent + 8) as usize],
+                self.unit
+            )
+        } else {
+            format!("{} {}", format_number(value, decimals), self.unit)
+        }
+    }
+}
+
 /// Information about the plot that has to persist between frames.
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone)]
 struct PlotMemory {
     bounds: Bounds,
+    /// The y-range of the secondary (right) y-axis. The x-range is shared with `bounds`.
+    #[cfg_attr(feature = "persistence", serde(default = "nothing_bounds"))]
+    right_bounds: Bounds,
     auto_bounds: bool,
+    /// The bounds given with [`Plot::initial_bounds`] when `bounds` were last seeded with them.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    seeded_bounds: Option<Bounds>,
     hidden_curves: HashSet<String>,
+    #[cfg_attr(feature = "persistence", serde(default))]
+    hovered_entry: Option<String>,
+    /// Where the current box zoom selection started, in screen coordinates.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    box_zoom_start: Option<Pos2>,
+    /// The points of the ongoing selection drag, in screen coordinates.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    selection_path: Vec<Pos2>,
+    #[cfg_attr(feature = "persistence", serde(default))]
+    selected_points: Vec<SelectedPoints>,
+}
+
+/// For memory saved before there was a right y-axis. Invalid bounds are recalculated.
+#[cfg(feature = "persistence")]
+fn nothing_bounds() -> Bounds {
+    Bounds::NOTHING
 }
 
+/// The contents of the menu shown by [`Plot::context_menu`].
+type ContextMenu<'a> = Box<dyn FnOnce(&mut Ui, Value) + 'a>;
+
 // ----------------------------------------------------------------------------
 
 /// A 2D plot, e.g. a graph of a function.
@@ -44,20 +136,35 @@ struct PlotMemory {
 ///     Plot::new("Test Plot").curve(curve).view_aspect(2.0)
 /// );
 /// ```
-pub struct Plot {
+pub struct Plot<'a> {
     name: String,
     next_auto_color_idx: usize,
 
     curves: Vec<Curve>,
     hlines: Vec<HLine>,
     vlines: Vec<VLine>,
+    hspans: Vec<HSpan>,
+    vspans: Vec<VSpan>,
+    contours: Vec<Contour>,
+    vector_fields: Vec<VectorField>,
+    images: Vec<PlotImage>,
 
     center_x_axis: bool,
     center_y_axis: bool,
-    allow_zoom: bool,
-    allow_drag: bool,
+    allow_zoom: [bool; 2],
+    allow_drag: [bool; 2],
+    allow_box_zoom: bool,
+    selection_mode: Option<SelectionMode>,
     min_auto_bounds: Bounds,
     margin_fraction: Vec2,
+    set_bounds: Option<Bounds>,
+    initial_bounds: Option<Bounds>,
+    reset: bool,
+    set_hidden_curves: Option<HashSet<String>>,
+    bounds_limit: Option<Bounds>,
+    min_x_range: f64,
+    max_x_range: f64,
+    rolling_window: Option<f64>,
 
     min_size: Vec2,
     width: Option<f32>,
@@ -67,10 +174,21 @@ pub struct Plot {
 
     show_x: bool,
     show_y: bool,
-    show_legend: bool,
+    hover_mode: HoverMode,
+    x_categories: Vec<String>,
+    show_grid: [bool; 2],
+    grid_stroke: Option<Stroke>,
+    grid_base: i64,
+    grid_min_spacing: f32,
+    grid_spacing: [Option<f64>; 2],
+    ticks: [Vec<(f64, String)>; 2],
+    legend_config: Option<Legend>,
+    cursor_link: Option<Id>,
+    units: [AxisUnit; 2],
+    context_menu: Option<ContextMenu<'a>>,
 }
 
-impl Plot {
+impl<'a> Plot<'a> {
     #[allow(clippy::needless_pass_by_value)]
     pub fn new(name: impl ToString) -> Self {
         Self {
@@ -80,13 +198,28 @@ impl Plot {
             curves: Default::default(),
             hlines: Default::default(),
             vlines: Default::default(),
+            hspans: Default::default(),
+            vspans: Default::default(),
+            contours: Default::default(),
+            vector_fields: Default::default(),
+            images: Default::default(),
 
             center_x_axis: false,
             center_y_axis: false,
-            allow_zoom: true,
-            allow_drag: true,
+            allow_zoom: [true; 2],
+            allow_drag: [true; 2],
+            allow_box_zoom: true,
+            selection_mode: None,
             min_auto_bounds: Bounds::NOTHING,
             margin_fraction: Vec2::splat(0.05),
+            set_bounds: None,
+            initial_bounds: None,
+            reset: false,
+            set_hidden_curves: None,
+            bounds_limit: None,
+            min_x_range: 0.0,
+            max_x_range: f64::INFINITY,
+            rolling_window: None,
 
             min_size: Vec2::splat(64.0),
             width: None,
@@ -96,17 +229,34 @@ impl Plot {
 
             show_x: true,
             show_y: true,
-            show_legend: true,
+            hover_mode: HoverMode::ClosestPoint,
+            x_categories: Vec::new(),
+            show_grid: [true; 2],
+            grid_stroke: None,
+            grid_base: 10,
+            grid_min_spacing: 6.0,
+            grid_spacing: [None; 2],
+            ticks: Default::default(),
+            legend_config: Some(Legend::default()),
+            cursor_link: None,
+            context_menu: None,
+            units: [
+                AxisUnit {
+                    unit: String::new(),
+                    si_prefixes: true,
+                },
+                AxisUnit {
+                    unit: String::new(),
+                    si_prefixes: true,
+                },
+            ],
         }
     }
 
     fn auto_color(&mut self, color: &mut Color32) {
         if *color == Color32::TRANSPARENT {
-            let i = self.next_auto_color_idx;
+            *color = auto_color(self.next_auto_color_idx);
             self.next_auto_color_idx += 1;
-            let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
-            let h = i as f32 * golden_ratio;
-            *color = Hsva::new(h, 0.85, 0.5, 1.0).into(); // TODO: OkLab or some other perspective color space
         }
     }
 
@@ -138,6 +288,52 @@ impl Plot {
         self
     }
 
+    /// Add a horizontal band, e.g. to mark an alarm range.
+    /// Always fills the full width of the plot.
+    pub fn hspan(mut self, mut hspan: HSpan) -> Self {
+        self.auto_span_color(&mut hspan.fill);
+        self.hspans.push(hspan);
+        self
+    }
+
+    /// Add a vertical band, e.g. to mark a time window.
+    /// Always fills the full height of the plot.
+    pub fn vspan(mut self, mut vspan: VSpan) -> Self {
+        self.auto_span_color(&mut vspan.fill);
+        self.vspans.push(vspan);
+        self
+    }
+
+    /// Add contour lines of a scalar field. Levels without a color are auto-assigned one.
+    pub fn contour(mut self, mut contour: Contour) -> Self {
+        for (_, color) in &mut contour.levels {
+            self.auto_color(color);
+        }
+        self.contours.push(contour);
+        self
+    }
+
+    /// Add arrows showing a vector field.
+    pub fn vector_field(mut self, mut vector_field: VectorField) -> Self {
+        self.auto_color(&mut vector_field.color);
+        self.vector_fields.push(vector_field);
+        self
+    }
+
+    /// Add an image in plot coordinates. Images are drawn behind everything else.
+    pub fn image(mut self, image: PlotImage) -> Self {
+        self.images.push(image);
+        self
+    }
+
+    /// Like `auto_color`, but translucent so the data behind a span stays visible.
+    fn auto_span_color(&mut self, fill: &mut Color32) {
+        if *fill == Color32::TRANSPARENT {
+            self.auto_color(fill);
+            *fill = fill.linear_multiply(0.25);
+        }
+    }
+
     /// width / height ratio of the data.
     /// For instance, it can be useful to set this to `1.0` for when the two axes show the same
     /// unit.
@@ -188,6 +384,147 @@ impl Plot {
         self
     }
 
+    /// Which values to show when hovering. Default: [`HoverMode::ClosestPoint`].
+    pub fn hover_mode(mut self, mode: HoverMode) -> Self {
+        self.hover_mode = mode;
+        self
+    }
+
+    /// Use a categorical x-axis: the x-value `i` is labeled with the `i`-th category
+    /// instead of a number, both on the axis and when hovering.
+    ///
+    /// Put the values of each category at its index on the x-axis,
+    /// e.g. with [`Curve::from_ys_f32`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::{Curve, Plot};
+    /// let curve = Curve::from_ys_f32(&[3.0, 5.0, 2.0]);
+    /// ui.add(Plot::new("Fruit").curve(curve).x_categories(&["apples", "pears", "plums"]));
+    /// ```
+    pub fn x_categories(mut self, categories: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.x_categories = categories
+            .into_iter()
+            .map(|category| category.to_string())
+            .collect();
+        self
+    }
+
+    /// Show the grid lines of both axes. Default: `true`.
+    ///
+    /// The axis labels are shown regardless.
+    pub fn show_grid(mut self, show: bool) -> Self {
+        self.show_grid = [show; 2];
+        self
+    }
+
+    /// Show the vertical grid lines, i.e. the ones of the x-axis. Default: `true`.
+    pub fn show_x_grid(mut self, show: bool) -> Self {
+        self.show_grid[0] = show;
+        self
+    }
+
+    /// Show the horizontal grid lines, i.e. the ones of the y-axis. Default: `true`.
+    pub fn show_y_grid(mut self, show: bool) -> Self {
+        self.show_grid[1] = show;
+        self
+    }
+
+    /// Paint all grid lines with this stroke.
+    ///
+    /// By default the grid lines are faded based on how densely they are spaced,
+    /// using the text color of the current theme.
+    pub fn grid_stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.grid_stroke = Some(stroke.into());
+        self
+    }
+
+    /// The grid lines are spaced by powers of this number, e.g. `2` for binary data
+    /// or `60` for seconds and minutes. Default: `10`.
+    pub fn grid_base(mut self, base: i64) -> Self {
+        self.grid_base = base.max(2);
+        self
+    }
+
+    /// The smallest distance between two grid lines, in points.
+    /// Lower values give denser minor lines. Default: `6.0`.
+    pub fn grid_min_spacing(mut self, min_spacing: f32) -> Self {
+        self.grid_min_spacing = min_spacing.at_least(1.0);
+        self
+    }
+
+    /// Put the grid lines of the x-axis at multiples of `step`, instead of at powers of the
+    /// [`Self::grid_base`]. Use e.g. `30.0` for angles in degrees.
+    ///
+    /// When zoomed out, lines are skipped to keep them [`Self::grid_min_spacing`] apart.
+    /// Every `grid_base`-th line is emphasized, so `.x_grid_spacing(30.0).grid_base(3)`
+    /// emphasizes every 90°.
+    pub fn x_grid_spacing(mut self, step: f64) -> Self {
+        if step > 0.0 && step.is_finite() {
+            self.grid_spacing[0] = Some(step);
+        }
+        self
+    }
+
+    /// Put the grid lines of the y-axis at multiples of `step`. See [`Self::x_grid_spacing`].
+    pub fn y_grid_spacing(mut self, step: f64) -> Self {
+        if step > 0.0 && step.is_finite() {
+            self.grid_spacing[1] = Some(step);
+        }
+        self
+    }
+
+    /// Only show grid lines and labels at these x-values, e.g. `[(0.0, "C4"), (12.0, "C5")]`.
+    ///
+    /// These replace the automatic grid lines of the x-axis, including any [`Self::x_categories`].
+    pub fn x_ticks(mut self, ticks: impl IntoIterator<Item = (f64, impl ToString)>) -> Self {
+        self.ticks[0] = ticks
+            .into_iter()
+            .map(|(value, label)| (value, label.to_string()))
+            .collect();
+        self
+    }
+
+    /// Only show grid lines and labels at these y-values. See [`Self::x_ticks`].
+    pub fn y_ticks(mut self, ticks: impl IntoIterator<Item = (f64, impl ToString)>) -> Self {
+        self.ticks[1] = ticks
+            .into_iter()
+            .map(|(value, label)| (value, label.to_string()))
+            .collect();
+        self
+    }
+
+    /// The unit of the x-axis, e.g. `"s"`, shown in the axis labels and the hover readout.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn x_unit(mut self, unit: impl ToString) -> Self {
+        self.units[0].unit = unit.to_string();
+        self
+    }
+
+    /// The unit of the (primary) y-axis, e.g. `"V"`, shown in the axis labels and the hover
+    /// readout.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn y_unit(mut self, unit: impl ToString) -> Self {
+        self.units[1].unit = unit.to_string();
+        self
+    }
+
+    /// Scale values with a unit by an SI prefix, so e.g. `0.000012` with the unit `"V"`
+    /// is shown as `12 µV`. Default: `true`.
+    pub fn si_prefixes(mut self, on: bool) -> Self {
+        self.units[0].si_prefixes = on;
+        self.units[1].si_prefixes = on;
+        self
+    }
+
+    /// Link the cursor of all plots with the same `group`: hovering one of them shows
+    /// a vertical line, with the values of the curves, at the same x-value in all the others.
+    /// Useful e.g. for stacked plots of several sensor channels.
+    pub fn link_cursor(mut self, group: impl std::hash::Hash) -> Self {
+        self.cursor_link = Some(Id::new(group).with("plot_cursor_link"));
+        self
+    }
+
     #[deprecated = "Renamed center_x_axis"]
     pub fn symmetrical_x_axis(mut self, on: bool) -> Self {
         self.center_x_axis = on;
@@ -214,13 +551,69 @@ impl Plot {
 
     /// Whether to allow zooming in the plot. Default: `true`.
     pub fn allow_zoom(mut self, on: bool) -> Self {
-        self.allow_zoom = on;
+        self.allow_zoom = [on; 2];
+        self
+    }
+
+    /// Whether to allow zooming along the x-axis. Default: `true`.
+    pub fn allow_zoom_x(mut self, on: bool) -> Self {
+        self.allow_zoom[0] = on;
+        self
+    }
+
+    /// Whether to allow zooming along the y-axis. Default: `true`.
+    ///
+    /// If neither zooming nor dragging is allowed along the y-axis, the y-axis is kept
+    /// fitted to the data within the visible x range.
+    pub fn allow_zoom_y(mut self, on: bool) -> Self {
+        self.allow_zoom[1] = on;
         self
     }
 
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
     pub fn allow_drag(mut self, on: bool) -> Self {
-        self.allow_drag = on;
+        self.allow_drag = [on; 2];
+        self
+    }
+
+    /// Whether to allow dragging the bounds along the x-axis. Default: `true`.
+    pub fn allow_drag_x(mut self, on: bool) -> Self {
+        self.allow_drag[0] = on;
+        self
+    }
+
+    /// Whether to allow dragging the bounds along the y-axis. Default: `true`.
+    ///
+    /// If neither zooming nor dragging is allowed along the y-axis, the y-axis is kept
+    /// fitted to the data within the visible x range.
+    pub fn allow_drag_y(mut self, on: bool) -> Self {
+        self.allow_drag[1] = on;
+        self
+    }
+
+    /// Only allow zooming and dragging along the x-axis, e.g. for time series.
+    /// The y-axis is kept fitted to the data within the visible x range.
+    pub fn lock_y(self) -> Self {
+        self.allow_zoom_y(false).allow_drag_y(false)
+    }
+
+    /// Only allow zooming and dragging along the y-axis.
+    pub fn lock_x(self) -> Self {
+        self.allow_zoom_x(false).allow_drag_x(false)
+    }
+
+    /// Whether to allow zooming to a region selected by dragging with the secondary mouse button.
+    /// Default: `true`.
+    pub fn allow_box_zoom(mut self, on: bool) -> Self {
+        self.allow_box_zoom = on;
+        self
+    }
+
+    /// Let the user select points by dragging with the primary mouse button while holding shift.
+    /// The selected points are highlighted and reported in [`PlotResponse::selected_points`].
+    /// Default: no selection.
+    pub fn selection(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = Some(mode);
         self
     }
 
@@ -238,27 +631,325 @@ impl Plot {
         self
     }
 
+    /// Show exactly these bounds, overriding any panning and zooming done by the user.
+    ///
+    /// The user can still pan and zoom away from these bounds in later frames
+    /// unless you keep calling this. Use [`PlotResponse::bounds`] to read back what is shown.
+    pub fn set_bounds(mut self, bounds: Bounds) -> Self {
+        self.set_bounds = Some(bounds);
+        self
+    }
+
+    /// Start out showing these bounds instead of fitting them to the data.
+    ///
+    /// The bounds are remembered between frames (and sessions, with the `persistence` feature)
+    /// together with the panning and zooming done by the user. Changing the initial bounds,
+    /// e.g. in a new version of your app, replaces any bounds remembered from before.
+    pub fn initial_bounds(mut self, bounds: Bounds) -> Self {
+        self.initial_bounds = Some(bounds);
+        self
+    }
+
+    /// Forget any panning and zooming done by the user.
+    ///
+    /// The plot goes back to its [`Self::initial_bounds`] if given,
+    /// otherwise the bounds are fitted to the data again.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::Plot;
+    /// let mut plot = Plot::new("Test Plot");
+    /// if ui.button("Reset view").clicked() {
+    ///     plot = plot.reset();
+    /// }
+    /// ui.add(plot);
+    /// ```
+    pub fn reset(mut self) -> Self {
+        self.reset = true;
+        self
+    }
+
+    /// Hide exactly the curves with these names, as if the user had unchecked them in the legend.
+    /// All other curves are shown.
+    ///
+    /// The user can still toggle curves in the legend in later frames unless you keep
+    /// calling this. Use [`PlotResponse::hidden_curves`] to read back which curves are hidden,
+    /// e.g. to persist them in your own settings.
+    pub fn set_hidden_curves(mut self, names: impl IntoIterator<Item = impl ToString>) -> Self {
+        self.set_hidden_curves = Some(names.into_iter().map(|name| name.to_string()).collect());
+        self
+    }
+
+    /// Never show anything outside of these bounds. Panning stops at the edges,
+    /// and zooming out stops once the bounds are filled.
+    ///
+    /// Use infinite values to leave a side unlimited, e.g.
+    /// `Bounds::from_min_max([0.0, f64::NEG_INFINITY], [f64::INFINITY, f64::INFINITY])`
+    /// to only prevent panning to negative x-values.
+    pub fn bounds_limit(mut self, limit: Bounds) -> Self {
+        self.bounds_limit = Some(limit);
+        self
+    }
+
+    /// The smallest width of the x-range the user can zoom in to. Default: `0.0`.
+    pub fn min_x_range(mut self, min_width: f64) -> Self {
+        self.min_x_range = min_width;
+        self
+    }
+
+    /// The largest width of the x-range the user can zoom out to. Default: unlimited.
+    pub fn max_x_range(mut self, max_width: f64) -> Self {
+        self.max_x_range = max_width;
+        self
+    }
+
+    /// Follow the newest data: when the bounds are automatic, only show the last `x_width`
+    /// units of the x-axis (ending at the largest x-value of any curve),
+    /// with the y-axis fitted to the values in that window.
+    ///
+    /// Panning or zooming stops following; double-click to resume.
+    /// Useful together with [`RollingBuffer`] for live data.
+    pub fn rolling_window(mut self, x_width: f64) -> Self {
+        self.rolling_window = Some(x_width);
+        self
+    }
+
     /// Whether to show a legend including all named curves. Default: `true`.
     pub fn show_legend(mut self, show: bool) -> Self {
-        self.show_legend = show;
+        if !show {
+            self.legend_config = None;
+        } else if self.legend_config.is_none() {
+            self.legend_config = Some(Legend::default());
+        }
         self
     }
+
+    /// Show a legend including all named curves, configured by `legend`.
+    pub fn legend(mut self, legend: Legend) -> Self {
+        self.legend_config = Some(legend);
+        self
+    }
+
+    /// Show a menu when the plot is clicked with the secondary mouse button.
+    ///
+    /// `add_contents` is given the clicked position in plot coordinates.
+    /// The menu is closed by any click or by pressing escape.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::{Plot, VLine, Value};
+    /// let mut markers: Vec<f64> = vec![];
+    /// let mut plot = Plot::new("Markers");
+    /// for &x in &markers {
+    ///     plot = plot.vline(VLine::new(x, (1.0, egui::Color32::RED)));
+    /// }
+    /// ui.add(plot.context_menu(|ui, value: Value| {
+    ///     if ui.button("Add marker here").clicked() {
+    ///         markers.push(value.x);
+    ///     }
+    /// }));
+    /// ```
+    pub fn context_menu(mut self, add_contents: impl FnOnce(&mut Ui, Value) + 'a) -> Self {
+        self.context_menu = Some(Box::new(add_contents));
+        self
+    }
+}
+
+/// A data point of a [`Curve`] close to the pointer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HoveredPoint {
+    /// The name of the curve the point belongs to. Can be empty.
+    pub curve_name: String,
+    /// The index of the point in the curve, e.g. to look up your own metadata.
+    pub index: usize,
+    /// The value of the point.
+    pub value: Value,
+    /// The label given with [`Curve::point_labels`], if any.
+    pub label: Option<String>,
+}
+
+/// Which values are shown when hovering a [`Plot`], see [`Plot::hover_mode`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HoverMode {
+    /// The data point closest to the pointer.
+    ClosestPoint,
+    /// The point of every curve closest to the hovered x-value, listed together.
+    /// Useful for several time series sharing the x-axis.
+    EachCurve,
+}
+
+/// How the user selects points in a [`Plot`], see [`Plot::selection`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// Select the points within a rectangle.
+    Rectangle,
+    /// Select the points within a free-form shape drawn with the pointer.
+    Lasso,
+}
+
+impl SelectionMode {
+    /// The selected area, from the points of the selection drag.
+    fn polygon(self, path: &[Pos2]) -> Vec<Pos2> {
+        match (self, path) {
+            (SelectionMode::Rectangle, [start, end]) => {
+                let rect = Rect::from_two_pos(*start, *end);
+                vec![
+                    rect.left_top(),
+                    rect.right_top(),
+                    rect.right_bottom(),
+                    rect.left_bottom(),
+                ]
+            }
+            _ => path.to_vec(),
+        }
+    }
+}
+
+/// The selected points of one [`Curve`].
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+#[derive(Clone, Debug, PartialEq)]
+pub struct SelectedPoints {
+    /// The name of the curve. Can be empty.
+    pub curve_name: String,
+    /// The indices of the selected points in the curve.
+    pub indices: Vec<usize>,
+}
+
+/// Whether `point` is inside the polygon, using the even-odd rule.
+fn polygon_contains(polygon: &[Pos2], point: Pos2) -> bool {
+    let mut inside = false;
+    let mut previous = match polygon.last() {
+        Some(last) => *last,
+        None => return false,
+    };
+    for &current in polygon {
+        if (current.y > point.y) != (previous.y > point.y)
+            && point.x
+                < (previous.x - current.x) * (point.y - current.y) / (previous.y - current.y)
+                    + current.x
+        {
+            inside = !inside;
+        }
+        previous = current;
+    }
+    inside
+}
+
+/// The result of showing a [`Plot`] with [`Plot::show`].
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Plot, Value};
+/// let mut marker: Option<Value> = None;
+/// let plot_response = Plot::new("Test Plot").show(ui);
+/// if let Some(value) = plot_response.clicked_value() {
+///     marker = Some(value); // e.g. show a marker where the user clicked
+/// }
+/// ```
+pub struct PlotResponse {
+    /// The response of the plot frame.
+    pub response: Response,
+    /// The bounds shown in this frame, after any panning and zooming.
+    pub bounds: Bounds,
+    /// Where the pointer is, in plot coordinates, if the plot is hovered.
+    pub pointer_value: Option<Value>,
+    /// The data point closest to the pointer, if the pointer is close enough to any.
+    pub hovered_point: Option<HoveredPoint>,
+    /// Names of the curves (and other named items) hidden in the legend.
+    pub hidden_curves: HashSet<String>,
+    /// The points the user has selected, see [`Plot::selection`].
+    /// Only curves with selected points are listed.
+    pub selected_points: Vec<SelectedPoints>,
+    /// Whether the user finished a new selection this frame.
+    pub selection_changed: bool,
+    transform: ScreenTransform,
+}
+
+impl PlotResponse {
+    /// The plot coordinates of a click with the given button, if any.
+    pub fn clicked_value_by(&self, button: PointerButton) -> Option<Value> {
+        if self.response.clicked_by(button) {
+            self.response
+                .interact_pointer_pos()
+                .map(|pos| self.transform.value_from_position(pos))
+        } else {
+            None
+        }
+    }
+
+    /// The plot coordinates of a click with the primary button, if any.
+    pub fn clicked_value(&self) -> Option<Value> {
+        self.clicked_value_by(PointerButton::Primary)
+    }
+
+    /// The data point that was clicked with the primary button, if any.
+    pub fn clicked_point(&self) -> Option<&HoveredPoint> {
+        if self.response.clicked() {
+            self.hovered_point.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Convert a screen position to plot coordinates.
+    pub fn value_from_position(&self, pos: Pos2) -> Value {
+        self.transform.value_from_position(pos)
+    }
+
+    /// Convert plot coordinates to a screen position.
+    pub fn position_from_value(&self, value: &Value) -> Pos2 {
+        self.transform.position_from_value(value)
+    }
 }
 
-impl Widget for Plot {
+impl Widget for Plot<'_> {
     fn ui(self, ui: &mut Ui) -> Response {
+        self.show(ui).response
+    }
+}
+
+impl Plot<'_> {
+    /// Show the plot and return its [`Response`] together with the bounds that were shown.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// use egui::plot::{Bounds, Curve, Plot, Value};
+    /// let curve = Curve::from_values(vec![Value::new(0.0, 0.0), Value::new(10.0, 5.0)]);
+    /// let mut plot = Plot::new("Test Plot").curve(curve);
+    /// if ui.button("Jump to x = 5").clicked() {
+    ///     plot = plot.set_bounds(Bounds::from_min_max([4.0, 0.0], [6.0, 5.0]));
+    /// }
+    /// let bounds = plot.show(ui).bounds;
+    /// ui.label(format!("Showing x from {} to {}", bounds.min()[0], bounds.max()[0]));
+    /// ```
+    pub fn show(self, ui: &mut Ui) -> PlotResponse {
         let Self {
             name,
             next_auto_color_idx: _,
             mut curves,
-            hlines,
-            vlines,
+            mut hlines,
+            mut vlines,
+            mut hspans,
+            mut vspans,
+            mut contours,
+            mut vector_fields,
+            mut images,
             center_x_axis,
             center_y_axis,
             allow_zoom,
             allow_drag,
+            allow_box_zoom,
+            selection_mode,
             min_auto_bounds,
             margin_fraction,
+            set_bounds,
+            initial_bounds,
+            reset,
+            set_hidden_curves,
+            bounds_limit,
+            min_x_range,
+            max_x_range,
+            rolling_window,
             width,
             height,
             min_size,
@@ -266,7 +957,18 @@ impl Widget for Plot {
             view_aspect,
             mut show_x,
             mut show_y,
-            show_legend,
+            hover_mode,
+            x_categories,
+            show_grid,
+            grid_stroke,
+            grid_base,
+            grid_min_spacing,
+            grid_spacing,
+            ticks,
+            legend_config,
+            cursor_link,
+            units,
+            context_menu,
         } = self;
 
         let plot_id = ui.make_persistent_id(name);
@@ -275,17 +977,47 @@ impl Widget for Plot {
             .id_data
             .get_mut_or_insert_with(plot_id, || PlotMemory {
                 bounds: min_auto_bounds,
+                right_bounds: Bounds::NOTHING,
                 auto_bounds: !min_auto_bounds.is_valid(),
+                seeded_bounds: None,
                 hidden_curves: HashSet::new(),
+                hovered_entry: None,
+                box_zoom_start: None,
+                selection_path: Vec::new(),
+                selected_points: Vec::new(),
             })
             .clone();
 
         let PlotMemory {
             mut bounds,
+            mut right_bounds,
             mut auto_bounds,
+            mut seeded_bounds,
             mut hidden_curves,
+            mut hovered_entry,
+            mut box_zoom_start,
+            mut selection_path,
+            mut selected_points,
         } = memory;
 
+        // Remembered bounds from before the initial bounds were changed are stale.
+        if let Some(initial_bounds) = initial_bounds {
+            if reset || seeded_bounds != Some(initial_bounds) {
+                bounds = initial_bounds;
+                auto_bounds = false;
+                seeded_bounds = Some(initial_bounds);
+            }
+        } else if reset {
+            auto_bounds = true;
+        }
+        if let Some(set_bounds) = set_bounds {
+            bounds = set_bounds;
+            auto_bounds = false;
+        }
+        if let Some(set_hidden_curves) = set_hidden_curves {
+            hidden_curves = set_hidden_curves;
+        }
+
         // Determine the size of the plot in the UI
         let size = {
             let width = width
@@ -310,7 +1042,42 @@ impl Widget for Plot {
             vec2(width, height)
         };
 
-        let (rect, response) = ui.allocate_exact_size(size, Sense::drag());
+        let (full_rect, _) = ui.allocate_exact_size(size, Sense::hover());
+
+        // --- Legend ---
+
+        let mut legend = legend_config.and_then(|config| {
+            let items = curves
+                .iter()
+                .map(|curve| (&curve.name, curve.stroke.color))
+                .chain(hlines.iter().map(|line| (&line.name, line.stroke.color)))
+                .chain(vlines.iter().map(|line| (&line.name, line.stroke.color)))
+                .chain(hspans.iter().map(|span| (&span.name, span.fill)))
+                .chain(vspans.iter().map(|span| (&span.name, span.fill)))
+                .chain(contours.iter().filter_map(|contour| {
+                    let (_, color) = contour.levels.first()?;
+                    Some((&contour.name, *color))
+                }))
+                .chain(
+                    vector_fields
+                        .iter()
+                        .map(|vector_field| (&vector_field.name, vector_field.color)),
+                )
+                .chain(images.iter().map(|image| (&image.name, image.tint)));
+            LegendWidget::try_new(
+                plot_id.with("legend"),
+                config,
+                items,
+                &hidden_curves,
+                ui.visuals().noninteractive().fg_stroke.color,
+            )
+        });
+
+        // A legend placed outside of the plot takes space from the plot frame.
+        let rect = legend
+            .as_ref()
+            .map_or(full_rect, |legend| legend.shrink_frame(ui, full_rect));
+        let response = ui.interact(rect, plot_id, Sense::drag());
         let plot_painter = ui.painter().sub_region(rect);
 
         // Background
@@ -321,241 +1088,806 @@ impl Widget for Plot {
             stroke: ui.visuals().window_stroke(),
         });
 
-        // --- Legend ---
-
-        if show_legend {
-            // Collect the legend entries. If multiple curves have the same name, they share a
-            // checkbox. If their colors don't match, we pick a neutral color for the checkbox.
-            let mut legend_entries: BTreeMap<String, LegendEntry> = BTreeMap::new();
-            curves
-                .iter()
-                .filter(|curve| !curve.name.is_empty())
-                .for_each(|curve| {
-                    let checked = !hidden_curves.contains(&curve.name);
-                    let text = curve.name.clone();
-                    legend_entries
-                        .entry(curve.name.clone())
-                        .and_modify(|entry| {
-                            if entry.color != curve.stroke.color {
-                                entry.color = ui.visuals().noninteractive().fg_stroke.color
-                            }
-                        })
-                        .or_insert_with(|| LegendEntry::new(text, curve.stroke.color, checked));
-                });
-
-            // Show the legend.
-            let mut legend_ui = ui.child_ui(rect, Layout::top_down(Align::LEFT));
-            legend_entries.values_mut().for_each(|entry| {
-                let response = legend_ui.add(entry);
-                if response.hovered() {
-                    show_x = false;
-                    show_y = false;
-                }
-            });
-
-            // Get the names of the hidden curves.
-            hidden_curves = legend_entries
-                .values()
-                .filter(|entry| !entry.checked)
-                .map(|entry| entry.text.clone())
-                .collect();
-
-            // Highlight the hovered curves.
-            legend_entries
-                .values()
-                .filter(|entry| entry.hovered)
-                .for_each(|entry| {
-                    curves
-                        .iter_mut()
-                        .filter(|curve| curve.name == entry.text)
-                        .for_each(|curve| {
-                            curve.stroke.width *= 2.0;
-                        });
-                });
+        if legend.is_some() {
+            // Highlight the hovered curves and lines.
+            if let Some(hovered_name) = &hovered_entry {
+                show_x = false;
+                show_y = false;
+                curves
+                    .iter_mut()
+                    .filter(|curve| &curve.name == hovered_name)
+                    .for_each(|curve| curve.stroke.width *= 2.0);
+                hlines
+                    .iter_mut()
+                    .filter(|line| &line.name == hovered_name)
+                    .for_each(|line| line.stroke.width *= 2.0);
+                vlines
+                    .iter_mut()
+                    .filter(|line| &line.name == hovered_name)
+                    .for_each(|line| line.stroke.width *= 2.0);
+            }
 
-            // Remove deselected curves.
+            // Remove deselected curves and lines.
             curves.retain(|curve| !hidden_curves.contains(&curve.name));
+            hlines.retain(|line| !hidden_curves.contains(&line.name));
+            vlines.retain(|line| !hidden_curves.contains(&line.name));
+            hspans.retain(|span| !hidden_curves.contains(&span.name));
+            vspans.retain(|span| !hidden_curves.contains(&span.name));
+            contours.retain(|contour| !hidden_curves.contains(&contour.name));
+            vector_fields.retain(|vector_field| !hidden_curves.contains(&vector_field.name));
+            images.retain(|image| !hidden_curves.contains(&image.name));
         }
 
-        // ---
-
         auto_bounds |= response.double_clicked_by(PointerButton::Primary);
 
+        let has_right_axis = curves.iter().any(|curve| curve.y_axis == YAxis::Right);
+
+        // When following the newest data, only the values in the window determine the bounds.
+        let x_window = rolling_window.and_then(|x_width| {
+            let newest = curves
+                .iter()
+                .map(|curve| curve.bounds.max[0])
+                .fold(f64::NEG_INFINITY, f64::max);
+            if newest.is_finite() {
+                Some((newest - x_width)..=newest)
+            } else {
+                None
+            }
+        });
+        let curve_bounds = |curve: &Curve| match &x_window {
+            Some(x_window) => curve.bounds_in_x_range(x_window),
+            None => curve.bounds,
+        };
+
         // Set bounds automatically based on content.
         if auto_bounds || !bounds.is_valid() {
             bounds = min_auto_bounds;
             hlines.iter().for_each(|line| bounds.extend_with_y(line.y));
+            hspans.iter().for_each(|span| {
+                bounds.extend_with_y(*span.y_range.start());
+                bounds.extend_with_y(*span.y_range.end());
+            });
+            vspans.iter().for_each(|span| {
+                bounds.extend_with_x(*span.x_range.start());
+                bounds.extend_with_x(*span.x_range.end());
+            });
             vlines.iter().for_each(|line| bounds.extend_with_x(line.x));
-            curves.iter().for_each(|curve| bounds.merge(&curve.bounds));
+            contours.iter().for_each(|contour| {
+                let contour_bounds = contour.bounds();
+                bounds.merge_x(&contour_bounds);
+                bounds.merge_y(&contour_bounds);
+            });
+            images.iter().for_each(|image| {
+                bounds.merge_x(&image.bounds);
+                bounds.merge_y(&image.bounds);
+            });
+            vector_fields.iter().for_each(|vector_field| {
+                let vector_field_bounds = vector_field.bounds();
+                bounds.merge_x(&vector_field_bounds);
+                bounds.merge_y(&vector_field_bounds);
+            });
+            curves.iter().for_each(|curve| {
+                let curve_bounds = curve_bounds(curve);
+                bounds.merge_x(&curve_bounds);
+                if curve.y_axis == YAxis::Left {
+                    bounds.merge_y(&curve_bounds);
+                }
+            });
             bounds.add_relative_margin(margin_fraction);
+            if let Some(x_window) = &x_window {
+                bounds.min[0] = *x_window.start();
+                bounds.max[0] = *x_window.end();
+            }
         }
         // Make sure they are not empty.
         if !bounds.is_valid() {
             bounds = Bounds::new_symmetrical(1.0);
         }
 
+        // The secondary y-axis is scaled independently of the primary one.
+        if has_right_axis && (auto_bounds || !right_bounds.is_valid()) {
+            right_bounds = Bounds::NOTHING;
+            curves
+                .iter()
+                .filter(|curve| curve.y_axis == YAxis::Right)
+                .for_each(|curve| right_bounds.merge_y(&curve_bounds(curve)));
+            right_bounds.min[0] = bounds.min[0];
+            right_bounds.max[0] = bounds.max[0];
+            right_bounds.add_relative_margin(Vec2::new(0.0, margin_fraction.y));
+            if !right_bounds.is_valid() {
+                right_bounds.min[1] = bounds.min[1];
+                right_bounds.max[1] = bounds.max[1];
+            }
+        }
+
         // Scale axes so that the origin is in the center.
         if center_x_axis {
             bounds.make_x_symmetrical();
         };
         if center_y_axis {
-            bounds.make_y_symmetrical()
+            bounds.make_y_symmetrical();
+            right_bounds.make_y_symmetrical();
         };
 
         let mut transform = ScreenTransform::new(rect, bounds, center_x_axis, center_y_axis);
+        let mut right_transform = has_right_axis
+            .then(|| ScreenTransform::new(rect, right_bounds, center_x_axis, center_y_axis));
 
         // Enforce equal aspect ratio.
         if let Some(data_aspect) = data_aspect {
             transform.set_aspect(data_aspect as f64);
         }
 
+        // Only move along the axes that allow it.
+        let mask = |allowed: [bool; 2], delta: Vec2, neutral: f32| {
+            vec2(
+                if allowed[0] { delta.x } else { neutral },
+                if allowed[1] { delta.y } else { neutral },
+            )
+        };
+
+        // Selection: drag with the primary button while holding shift.
+        let mut finished_selection = None;
+        if let Some(mode) = selection_mode {
+            if selection_path.is_empty()
+                && response.dragged_by(PointerButton::Primary)
+                && ui.input().modifiers.shift
+            {
+                selection_path.extend(ui.input().pointer.press_origin());
+            }
+            if !selection_path.is_empty() {
+                let pointer = ui.input().pointer.interact_pos().map(|pos| rect.clamp(pos));
+                if let Some(pointer) = pointer {
+                    match mode {
+                        SelectionMode::Rectangle => {
+                            selection_path.truncate(1);
+                            selection_path.push(pointer);
+                        }
+                        SelectionMode::Lasso => {
+                            if selection_path.last() != Some(&pointer) {
+                                selection_path.push(pointer);
+                            }
+                        }
+                    }
+                }
+                if response.drag_released() {
+                    finished_selection = Some(mode.polygon(&selection_path));
+                    selection_path.clear();
+                } else if !response.dragged() {
+                    selection_path.clear();
+                }
+            }
+        }
+        let selecting = !selection_path.is_empty() || finished_selection.is_some();
+
         // Dragging
-        if allow_drag && response.dragged_by(PointerButton::Primary) {
-            transform.translate_bounds(-response.drag_delta());
+        if allow_drag.iter().any(|&on| on)
+            && !selecting
+            && response.dragged_by(PointerButton::Primary)
+        {
+            let drag_delta = mask(allow_drag, response.drag_delta(), 0.0);
+            transform.translate_bounds(-drag_delta);
+            if let Some(right_transform) = &mut right_transform {
+                right_transform.translate_bounds(-drag_delta);
+            }
             auto_bounds = false;
         }
 
         // Zooming
-        if allow_zoom {
+        if allow_zoom.iter().any(|&on| on) {
             if let Some(hover_pos) = response.hover_pos() {
                 let zoom_factor = if data_aspect.is_some() {
                     Vec2::splat(ui.input().zoom_delta())
                 } else {
                     ui.input().zoom_delta_2d()
                 };
+                let zoom_factor = mask(allow_zoom, zoom_factor, 1.0);
                 if zoom_factor != Vec2::splat(1.0) {
                     transform.zoom(zoom_factor, hover_pos);
+                    if let Some(right_transform) = &mut right_transform {
+                        right_transform.zoom(zoom_factor, hover_pos);
+                    }
                     auto_bounds = false;
                 }
 
-                let scroll_delta = ui.input().scroll_delta;
+                let scroll_delta = {
+                    let mut frame_state = ui.ctx().frame_state();
+                    let scroll_delta = mask(allow_drag, frame_state.scroll_delta, 0.0);
+                    // Don't let a surrounding `ScrollArea` scroll too:
+                    frame_state.scroll_delta -= scroll_delta;
+                    scroll_delta
+                };
                 if scroll_delta != Vec2::ZERO {
                     transform.translate_bounds(-scroll_delta);
+                    if let Some(right_transform) = &mut right_transform {
+                        right_transform.translate_bounds(-scroll_delta);
+                    }
                     auto_bounds = false;
                 }
             }
         }
 
+        // Box zoom: drag with the secondary button to select the region to zoom to.
+        let mut box_zoom_rect = None;
+        if allow_box_zoom {
+            if box_zoom_start.is_none() && response.dragged_by(PointerButton::Secondary) {
+                box_zoom_start = ui.input().pointer.press_origin();
+            }
+            if let Some(start) = box_zoom_start {
+                let end = ui.input().pointer.interact_pos().map(|pos| rect.clamp(pos));
+                if let Some(end) = end {
+                    let mut zoom_rect = Rect::from_two_pos(start, end);
+                    // Keep the full range of the axes that may not be zoomed.
+                    if !allow_zoom[0] {
+                        zoom_rect.min.x = rect.min.x;
+                        zoom_rect.max.x = rect.max.x;
+                    }
+                    if !allow_zoom[1] {
+                        zoom_rect.min.y = rect.min.y;
+                        zoom_rect.max.y = rect.max.y;
+                    }
+                    if response.drag_released() {
+                        box_zoom_start = None;
+                        // Ignore tiny selections, they are most likely accidental.
+                        if zoom_rect.width() > 2.0 && zoom_rect.height() > 2.0 {
+                            transform.zoom_to_rect(zoom_rect);
+                            if let Some(right_transform) = &mut right_transform {
+                                right_transform.zoom_to_rect(zoom_rect);
+                            }
+                            auto_bounds = false;
+                        }
+                    } else if response.dragged() {
+                        box_zoom_rect = Some(zoom_rect);
+                    } else {
+                        box_zoom_start = None;
+                    }
+                }
+            }
+        }
+
+        transform.limit_bounds(min_x_range..=max_x_range, bounds_limit.as_ref());
+
+        // The x-axis is shared between both y-axes.
+        if let Some(right_transform) = &mut right_transform {
+            right_transform.set_x_range_from(transform.bounds());
+        }
+
         // Initialize values from functions.
         curves
             .iter_mut()
             .for_each(|curve| curve.generate_points(transform.bounds().range_x()));
 
+        // With the y-axis locked, keep it fitted to the data in the visible x range.
+        let y_locked = !allow_zoom[1] && !allow_drag[1];
+        if y_locked && !auto_bounds && set_bounds.is_none() && data_aspect.is_none() {
+            let x_range = transform.bounds().range_x();
+            let fit_y = |y_axis: YAxis, bounds: &Bounds| {
+                let mut fitted = *bounds;
+                fitted.min[1] = f64::INFINITY;
+                fitted.max[1] = -f64::INFINITY;
+                if y_axis == YAxis::Left {
+                    fitted.merge_y(&min_auto_bounds);
+                    hlines.iter().for_each(|line| fitted.extend_with_y(line.y));
+                    hspans.iter().for_each(|span| {
+                        fitted.extend_with_y(*span.y_range.start());
+                        fitted.extend_with_y(*span.y_range.end());
+                    });
+                }
+                curves
+                    .iter()
+                    .filter(|curve| curve.y_axis == y_axis)
+                    .for_each(|curve| fitted.merge_y(&curve.bounds_in_x_range(&x_range)));
+                fitted.add_relative_margin(Vec2::new(0.0, margin_fraction.y));
+                if center_y_axis {
+                    fitted.make_y_symmetrical();
+                }
+                fitted
+            };
+            let fitted = fit_y(YAxis::Left, transform.bounds());
+            if fitted.is_valid() {
+                transform.set_y_range_from(&fitted);
+                transform.limit_bounds(min_x_range..=max_x_range, bounds_limit.as_ref());
+            }
+            if let Some(right_transform) = &mut right_transform {
+                let fitted = fit_y(YAxis::Right, right_transform.bounds());
+                if fitted.is_valid() {
+                    right_transform.set_y_range_from(&fitted);
+                }
+            }
+        }
+
         let bounds = *transform.bounds();
+        let right_bounds = right_transform
+            .as_ref()
+            .map_or(right_bounds, |right_transform| *right_transform.bounds());
+
+        let pointer_value = response
+            .hover_pos()
+            .map(|pos| transform.value_from_position(pos));
+        let response_transform = transform.clone();
+
+        // Share the cursor with the linked plots, and show theirs.
+        let mut linked_cursor_x = None;
+        if let Some(link) = cursor_link {
+            let mut memory = ui.memory();
+            let linked_cursor = memory.id_data_temp.get::<LinkedCursor>(&link).copied();
+            if let Some(pointer_value) = pointer_value {
+                memory.id_data_temp.insert(
+                    link,
+                    LinkedCursor {
+                        source: plot_id,
+                        x: pointer_value.x,
+                    },
+                );
+            } else if let Some(linked_cursor) = linked_cursor {
+                if linked_cursor.source == plot_id {
+                    memory.id_data_temp.remove(&link);
+                } else {
+                    linked_cursor_x = Some(linked_cursor.x);
+                }
+            }
+        }
 
         let prepared = Prepared {
             curves,
             hlines,
             vlines,
+            hspans,
+            vspans,
+            contours,
+            vector_fields,
+            images,
             show_x,
             show_y,
+            hover_mode,
+            x_categories,
+            show_grid,
+            grid_stroke,
+            grid_base,
+            grid_min_spacing,
+            grid_spacing,
+            ticks,
+            linked_cursor_x,
+            units,
             transform,
+            right_transform,
         };
         prepared.ui(ui, &response);
 
+        let hovered_point = response.hover_pos().and_then(|pointer| {
+            prepared
+                .closest_point(pointer)
+                .map(|(curve, index)| HoveredPoint {
+                    curve_name: curve.name.clone(),
+                    index,
+                    value: curve.values[index],
+                    label: curve.point_label(index).map(ToString::to_string),
+                })
+        });
+
+        let selection_changed = finished_selection.is_some();
+        if let Some(polygon) = &finished_selection {
+            selected_points = prepared.points_in_polygon(polygon);
+        }
+        prepared.paint_selected_points(ui, &selected_points);
+        if let Some(mode) = selection_mode {
+            if !selection_path.is_empty() {
+                let selection = ui.visuals().selection;
+                plot_painter.add(Shape::closed_line(
+                    mode.polygon(&selection_path),
+                    selection.stroke,
+                ));
+            }
+        }
+
+        if let Some(zoom_rect) = box_zoom_rect {
+            let selection = ui.visuals().selection;
+            plot_painter.rect(
+                zoom_rect,
+                0.0,
+                selection.bg_fill.linear_multiply(0.25),
+                selection.stroke,
+            );
+        }
+
+        // The legend is shown on top of the data. Changes take effect on the next frame.
+        if let Some(legend) = &mut legend {
+            legend.ui(ui, rect);
+            hidden_curves = legend.hidden_curves();
+            hovered_entry = legend.hovered_entry_name();
+        } else {
+            hovered_entry = None;
+        }
+
+        if let Some(add_contents) = context_menu {
+            show_context_menu(ui, plot_id, &response, &response_transform, add_contents);
+        }
+
         ui.memory().id_data.insert(
             plot_id,
             PlotMemory {
                 bounds,
+                right_bounds,
                 auto_bounds,
-                hidden_curves,
+                seeded_bounds,
+                hidden_curves: hidden_curves.clone(),
+                hovered_entry,
+                box_zoom_start,
+                selection_path,
+                selected_points: selected_points.clone(),
             },
         );
 
-        if show_x || show_y {
+        let response = if box_zoom_rect.is_some() {
+            response.on_hover_cursor(CursorIcon::ZoomIn)
+        } else if show_x || show_y {
             response.on_hover_cursor(CursorIcon::Crosshair)
         } else {
             response
+        };
+
+        PlotResponse {
+            response,
+            bounds,
+            pointer_value,
+            hovered_point,
+            hidden_curves,
+            selected_points,
+            selection_changed,
+            transform: response_transform,
+        }
+    }
+}
+
+/// The value closest to `x` along the x-axis, if any.
+fn closest_value_at_x(values: &[Value], x: f64) -> Option<&Value> {
+    values.iter().min_by(|a, b| {
+        let (da, db) = ((a.x - x).abs(), (b.x - x).abs());
+        da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+    })
+}
+
+/// Open the context menu on a secondary click and show it while it is open.
+fn show_context_menu(
+    ui: &Ui,
+    plot_id: Id,
+    response: &Response,
+    transform: &ScreenTransform,
+    add_contents: ContextMenu<'_>,
+) {
+    let menu_id = plot_id.with("context_menu");
+
+    // Remember where the menu was opened, so it stays in place while the plot changes.
+    let mut just_opened = false;
+    if response.clicked_by(PointerButton::Secondary) {
+        if let Some(pos) = response.interact_pointer_pos() {
+            let value = transform.value_from_position(pos);
+            let mut memory = ui.memory();
+            memory.id_data_temp.insert(menu_id, (pos, value));
+            memory.open_popup(menu_id);
+            just_opened = true;
         }
     }
+
+    if !ui.memory().is_popup_open(menu_id) {
+        return;
+    }
+    let opened_at = ui
+        .memory()
+        .id_data_temp
+        .get::<(Pos2, Value)>(&menu_id)
+        .copied();
+    if let Some((pos, value)) = opened_at {
+        Area::new(menu_id)
+            .order(Order::Foreground)
+            .fixed_pos(pos)
+            .show(ui.ctx(), |ui| {
+                Frame::popup(ui.style()).show(ui, |ui| {
+                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                        add_contents(ui, value)
+                    });
+                });
+            });
+    }
+
+    if opened_at.is_none()
+        || !just_opened && (ui.input().key_pressed(Key::Escape) || ui.input().pointer.any_click())
+    {
+        ui.memory().close_popup();
+    }
 }
 
 struct Prepared {
     curves: Vec<Curve>,
     hlines: Vec<HLine>,
     vlines: Vec<VLine>,
+    hspans: Vec<HSpan>,
+    vspans: Vec<VSpan>,
+    contours: Vec<Contour>,
+    vector_fields: Vec<VectorField>,
+    images: Vec<PlotImage>,
     show_x: bool,
     show_y: bool,
+    hover_mode: HoverMode,
+    x_categories: Vec<String>,
+    show_grid: [bool; 2],
+    grid_stroke: Option<Stroke>,
+    grid_base: i64,
+    grid_min_spacing: f32,
+    grid_spacing: [Option<f64>; 2],
+    ticks: [Vec<(f64, String)>; 2],
+    /// The x-value hovered in a linked plot.
+    linked_cursor_x: Option<f64>,
+    units: [AxisUnit; 2],
     transform: ScreenTransform,
+    /// Only set if there are curves on the secondary y-axis.
+    right_transform: Option<ScreenTransform>,
 }
 
 impl Prepared {
+    /// The transform to use for values on the given y-axis.
+    fn transform_for(&self, y_axis: YAxis) -> &ScreenTransform {
+        match (y_axis, &self.right_transform) {
+            (YAxis::Right, Some(right_transform)) => right_transform,
+            _ => &self.transform,
+        }
+    }
+
     fn ui(&self, ui: &mut Ui, response: &Response) {
         let Self { transform, .. } = self;
 
         let mut shapes = Vec::new();
 
+        // Images are a background layer, below the grid.
+        for image in &self.images {
+            let rect = Rect::from_two_pos(
+                transform
+                    .position_from_value(&Value::new(image.bounds.min[0], image.bounds.min[1])),
+                transform
+                    .position_from_value(&Value::new(image.bounds.max[0], image.bounds.max[1])),
+            );
+            let mut mesh = Mesh::with_texture(image.texture_id);
+            mesh.add_rect_with_uv(rect, image.uv, image.tint);
+            shapes.push(Shape::Mesh(mesh));
+        }
+
         for d in 0..2 {
-            self.paint_axis(ui, d, &mut shapes);
+            self.paint_axis(ui, transform, d, false, &mut shapes);
+        }
+        if let Some(right_transform) = &self.right_transform {
+            self.paint_axis(ui, right_transform, 1, true, &mut shapes);
         }
 
-        for &hline in &self.hlines {
-            let HLine { y, stroke } = hline;
+        // Spans go below the lines and curves, so they don't hide any data.
+        let frame = *transform.frame();
+        for hspan in &self.hspans {
+            let y_range = &hspan.y_range;
+            let y0 = transform
+                .position_from_value(&Value::new(0.0, *y_range.start()))
+                .y;
+            let y1 = transform
+                .position_from_value(&Value::new(0.0, *y_range.end()))
+                .y;
+            let rect = Rect::from_x_y_ranges(frame.x_range(), y0.min(y1)..=y0.max(y1));
+            shapes.push(Shape::rect_filled(rect, 0.0, hspan.fill));
+            for &y in &[y0, y1] {
+                shapes.push(Shape::line_segment(
+                    [pos2(frame.left(), y), pos2(frame.right(), y)],
+                    hspan.stroke,
+                ));
+            }
+        }
+        for vspan in &self.vspans {
+            let x_range = &vspan.x_range;
+            let x0 = transform
+                .position_from_value(&Value::new(*x_range.start(), 0.0))
+                .x;
+            let x1 = transform
+                .position_from_value(&Value::new(*x_range.end(), 0.0))
+                .x;
+            let rect = Rect::from_x_y_ranges(x0.min(x1)..=x0.max(x1), frame.y_range());
+            shapes.push(Shape::rect_filled(rect, 0.0, vspan.fill));
+            for &x in &[x0, x1] {
+                shapes.push(Shape::line_segment(
+                    [pos2(x, frame.top()), pos2(x, frame.bottom())],
+                    vspan.stroke,
+                ));
+            }
+        }
+
+        for hline in &self.hlines {
+            let HLine {
+                y, stroke, label, ..
+            } = hline;
             let points = [
-                transform.position_from_value(&Value::new(transform.bounds().min[0], y)),
-                transform.position_from_value(&Value::new(transform.bounds().max[0], y)),
+                transform.position_from_value(&Value::new(transform.bounds().min[0], *y)),
+                transform.position_from_value(&Value::new(transform.bounds().max[0], *y)),
             ];
-            shapes.push(Shape::line_segment(points, stroke));
+            shapes.push(Shape::line_segment(points, *stroke));
+            if !label.is_empty() {
+                // Above the right end of the line.
+                shapes.push(Shape::text(
+                    ui.fonts(),
+                    points[1] + vec2(-4.0, -2.0),
+                    Align2::RIGHT_BOTTOM,
+                    label,
+                    TextStyle::Body,
+                    stroke.color,
+                ));
+            }
         }
 
-        for &vline in &self.vlines {
-            let VLine { x, stroke } = vline;
+        for vline in &self.vlines {
+            let VLine {
+                x, stroke, label, ..
+            } = vline;
             let points = [
-                transform.position_from_value(&Value::new(x, transform.bounds().min[1])),
-                transform.position_from_value(&Value::new(x, transform.bounds().max[1])),
+                transform.position_from_value(&Value::new(*x, transform.bounds().min[1])),
+                transform.position_from_value(&Value::new(*x, transform.bounds().max[1])),
             ];
-            shapes.push(Shape::line_segment(points, stroke));
+            shapes.push(Shape::line_segment(points, *stroke));
+            if !label.is_empty() {
+                // Right of the top end of the line.
+                shapes.push(Shape::text(
+                    ui.fonts(),
+                    points[1] + vec2(4.0, 4.0),
+                    Align2::LEFT_TOP,
+                    label,
+                    TextStyle::Body,
+                    stroke.color,
+                ));
+            }
+        }
+
+        for contour in &self.contours {
+            for &(level, color) in &contour.levels {
+                let segments = contour.segments(level);
+                let stroke = Stroke::new(contour.stroke_width, color);
+                shapes.extend(segments.iter().map(|segment| {
+                    Shape::line_segment(
+                        [
+                            transform.position_from_value(&segment[0]),
+                            transform.position_from_value(&segment[1]),
+                        ],
+                        stroke,
+                    )
+                }));
+                // Label each level once, somewhere along its line.
+                if let (true, Some(segment)) =
+                    (contour.show_labels, segments.get(segments.len() / 2))
+                {
+                    let pos = transform.position_from_value(&segment[0]);
+                    shapes.push(Shape::text(
+                        ui.fonts(),
+                        pos,
+                        Align2::CENTER_BOTTOM,
+                        emath::round_to_decimals(level, 5).to_string(),
+                        TextStyle::Small,
+                        color,
+                    ));
+                }
+            }
+        }
+
+        for vector_field in &self.vector_fields {
+            vector_field.add_shapes(transform, &mut shapes);
         }
 
         for curve in &self.curves {
+            let transform = self.transform_for(curve.y_axis);
             let stroke = curve.stroke;
             let values = &curve.values;
+            if let Some(baseline) = curve.stems {
+                let x_range = transform.bounds().range_x();
+                let marker_radius = (1.5 * stroke.width).at_least(2.0);
+                for value in values.iter().filter(|value| x_range.contains(&value.x)) {
+                    let tip = transform.position_from_value(value);
+                    let base = transform.position_from_value(&Value::new(value.x, baseline));
+                    shapes.push(Shape::line_segment([base, tip], stroke));
+                    shapes.push(Shape::circle_filled(tip, marker_radius, stroke.color));
+                }
+                continue;
+            }
             let shape = if values.len() == 1 {
                 let point = transform.position_from_value(&values[0]);
                 Shape::circle_filled(point, stroke.width / 2.0, stroke.color)
             } else {
-                Shape::line(
-                    values
-                        .iter()
-                        .map(|v| transform.position_from_value(v))
-                        .collect(),
-                    stroke,
-                )
+                Shape::line(curve.screen_positions(transform), stroke)
             };
             shapes.push(shape);
         }
 
         if let Some(pointer) = response.hover_pos() {
-            self.hover(ui, pointer, &mut shapes);
+            match self.hover_mode {
+                HoverMode::ClosestPoint => self.hover(ui, pointer, &mut shapes),
+                HoverMode::EachCurve => self.hover_each_curve(ui, pointer, &mut shapes),
+            }
+        } else if let Some(x) = self.linked_cursor_x {
+            self.paint_linked_cursor(ui, x, &mut shapes);
         }
 
         ui.painter().sub_region(*transform.frame()).extend(shapes);
     }
 
-    fn paint_axis(&self, ui: &Ui, axis: usize, shapes: &mut Vec<Shape>) {
-        let Self { transform, .. } = self;
-
+    /// Paints the grid lines and labels of the given axis.
+    ///
+    /// The secondary y-axis only gets labels along the right edge, since its grid lines
+    /// would not line up with the ones of the primary axis.
+    fn paint_axis(
+        &self,
+        ui: &Ui,
+        transform: &ScreenTransform,
+        axis: usize,
+        secondary: bool,
+        shapes: &mut Vec<Shape>,
+    ) {
         let bounds = transform.bounds();
         let text_style = TextStyle::Body;
 
-        let base: i64 = 10;
+        let base = self.grid_base;
         let basef = base as f64;
 
-        let min_line_spacing_in_points = 6.0; // TODO: large enough for a wide label
-        let step_size = transform.dvalue_dpos()[axis] * min_line_spacing_in_points;
-        let step_size = basef.powi(step_size.abs().log(basef).ceil() as i32);
+        let min_line_spacing_in_points = self.grid_min_spacing as f64; // TODO: large enough for a wide label
 
-        let step_size_in_points = (transform.dpos_dvalue()[axis] * step_size).abs() as f32;
+        // Each tick is a value, the spacing of lines of its importance and an explicit label.
+        let mut ticks: Vec<(f64, f32, Option<&str>)> = Vec::new();
+        let explicit_ticks = if secondary {
+            &[][..]
+        } else {
+            &self.ticks[axis][..]
+        };
+        let categorical = axis == 0 && !self.x_categories.is_empty() && explicit_ticks.is_empty();
+
+        if explicit_ticks.is_empty() {
+            let mut step_size = if let (Some(spacing), false) = (self.grid_spacing[axis], secondary)
+            {
+                // Skip lines when zoomed out, so they don't get too dense.
+                let spacing_in_points = (transform.dpos_dvalue()[axis] * spacing).abs();
+                spacing
+                    * (min_line_spacing_in_points / spacing_in_points)
+                        .ceil()
+                        .at_least(1.0)
+            } else {
+                let step_size = transform.dvalue_dpos()[axis] * min_line_spacing_in_points;
+                basef.powi(step_size.abs().log(basef).ceil() as i32)
+            };
 
-        // Where on the cross-dimension to show the label values
-        let value_cross = 0.0_f64.clamp(bounds.min[1 - axis], bounds.max[1 - axis]);
+            // A categorical axis only has lines and labels at whole numbers.
+            if categorical {
+                step_size = step_size.at_least(1.0);
+            }
+
+            let step_size_in_points = (transform.dpos_dvalue()[axis] * step_size).abs() as f32;
+
+            for i in 0.. {
+                let value_main = step_size * (bounds.min[axis] / step_size + i as f64).floor();
+                if value_main > bounds.max[axis] {
+                    break;
+                }
 
-        for i in 0.. {
-            let value_main = step_size * (bounds.min[axis] / step_size + i as f64).floor();
-            if value_main > bounds.max[axis] {
-                break;
+                let n = (value_main / step_size).round() as i64;
+                let spacing_in_points = if n % (base * base) == 0 {
+                    step_size_in_points * (basef * basef) as f32 // think line (multiple of 100)
+                } else if n % base == 0 {
+                    step_size_in_points * basef as f32 // medium line (multiple of 10)
+                } else {
+                    step_size_in_points // thin line
+                };
+                ticks.push((value_main, spacing_in_points, None));
+            }
+        } else {
+            // Explicit ticks are all shown like the most important lines.
+            for (value, label) in explicit_ticks {
+                if bounds.min[axis] <= *value && *value <= bounds.max[axis] {
+                    ticks.push((*value, 300.0, Some(label.as_str())));
+                }
             }
+        }
 
+        // Where on the cross-dimension to show the label values
+        let value_cross = 0.0_f64.clamp(bounds.min[1 - axis], bounds.max[1 - axis]);
+
+        for (value_main, spacing_in_points, explicit_label) in ticks {
             let value = if axis == 0 {
                 Value::new(value_main, value_cross)
             } else {
@@ -563,45 +1895,52 @@ impl Prepared {
             };
             let pos_in_gui = transform.position_from_value(&value);
 
-            let n = (value_main / step_size).round() as i64;
-            let spacing_in_points = if n % (base * base) == 0 {
-                step_size_in_points * (basef * basef) as f32 // think line (multiple of 100)
-            } else if n % base == 0 {
-                step_size_in_points * basef as f32 // medium line (multiple of 10)
-            } else {
-                step_size_in_points // thin line
-            };
-
             let line_alpha = remap_clamp(
                 spacing_in_points,
                 (min_line_spacing_in_points as f32)..=300.0,
                 0.0..=0.15,
             );
 
-            if line_alpha > 0.0 {
-                let line_color = color_from_alpha(ui, line_alpha);
+            if line_alpha > 0.0 && self.show_grid[axis] && !secondary {
+                let stroke = self
+                    .grid_stroke
+                    .unwrap_or_else(|| Stroke::new(1.0, color_from_alpha(ui, line_alpha)));
 
                 let mut p0 = pos_in_gui;
                 let mut p1 = pos_in_gui;
                 p0[1 - axis] = transform.frame().min[1 - axis];
                 p1[1 - axis] = transform.frame().max[1 - axis];
-                shapes.push(Shape::line_segment([p0, p1], Stroke::new(1.0, line_color)));
+                shapes.push(Shape::line_segment([p0, p1], stroke));
             }
 
             let text_alpha = remap_clamp(spacing_in_points, 40.0..=150.0, 0.0..=0.4);
 
-            if text_alpha > 0.0 {
+            let text = if text_alpha <= 0.0 {
+                None
+            } else if let Some(label) = explicit_label {
+                Some(label.to_string())
+            } else if categorical {
+                self.x_category(value_main).map(ToString::to_string)
+            } else {
+                Some(self.format_value(axis, secondary, value_main, None))
+            };
+
+            if let Some(text) = text {
                 let color = color_from_alpha(ui, text_alpha);
-                let text = emath::round_to_decimals(value_main, 5).to_string(); // hack
 
                 let galley = ui.fonts().layout_single_line(text_style, text);
 
                 let mut text_pos = pos_in_gui + vec2(1.0, -galley.size.y);
 
-                // Make sure we see the labels, even if the axis is off-screen:
-                text_pos[1 - axis] = text_pos[1 - axis]
-                    .at_most(transform.frame().max[1 - axis] - galley.size[1 - axis] - 2.0)
-                    .at_least(transform.frame().min[1 - axis] + 1.0);
+                if secondary {
+                    // Labels of the secondary axis are always on the right edge:
+                    text_pos.x = transform.frame().right() - galley.size.x - 2.0;
+                } else {
+                    // Make sure we see the labels, even if the axis is off-screen:
+                    text_pos[1 - axis] = text_pos[1 - axis]
+                        .at_most(transform.frame().max[1 - axis] - galley.size[1 - axis] - 2.0)
+                        .at_least(transform.frame().min[1 - axis] + 1.0);
+                }
 
                 shapes.push(Shape::Text {
                     pos: text_pos,
@@ -621,12 +1960,166 @@ impl Prepared {
         }
     }
 
-    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
+    /// Format a value of the given axis with its unit.
+    /// The secondary y-axis has no unit, since it usually shows a different quantity.
+    fn format_value(
+        &self,
+        axis: usize,
+        secondary: bool,
+        value: f64,
+        decimals: Option<usize>,
+    ) -> String {
+        if secondary {
+            AxisUnit::default().format(value, decimals)
+        } else {
+            self.units[axis].format(value, decimals)
+        }
+    }
+
+    /// The points of all curves within the given screen-space polygon.
+    fn points_in_polygon(&self, polygon: &[Pos2]) -> Vec<SelectedPoints> {
+        self.curves
+            .iter()
+            .filter_map(|curve| {
+                let transform = self.transform_for(curve.y_axis);
+                let indices: Vec<usize> = curve
+                    .values
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, value)| {
+                        polygon_contains(polygon, transform.position_from_value(value))
+                    })
+                    .map(|(index, _)| index)
+                    .collect();
+                if indices.is_empty() {
+                    None
+                } else {
+                    Some(SelectedPoints {
+                        curve_name: curve.name.clone(),
+                        indices,
+                    })
+                }
+            })
+            .collect()
+    }
+
+    /// Highlight the selected points.
+    fn paint_selected_points(&self, ui: &Ui, selected_points: &[SelectedPoints]) {
+        let stroke = ui.visuals().selection.stroke;
+        let mut shapes = Vec::new();
+        for selected in selected_points {
+            for curve in self.curves.iter().filter(|c| c.name == selected.curve_name) {
+                let transform = self.transform_for(curve.y_axis);
+                for value in selected.indices.iter().filter_map(|&i| curve.values.get(i)) {
+                    let position = transform.position_from_value(value);
+                    shapes.push(Shape::circle_stroke(position, 4.0, stroke));
+                }
+            }
+        }
+        ui.painter()
+            .sub_region(*self.transform.frame())
+            .extend(shapes);
+    }
+
+    /// The category of the given x-value on a categorical x-axis.
+    fn x_category(&self, x: f64) -> Option<&str> {
+        let index = x.round();
+        if index >= 0.0 {
+            self.x_categories.get(index as usize).map(String::as_str)
+        } else {
+            None
+        }
+    }
+
+    /// The curve and index of the data point closest to the pointer, if it is close enough.
+    fn closest_point(&self, pointer: Pos2) -> Option<(&Curve, usize)> {
+        let interact_radius: f32 = 16.0;
+        let mut closest = None;
+        let mut closest_dist_sq = interact_radius.powi(2);
+        for curve in &self.curves {
+            let transform = self.transform_for(curve.y_axis);
+            for (index, value) in curve.values.iter().enumerate() {
+                let pos = transform.position_from_value(value);
+                let dist_sq = pointer.distance_sq(pos);
+                if dist_sq < closest_dist_sq {
+                    closest_dist_sq = dist_sq;
+                    closest = Some((curve, index));
+                }
+            }
+        }
+        closest
+    }
+
+    /// Show the cursor of a linked plot: a vertical line at `x`,
+    /// and the value of each curve closest to it.
+    fn paint_linked_cursor(&self, ui: &Ui, x: f64, shapes: &mut Vec<Shape>) {
+        let Self { transform, .. } = self;
+        if !transform.bounds().range_x().contains(&x) {
+            return;
+        }
+
+        let line_color = if ui.visuals().dark_mode {
+            Color32::from_gray(100).additive()
+        } else {
+            Color32::from_black_alpha(180)
+        };
+        let rect = transform.frame();
+        let screen_x = transform.position_from_value(&Value::new(x, 0.0)).x;
+        shapes.push(Shape::line_segment(
+            [pos2(screen_x, rect.top()), pos2(screen_x, rect.bottom())],
+            (1.0, line_color),
+        ));
+
+        let decimals = |transform: &ScreenTransform, axis: usize| {
+            ((-transform.dvalue_dpos()[axis].abs().log10())
+                .ceil()
+                .at_least(0.0) as usize)
+                .at_most(6)
+        };
+        let x_text = match self.x_category(x) {
+            Some(category) => category.to_string(),
+            None => self.format_value(0, false, x, Some(decimals(transform, 0))),
+        };
+        shapes.push(Shape::text(
+            ui.fonts(),
+            pos2(screen_x + 3.0, rect.top() + 2.0),
+            Align2::LEFT_TOP,
+            format!("x = {}", x_text),
+            TextStyle::Body,
+            ui.visuals().text_color(),
+        ));
+
+        for curve in &self.curves {
+            let transform = self.transform_for(curve.y_axis);
+            if let Some(value) = closest_value_at_x(&curve.values, x) {
+                let position = transform.position_from_value(value);
+                if !rect.contains(position) {
+                    continue;
+                }
+                shapes.push(Shape::circle_filled(position, 3.0, curve.stroke.color));
+                shapes.push(Shape::text(
+                    ui.fonts(),
+                    position + vec2(4.0, -2.0),
+                    Align2::LEFT_BOTTOM,
+                    self.format_value(
+                        1,
+                        curve.y_axis == YAxis::Right,
+                        value.y,
+                        Some(decimals(transform, 1)),
+                    ),
+                    TextStyle::Body,
+                    curve.stroke.color,
+                ));
+            }
+        }
+    }
+
+    /// Show the value of each curve closest to the hovered x-value, listed next to the pointer.
+    fn hover_each_curve(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
         let Self {
             transform,
             show_x,
             show_y,
-            curves,
             ..
         } = self;
 
@@ -634,29 +2127,102 @@ impl Prepared {
             return;
         }
 
-        let interact_radius: f32 = 16.0;
-        let mut closest_value = None;
-        let mut closest_curve = None;
-        let mut closest_dist_sq = interact_radius.powi(2);
-        for curve in curves {
-            for value in &curve.values {
-                let pos = transform.position_from_value(value);
-                let dist_sq = pointer.distance_sq(pos);
-                if dist_sq < closest_dist_sq {
-                    closest_dist_sq = dist_sq;
-                    closest_value = Some(value);
-                    closest_curve = Some(curve);
+        let line_color = if ui.visuals().dark_mode {
+            Color32::from_gray(100).additive()
+        } else {
+            Color32::from_black_alpha(180)
+        };
+        let rect = transform.frame();
+        let x = transform.value_from_position(pointer).x;
+
+        let decimals = |transform: &ScreenTransform, axis: usize| {
+            ((-transform.dvalue_dpos()[axis].abs().log10())
+                .ceil()
+                .at_least(0.0) as usize)
+                .at_most(6)
+        };
+
+        // The lines of the tooltip, with their colors.
+        let mut lines = Vec::new();
+        if *show_x {
+            shapes.push(Shape::line_segment(
+                [pos2(pointer.x, rect.top()), pos2(pointer.x, rect.bottom())],
+                (1.0, line_color),
+            ));
+            let x_text = match self.x_category(x) {
+                Some(category) => category.to_string(),
+                None => self.format_value(0, false, x, Some(decimals(transform, 0))),
+            };
+            lines.push((format!("x = {}", x_text), ui.visuals().text_color()));
+        }
+        for curve in &self.curves {
+            let transform = self.transform_for(curve.y_axis);
+            if let Some(value) = closest_value_at_x(&curve.values, x) {
+                let position = transform.position_from_value(value);
+                if !rect.contains(position) {
+                    continue;
+                }
+                shapes.push(Shape::circle_filled(position, 3.0, curve.stroke.color));
+                if *show_y {
+                    let y_text = self.format_value(
+                        1,
+                        curve.y_axis == YAxis::Right,
+                        value.y,
+                        Some(decimals(transform, 1)),
+                    );
+                    let text = if curve.name.is_empty() {
+                        y_text
+                    } else {
+                        format!("{}: {}", curve.name, y_text)
+                    };
+                    lines.push((text, curve.stroke.color));
                 }
             }
         }
 
+        // Listed upwards from the pointer, with the x-value on top.
+        let row_height = ui.fonts().row_height(TextStyle::Body);
+        for (i, (text, color)) in lines.into_iter().rev().enumerate() {
+            shapes.push(Shape::text(
+                ui.fonts(),
+                pointer + vec2(3.0, -2.0 - i as f32 * row_height),
+                Align2::LEFT_BOTTOM,
+                text,
+                TextStyle::Body,
+                color,
+            ));
+        }
+    }
+
+    fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) {
+        let Self {
+            transform,
+            show_x,
+            show_y,
+            ..
+        } = self;
+
+        if !show_x && !show_y {
+            return;
+        }
+
+        let closest = self.closest_point(pointer);
+        let closest_curve = closest.map(|(curve, _)| curve);
+        let closest_value = closest.map(|(curve, index)| &curve.values[index]);
+
         let mut prefix = String::new();
-        if let Some(curve) = closest_curve {
+        if let Some((curve, index)) = closest {
             if !curve.name.is_empty() {
                 prefix = format!("{}\n", curve.name);
             }
+            if let Some(label) = curve.point_label(index) {
+                prefix += &format!("{}\n", label);
+            }
         }
 
+        // Values of curves on the secondary axis are shown in their own units.
+        let transform = closest_curve.map_or(transform, |curve| self.transform_for(curve.y_axis));
+
         let line_color = if ui.visuals().dark_mode {
             Color32::from_gray(100).additive()
         } else {
@@ -693,15 +2259,18 @@ impl Prepared {
             let scale = transform.dvalue_dpos();
             let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
             let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).at_most(6);
+            let x_text = match self.x_category(value.x) {
+                Some(category) => category.to_string(),
+                None => self.format_value(0, false, value.x, Some(x_decimals)),
+            };
+            let secondary = closest_curve.map(|curve| curve.y_axis) == Some(YAxis::Right);
+            let y_text = self.format_value(1, secondary, value.y, Some(y_decimals));
             if *show_x && *show_y {
-                format!(
-                    "{}x = {:.*}\ny = {:.*}",
-                    prefix, x_decimals, value.x, y_decimals, value.y
-                )
+                format!("{}x = {}\ny = {}", prefix, x_text, y_text)
             } else if *show_x {
-                format!("{}x = {:.*}", prefix, x_decimals, value.x)
+                format!("{}x = {}", prefix, x_text)
             } else if *show_y {
-                format!("{}y = {:.*}", prefix, y_decimals, value.y)
+                format!("{}y = {}", prefix, y_text)
             } else {
                 unreachable!()
             }
@@ -717,3 +2286,38 @@ impl Prepared {
         ));
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lasso_polygon() {
+        // An L-shape, which is not convex.
+        let polygon = [
+            pos2(0.0, 0.0),
+            pos2(2.0, 0.0),
+            pos2(2.0, 1.0),
+            pos2(1.0, 1.0),
+            pos2(1.0, 2.0),
+            pos2(0.0, 2.0),
+        ];
+        assert!(polygon_contains(&polygon, pos2(0.5, 0.5)));
+        assert!(polygon_contains(&polygon, pos2(0.5, 1.5)));
+        assert!(!polygon_contains(&polygon, pos2(1.5, 1.5)));
+        assert!(!polygon_contains(&polygon, pos2(3.0, 0.5)));
+    }
+
+    #[test]
+    fn unit_formatting() {
+        let volts = AxisUnit {
+            unit: "V".to_owned(),
+            si_prefixes: true,
+        };
+        assert_eq!(volts.format(0.000012, None), "12 µV");
+        assert_eq!(volts.format(0.000012, Some(8)), "12.00 µV");
+        assert_eq!(volts.format(1500.0, None), "1.5 kV");
+        assert_eq!(volts.format(0.0, None), "0 V");
+        assert_eq!(AxisUnit::default().format(0.25, Some(3)), "0.250");
+    }
+}
@@ -0,0 +1,243 @@
+//! A pie or donut chart.
+
+use std::f32::consts::TAU;
+
+use crate::*;
+use epaint::Mesh;
+
+/// A slice of a [`PieChart`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Slice {
+    pub(crate) value: f64,
+    pub(crate) name: String,
+    pub(crate) color: Color32,
+}
+
+impl Slice {
+    /// A slice with the given value. Its size is relative to the sum of all values of the chart.
+    pub fn new(value: impl Into<f64>) -> Self {
+        Self {
+            value: value.into(),
+            name: Default::default(),
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Name of this slice, shown when hovering it.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// Fill color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+/// A pie chart, or a donut chart if given an inner radius.
+///
+/// Hovering a slice highlights it and shows its name, value and share of the total.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{PieChart, Slice};
+/// ui.add(
+///     PieChart::new()
+///         .slice(Slice::new(3.0).name("apples"))
+///         .slice(Slice::new(1.0).name("pears"))
+///         .inner_radius(0.5),
+/// );
+/// ```
+pub struct PieChart {
+    slices: Vec<Slice>,
+    diameter: Option<f32>,
+    inner_radius: f32,
+    start_angle: f32,
+    show_labels: bool,
+}
+
+impl Default for PieChart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PieChart {
+    pub fn new() -> Self {
+        Self {
+            slices: Default::default(),
+            diameter: None,
+            inner_radius: 0.0,
+            start_angle: -TAU / 4.0,
+            show_labels: false,
+        }
+    }
+
+    /// Add a slice. Slices are laid out clockwise in the order they are added.
+    /// Slices with a value that is not positive are ignored.
+    pub fn slice(mut self, mut slice: Slice) -> Self {
+        if slice.value > 0.0 && slice.value.is_finite() {
+            if slice.color == Color32::TRANSPARENT {
+                slice.color = super::auto_color(self.slices.len());
+            }
+            self.slices.push(slice);
+        }
+        self
+    }
+
+    /// Size of the chart. By default the chart fills the available width or height,
+    /// whichever is smaller.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Radius of the hole in the middle, as a fraction of the outer radius.
+    /// `0.0` gives a pie chart (the default), anything larger a donut chart.
+    pub fn inner_radius(mut self, fraction: f32) -> Self {
+        self.inner_radius = fraction.clamp(0.0, 0.95);
+        self
+    }
+
+    /// Angle of the start of the first slice, in radians, clockwise from the right.
+    /// Default: straight up.
+    pub fn start_angle(mut self, angle: f32) -> Self {
+        self.start_angle = angle;
+        self
+    }
+
+    /// Show the name of each slice and its share of the total (e.g. "apples\n75.0%")
+    /// on top of it, if there is room. Default: `false`.
+    pub fn show_labels(mut self, show: bool) -> Self {
+        self.show_labels = show;
+        self
+    }
+
+    /// The slice at the given position relative to the center, if any.
+    fn slice_at(&self, offset: Vec2, outer_radius: f32, total: f64) -> Option<usize> {
+        let radius = offset.length();
+        if radius > outer_radius || radius < self.inner_radius * outer_radius {
+            return None;
+        }
+        let angle = (offset.y.atan2(offset.x) - self.start_angle).rem_euclid(TAU);
+        let mut start = 0.0;
+        for (i, slice) in self.slices.iter().enumerate() {
+            let end = start + (slice.value / total) as f32 * TAU;
+            if angle < end {
+                return Some(i);
+            }
+            start = end;
+        }
+        None
+    }
+}
+
+impl Widget for PieChart {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let diameter = self
+            .diameter
+            .unwrap_or_else(|| ui.available_size_before_wrap_finite().min_elem())
+            .at_least(32.0);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+
+        let total: f64 = self.slices.iter().map(|slice| slice.value).sum();
+        if total <= 0.0 {
+            return response;
+        }
+
+        let center = rect.center();
+        let outer_radius = diameter / 2.0 - 2.0;
+        let hovered = response
+            .hover_pos()
+            .and_then(|pos| self.slice_at(pos - center, outer_radius, total));
+
+        let mut mesh = Mesh::default();
+        let mut start = self.start_angle;
+        for (i, slice) in self.slices.iter().enumerate() {
+            let sweep = (slice.value / total) as f32 * TAU;
+            // Pull the hovered slice out a bit.
+            let offset = if hovered == Some(i) {
+                Vec2::angled(start + sweep / 2.0) * 2.0
+            } else {
+                Vec2::ZERO
+            };
+            add_annular_sector(
+                &mut mesh,
+                center + offset,
+                self.inner_radius * outer_radius,
+                outer_radius,
+                start..=start + sweep,
+                slice.color,
+            );
+            start += sweep;
+        }
+        ui.painter().add(Shape::Mesh(mesh));
+
+        if self.show_labels {
+            let text_color = ui.visuals().strong_text_color();
+            let label_radius = (1.0 + self.inner_radius) / 2.0 * outer_radius;
+            let mut start = self.start_angle;
+            for slice in &self.slices {
+                let sweep = (slice.value / total) as f32 * TAU;
+                let percent = format!("{:.1}%", 100.0 * slice.value / total);
+                let text = if slice.name.is_empty() {
+                    percent
+                } else {
+                    format!("{}\n{}", slice.name, percent)
+                };
+                let galley = ui.fonts().layout_no_wrap(TextStyle::Small, text);
+                // Only show the label if it fits within the arc of the slice.
+                if galley.size.x < sweep * label_radius {
+                    let pos = center + Vec2::angled(start + sweep / 2.0) * label_radius;
+                    ui.painter()
+                        .galley(pos - galley.size / 2.0, galley, text_color);
+                }
+                start += sweep;
+            }
+        }
+
+        if let Some(i) = hovered {
+            let slice = &self.slices[i];
+            let percent = 100.0 * slice.value / total;
+            let text = if slice.name.is_empty() {
+                format!("{} ({:.1}%)", slice.value, percent)
+            } else {
+                format!("{}\n{} ({:.1}%)", slice.name, slice.value, percent)
+            };
+            response.on_hover_text(text)
+        } else {
+            response
+        }
+    }
+}
+
+/// Add a ring segment (or a pie wedge, if `inner_radius` is zero) to the mesh.
+fn add_annular_sector(
+    mesh: &mut Mesh,
+    center: Pos2,
+    inner_radius: f32,
+    outer_radius: f32,
+    angles: std::ops::RangeInclusive<f32>,
+    color: Color32,
+) {
+    let (start, end) = (*angles.start(), *angles.end());
+    // One segment every few degrees is enough for a smooth arc.
+    let segments = (((end - start) / TAU) * 128.0).ceil().at_least(1.0) as u32;
+    let idx = mesh.vertices.len() as u32;
+    for i in 0..=segments {
+        let angle = lerp(start..=end, i as f32 / segments as f32);
+        let dir = Vec2::angled(angle);
+        mesh.colored_vertex(center + dir * inner_radius, color);
+        mesh.colored_vertex(center + dir * outer_radius, color);
+    }
+    for i in 0..segments {
+        let a = idx + 2 * i;
+        mesh.add_triangle(a, a + 1, a + 2);
+        mesh.add_triangle(a + 1, a + 2, a + 3);
+    }
+}
@@ -0,0 +1,279 @@
+//! A plot in polar coordinates.
+
+use std::f64::consts::TAU;
+
+use super::items::{Curve, Value};
+use crate::*;
+
+/// A plot in polar coordinates, e.g. for antenna patterns or other directional data.
+///
+/// The curves are given as `(angle, radius)`, with the angle in radians as the x-value
+/// and the radius as the y-value of each [`Value`].
+/// An angle of zero points to the right, and angles increase counter-clockwise.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Curve, PolarPlot};
+/// use std::f64::consts::TAU;
+/// let cardioid = Curve::from_explicit_callback(|angle| 1.0 + angle.cos(), 0.0..=TAU, 256);
+/// ui.add(PolarPlot::new("Antenna").curve(cardioid));
+/// ```
+pub struct PolarPlot {
+    name: String,
+    curves: Vec<Curve>,
+    max_radius: Option<f64>,
+    diameter: Option<f32>,
+    angle_step_degrees: f64,
+}
+
+impl PolarPlot {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn new(name: impl ToString) -> Self {
+        Self {
+            name: name.to_string(),
+            curves: Default::default(),
+            max_radius: None,
+            diameter: None,
+            angle_step_degrees: 30.0,
+        }
+    }
+
+    /// Add a curve of `(angle, radius)` values. Angles are in radians.
+    pub fn curve(mut self, mut curve: Curve) -> Self {
+        if !curve.no_data() {
+            if curve.stroke.color == Color32::TRANSPARENT {
+                curve.stroke.color = super::auto_color(self.curves.len());
+            }
+            self.curves.push(curve);
+        }
+        self
+    }
+
+    /// The radius at the edge of the plot.
+    /// By default this is the largest radius of all curves.
+    pub fn max_radius(mut self, max_radius: f64) -> Self {
+        self.max_radius = Some(max_radius);
+        self
+    }
+
+    /// Size of the plot, including the angle labels.
+    /// By default the plot fills the available width or height, whichever is smaller.
+    pub fn diameter(mut self, diameter: f32) -> Self {
+        self.diameter = Some(diameter);
+        self
+    }
+
+    /// Degrees between the radial grid lines. Default: `30.0`.
+    pub fn angle_step(mut self, degrees: f64) -> Self {
+        self.angle_step_degrees = degrees;
+        self
+    }
+}
+
+impl Widget for PolarPlot {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            name,
+            mut curves,
+            max_radius,
+            diameter,
+            angle_step_degrees,
+        } = self;
+
+        let diameter = diameter
+            .unwrap_or_else(|| ui.available_size_before_wrap_finite().min_elem())
+            .at_least(64.0);
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(diameter), Sense::hover());
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Other, &name));
+
+        curves
+            .iter_mut()
+            .for_each(|curve| curve.generate_points(0.0..=TAU));
+
+        let max_radius = max_radius
+            .unwrap_or_else(|| {
+                curves
+                    .iter()
+                    .flat_map(|curve| curve.values.iter())
+                    .map(|value| value.y.abs())
+                    .fold(0.0, f64::max)
+            })
+            .max(f64::MIN_POSITIVE);
+
+        // Leave room for the angle labels around the edge.
+        let label_margin = ui.fonts().row_height(TextStyle::Small) * 1.5;
+        let center = rect.center();
+        let radius = (diameter / 2.0 - label_margin).at_least(1.0);
+        let transform = PolarTransform {
+            center,
+            radius,
+            max_radius,
+        };
+
+        let mut shapes = Vec::new();
+        shapes.push(Shape::Circle {
+            center,
+            radius,
+            fill: ui.visuals().extreme_bg_color,
+            stroke: ui.visuals().window_stroke(),
+        });
+
+        let grid_color = ui.visuals().widgets.noninteractive.bg_stroke.color;
+        let text_color = ui.visuals().text_color();
+
+        // Circles of constant radius.
+        let step = nice_step(max_radius / 4.0);
+        let mut ring = step;
+        while ring < max_radius * (1.0 - 1e-6) {
+            let screen_radius = transform.screen_radius(ring);
+            shapes.push(Shape::circle_stroke(
+                center,
+                screen_radius,
+                (1.0, grid_color),
+            ));
+            shapes.push(Shape::text(
+                ui.fonts(),
+                center + vec2(screen_radius + 2.0, 0.0),
+                Align2::LEFT_BOTTOM,
+                emath::round_to_decimals(ring, 5).to_string(),
+                TextStyle::Small,
+                text_color,
+            ));
+            ring += step;
+        }
+
+        // Lines of constant angle.
+        let angle_step_degrees = angle_step_degrees.at_least(1.0);
+        let mut degrees: f64 = 0.0;
+        while degrees < 360.0 - 1e-6 {
+            let dir = PolarTransform::direction(degrees.to_radians());
+            shapes.push(Shape::line_segment(
+                [center, center + dir * radius],
+                (1.0, grid_color),
+            ));
+            shapes.push(Shape::text(
+                ui.fonts(),
+                center + dir * (radius + label_margin / 2.0),
+                Align2::CENTER_CENTER,
+                format!("{}°", emath::round_to_decimals(degrees, 2)),
+                TextStyle::Small,
+                text_color,
+            ));
+            degrees += angle_step_degrees;
+        }
+
+        for curve in &curves {
+            let points: Vec<Pos2> = curve
+                .values
+                .iter()
+                .map(|value| transform.position_from_value(value))
+                .collect();
+            if points.len() == 1 {
+                shapes.push(Shape::circle_filled(
+                    points[0],
+                    curve.stroke.width / 2.0,
+                    curve.stroke.color,
+                ));
+            } else {
+                shapes.push(Shape::line(points, curve.stroke));
+            }
+        }
+
+        if let Some(pointer) = response.hover_pos() {
+            hover(ui, &curves, &transform, pointer, &mut shapes);
+        }
+
+        ui.painter().sub_region(rect).extend(shapes);
+
+        response
+    }
+}
+
+/// Show the closest point to the pointer, if any.
+fn hover(
+    ui: &Ui,
+    curves: &[Curve],
+    transform: &PolarTransform,
+    pointer: Pos2,
+    shapes: &mut Vec<Shape>,
+) {
+    let interact_radius: f32 = 16.0;
+    let mut closest = None;
+    let mut closest_dist_sq = interact_radius.powi(2);
+    for curve in curves {
+        for value in &curve.values {
+            let dist_sq = pointer.distance_sq(transform.position_from_value(value));
+            if dist_sq < closest_dist_sq {
+                closest_dist_sq = dist_sq;
+                closest = Some((curve, value));
+            }
+        }
+    }
+
+    if let Some((curve, value)) = closest {
+        let position = transform.position_from_value(value);
+        shapes.push(Shape::circle_filled(
+            position,
+            3.0,
+            ui.visuals().text_color(),
+        ));
+
+        let mut text = String::new();
+        if !curve.name.is_empty() {
+            text = format!("{}\n", curve.name);
+        }
+        text += &format!(
+            "θ = {:.1}°\nr = {}",
+            value.x.to_degrees().rem_euclid(360.0),
+            emath::round_to_decimals(value.y, 5)
+        );
+        shapes.push(Shape::text(
+            ui.fonts(),
+            position + vec2(3.0, -2.0),
+            Align2::LEFT_BOTTOM,
+            text,
+            TextStyle::Body,
+            ui.visuals().text_color(),
+        ));
+    }
+}
+
+/// A "nice" step size (1, 2 or 5 times a power of ten) close to `approximate_step`.
+fn nice_step(approximate_step: f64) -> f64 {
+    let power = 10.0_f64.powf(approximate_step.log10().floor());
+    let fraction = approximate_step / power;
+    let nice = if fraction < 1.5 {
+        1.0
+    } else if fraction < 3.5 {
+        2.0
+    } else if fraction < 7.5 {
+        5.0
+    } else {
+        10.0
+    };
+    nice * power
+}
+
+/// Maps `(angle, radius)` values to screen positions.
+struct PolarTransform {
+    center: Pos2,
+    /// The screen radius of the outer edge.
+    radius: f32,
+    /// The value radius of the outer edge.
+    max_radius: f64,
+}
+
+impl PolarTransform {
+    fn screen_radius(&self, value_radius: f64) -> f32 {
+        (value_radius / self.max_radius) as f32 * self.radius
+    }
+
+    /// Screen direction of an angle. Negated y, since the angle increases counter-clockwise.
+    fn direction(angle: f64) -> Vec2 {
+        vec2(angle.cos() as f32, -angle.sin() as f32)
+    }
+
+    fn position_from_value(&self, value: &Value) -> Pos2 {
+        self.center + Self::direction(value.x) * self.screen_radius(value.y)
+    }
+}
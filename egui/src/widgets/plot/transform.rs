@@ -7,17 +7,32 @@ use crate::*;
 /// The range of data values we show.
 #[derive(Clone, Copy, PartialEq, Debug)]
 #[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
-pub(crate) struct Bounds {
-    pub min: [f64; 2],
-    pub max: [f64; 2],
+pub struct Bounds {
+    pub(crate) min: [f64; 2],
+    pub(crate) max: [f64; 2],
 }
 
 impl Bounds {
-    pub const NOTHING: Self = Self {
+    pub(crate) const NOTHING: Self = Self {
         min: [f64::INFINITY; 2],
         max: [-f64::INFINITY; 2],
     };
 
+    /// Bounds from the lower left corner `min` to the upper right corner `max`, as `[x, y]`.
+    pub fn from_min_max(min: [f64; 2], max: [f64; 2]) -> Self {
+        Self { min, max }
+    }
+
+    /// The lower left corner, as `[x, y]`.
+    pub fn min(&self) -> [f64; 2] {
+        self.min
+    }
+
+    /// The upper right corner, as `[x, y]`.
+    pub fn max(&self) -> [f64; 2] {
+        self.max
+    }
+
     pub fn new_symmetrical(half_extent: f64) -> Self {
         Self {
             min: [-half_extent; 2],
@@ -44,56 +59,59 @@ impl Bounds {
         self.max[1] - self.min[1]
     }
 
-    pub fn extend_with(&mut self, value: &Value) {
+    pub(crate) fn extend_with(&mut self, value: &Value) {
         self.extend_with_x(value.x);
         self.extend_with_y(value.y);
     }
 
     /// Expand to include the given x coordinate
-    pub fn extend_with_x(&mut self, x: f64) {
+    pub(crate) fn extend_with_x(&mut self, x: f64) {
         self.min[0] = self.min[0].min(x);
         self.max[0] = self.max[0].max(x);
     }
 
     /// Expand to include the given y coordinate
-    pub fn extend_with_y(&mut self, y: f64) {
+    pub(crate) fn extend_with_y(&mut self, y: f64) {
         self.min[1] = self.min[1].min(y);
         self.max[1] = self.max[1].max(y);
     }
 
-    pub fn expand_x(&mut self, pad: f64) {
+    pub(crate) fn expand_x(&mut self, pad: f64) {
         self.min[0] -= pad;
         self.max[0] += pad;
     }
 
-    pub fn expand_y(&mut self, pad: f64) {
+    pub(crate) fn expand_y(&mut self, pad: f64) {
         self.min[1] -= pad;
         self.max[1] += pad;
     }
 
-    pub fn merge(&mut self, other: &Bounds) {
+    pub(crate) fn merge_x(&mut self, other: &Bounds) {
         self.min[0] = self.min[0].min(other.min[0]);
-        self.min[1] = self.min[1].min(other.min[1]);
         self.max[0] = self.max[0].max(other.max[0]);
+    }
+
+    pub(crate) fn merge_y(&mut self, other: &Bounds) {
+        self.min[1] = self.min[1].min(other.min[1]);
         self.max[1] = self.max[1].max(other.max[1]);
     }
 
-    pub fn translate_x(&mut self, delta: f64) {
+    pub(crate) fn translate_x(&mut self, delta: f64) {
         self.min[0] += delta;
         self.max[0] += delta;
     }
 
-    pub fn translate_y(&mut self, delta: f64) {
+    pub(crate) fn translate_y(&mut self, delta: f64) {
         self.min[1] += delta;
         self.max[1] += delta;
     }
 
-    pub fn translate(&mut self, delta: Vec2) {
+    pub(crate) fn translate(&mut self, delta: Vec2) {
         self.translate_x(delta.x as f64);
         self.translate_y(delta.y as f64);
     }
 
-    pub fn add_relative_margin(&mut self, margin_fraction: Vec2) {
+    pub(crate) fn add_relative_margin(&mut self, margin_fraction: Vec2) {
         let width = self.width().max(0.0);
         let height = self.height().max(0.0);
         self.expand_x(margin_fraction.x as f64 * width);
@@ -104,17 +122,53 @@ impl Bounds {
         self.min[0]..=self.max[0]
     }
 
-    pub fn make_x_symmetrical(&mut self) {
+    pub fn range_y(&self) -> RangeInclusive<f64> {
+        self.min[1]..=self.max[1]
+    }
+
+    pub(crate) fn make_x_symmetrical(&mut self) {
         let x_abs = self.min[0].abs().max(self.max[0].abs());
         self.min[0] = -x_abs;
         self.max[0] = x_abs;
     }
 
-    pub fn make_y_symmetrical(&mut self) {
+    pub(crate) fn make_y_symmetrical(&mut self) {
         let y_abs = self.min[1].abs().max(self.max[1].abs());
         self.min[1] = -y_abs;
         self.max[1] = y_abs;
     }
+
+    /// Keep the width within the given range, keeping the center in place.
+    pub(crate) fn clamp_width(&mut self, min_width: f64, max_width: f64) {
+        let width = self.width();
+        let clamped = width.max(min_width).min(max_width);
+        if clamped != width && clamped > 0.0 {
+            let center = (self.min[0] + self.max[0]) / 2.0;
+            self.min[0] = center - clamped / 2.0;
+            self.max[0] = center + clamped / 2.0;
+        }
+    }
+
+    /// Move, and if needed shrink, these bounds so that they lie within `limit`.
+    /// Infinite values in `limit` leave that side unlimited.
+    pub(crate) fn limit_to(&mut self, limit: &Bounds) {
+        for axis in 0..2 {
+            let (min, max) = (limit.min[axis], limit.max[axis]);
+            if min >= max || min.is_nan() || max.is_nan() {
+                continue;
+            }
+            if self.max[axis] - self.min[axis] >= max - min {
+                self.min[axis] = min;
+                self.max[axis] = max;
+            } else if self.min[axis] < min {
+                self.max[axis] += min - self.min[axis];
+                self.min[axis] = min;
+            } else if self.max[axis] > max {
+                self.min[axis] -= self.max[axis] - max;
+                self.max[axis] = max;
+            }
+        }
+    }
 }
 
 /// Contains the screen rectangle and the plot bounds and provides methods to transform them.
@@ -148,6 +202,30 @@ impl ScreenTransform {
         &self.bounds
     }
 
+    /// Use the x-range of `other` for these bounds, e.g. to keep a secondary axis in sync.
+    pub fn set_x_range_from(&mut self, other: &Bounds) {
+        self.bounds.min[0] = other.min[0];
+        self.bounds.max[0] = other.max[0];
+    }
+
+    /// Keep the width of the x-range within `x_range_width` and the bounds within `limit`.
+    pub fn limit_bounds(&mut self, x_range_width: RangeInclusive<f64>, limit: Option<&Bounds>) {
+        let mut new_bounds = self.bounds;
+        new_bounds.clamp_width(*x_range_width.start(), *x_range_width.end());
+        if let Some(limit) = limit {
+            new_bounds.limit_to(limit);
+        }
+        if new_bounds.is_valid() {
+            self.bounds = new_bounds;
+        }
+    }
+
+    /// Use the y-range of `other` for these bounds.
+    pub fn set_y_range_from(&mut self, other: &Bounds) {
+        self.bounds.min[1] = other.min[1];
+        self.bounds.max[1] = other.max[1];
+    }
+
     pub fn translate_bounds(&mut self, mut delta_pos: Vec2) {
         if self.x_centered {
             delta_pos.x = 0.;
@@ -160,6 +238,19 @@ impl ScreenTransform {
         self.bounds.translate(delta_pos);
     }
 
+    /// Zoom so that the given screen rectangle fills the whole frame.
+    pub fn zoom_to_rect(&mut self, rect: Rect) {
+        let min = self.value_from_position(rect.left_bottom());
+        let max = self.value_from_position(rect.right_top());
+        let new_bounds = Bounds {
+            min: [min.x, min.y],
+            max: [max.x, max.y],
+        };
+        if new_bounds.is_valid() {
+            self.bounds = new_bounds;
+        }
+    }
+
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
         let center = self.value_from_position(center);
@@ -0,0 +1,175 @@
+//! Arrows showing a vector field, also known as a quiver plot.
+
+use std::ops::RangeInclusive;
+
+use super::items::Value;
+use super::transform::{Bounds, ScreenTransform};
+use crate::*;
+use emath::Rot2;
+
+/// Arrows at given positions showing the direction and magnitude of a vector field,
+/// e.g. to visualize a flow.
+///
+/// The arrows are scaled so that the longest visible one is [`Self::max_arrow_length`] points
+/// long, so they stay readable at any zoom level.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// use egui::plot::{Plot, VectorField};
+/// let vortex = VectorField::from_callback(|x, y| (-y, x), -1.0..=1.0, -1.0..=1.0, [9, 9]);
+/// ui.add(Plot::new("Flow").vector_field(vortex).data_aspect(1.0));
+/// ```
+pub struct VectorField {
+    /// Pairs of position and vector.
+    arrows: Vec<(Value, Value)>,
+    pub(crate) color: Color32,
+    /// Colors for the smallest and largest magnitude.
+    magnitude_colors: Option<(Color32, Color32)>,
+    stroke_width: f32,
+    max_arrow_length: f32,
+    pub(crate) name: String,
+}
+
+impl VectorField {
+    /// Arrows from pairs of position and vector.
+    pub fn new(arrows: Vec<(Value, Value)>) -> Self {
+        Self {
+            arrows,
+            color: Color32::TRANSPARENT,
+            magnitude_colors: None,
+            stroke_width: 1.0,
+            max_arrow_length: 20.0,
+            name: Default::default(),
+        }
+    }
+
+    /// Sample the vector `function(x, y)` on a grid with the given number of `[columns, rows]`.
+    pub fn from_callback(
+        function: impl Fn(f64, f64) -> (f64, f64),
+        x_range: RangeInclusive<f64>,
+        y_range: RangeInclusive<f64>,
+        resolution: [usize; 2],
+    ) -> Self {
+        let [columns, rows] = [resolution[0].at_least(2), resolution[1].at_least(2)];
+        let mut arrows = Vec::with_capacity(columns * rows);
+        for row in 0..rows {
+            let y = lerp(y_range.clone(), row as f64 / (rows - 1) as f64);
+            for column in 0..columns {
+                let x = lerp(x_range.clone(), column as f64 / (columns - 1) as f64);
+                let (dx, dy) = function(x, y);
+                arrows.push((Value::new(x, y), Value::new(dx, dy)));
+            }
+        }
+        Self::new(arrows)
+    }
+
+    /// Color of the arrows. Default is `Color32::TRANSPARENT` which means a color will be
+    /// auto-assigned.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Color the arrows by their magnitude, from `low` for the smallest
+    /// to `high` for the largest one. This overrides [`Self::color`].
+    pub fn magnitude_colors(mut self, low: impl Into<Color32>, high: impl Into<Color32>) -> Self {
+        self.magnitude_colors = Some((low.into(), high.into()));
+        self
+    }
+
+    /// Width of the arrows. Default: `1.0`.
+    pub fn stroke_width(mut self, width: f32) -> Self {
+        self.stroke_width = width;
+        self
+    }
+
+    /// Length of the longest visible arrow, in points. Default: `20.0`.
+    pub fn max_arrow_length(mut self, length: f32) -> Self {
+        self.max_arrow_length = length;
+        self
+    }
+
+    /// Name of the vector field.
+    ///
+    /// If a vector field is given a name it will show up in the plot legend
+    /// (if legends are turned on), where it can be hidden like a curve.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn name(mut self, name: impl ToString) -> Self {
+        self.name = name.to_string();
+        self
+    }
+
+    /// The positions of all arrows.
+    pub(crate) fn bounds(&self) -> Bounds {
+        let mut bounds = Bounds::NOTHING;
+        for (position, _) in &self.arrows {
+            bounds.extend_with(position);
+        }
+        bounds
+    }
+
+    pub(crate) fn add_shapes(&self, transform: &ScreenTransform, shapes: &mut Vec<Shape>) {
+        let bounds = transform.bounds();
+        let [dpos_dx, dpos_dy] = transform.dpos_dvalue();
+
+        // The arrows in screen space, before scaling them to the wanted length.
+        let arrows: Vec<(Pos2, Vec2, f64)> = self
+            .arrows
+            .iter()
+            .filter(|(position, _)| {
+                bounds.range_x().contains(&position.x) && bounds.range_y().contains(&position.y)
+            })
+            .filter(|(_, vector)| vector.x.is_finite() && vector.y.is_finite())
+            .map(|(position, vector)| {
+                let screen_vector = vec2((vector.x * dpos_dx) as f32, (vector.y * dpos_dy) as f32);
+                let magnitude = vector.x.hypot(vector.y);
+                (
+                    transform.position_from_value(position),
+                    screen_vector,
+                    magnitude,
+                )
+            })
+            .collect();
+
+        let longest = arrows
+            .iter()
+            .map(|(_, vector, _)| vector.length())
+            .fold(0.0, f32::max);
+        if longest <= 0.0 {
+            return;
+        }
+        let scale = self.max_arrow_length / longest;
+        let (min_magnitude, max_magnitude) = arrows.iter().fold(
+            (f64::INFINITY, f64::NEG_INFINITY),
+            |(min, max), &(_, _, magnitude)| (min.min(magnitude), max.max(magnitude)),
+        );
+
+        for (start, vector, magnitude) in arrows {
+            let color = match self.magnitude_colors {
+                Some((low, high)) => {
+                    let t = if max_magnitude > min_magnitude {
+                        ((magnitude - min_magnitude) / (max_magnitude - min_magnitude)) as f32
+                    } else {
+                        1.0
+                    };
+                    (Rgba::from(low) * (1.0 - t) + Rgba::from(high) * t).into()
+                }
+                None => self.color,
+            };
+            let stroke = Stroke::new(self.stroke_width, color);
+
+            let vector = vector * scale;
+            let tip = start + vector;
+            shapes.push(Shape::line_segment([start, tip], stroke));
+
+            // Arrow head:
+            let head = -vector * 0.25;
+            let rot = Rot2::from_angle(std::f32::consts::TAU / 12.0);
+            shapes.push(Shape::line_segment([tip, tip + rot * head], stroke));
+            shapes.push(Shape::line_segment(
+                [tip, tip + rot.inverse() * head],
+                stroke,
+            ));
+        }
+    }
+}
@@ -0,0 +1,284 @@
+use std::sync::Arc;
+
+use crate::*;
+use epaint::Galley;
+
+/// A paragraph of text where each part can have its own style.
+///
+/// Each part is a [`Label`], so it can have its own color, text style, underline, background etc.
+/// The parts wrap together as one paragraph.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.add(
+///     egui::RichText::new()
+///         .append("This is ")
+///         .append(egui::Label::new("important").strong().underline())
+///         .append(", this is ")
+///         .append(egui::Label::new("red").text_color(egui::Color32::RED))
+///         .append(", and this is ")
+///         .append(egui::Label::new("big").heading())
+///         .append("."),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct RichText {
+    spans: Vec<Label>,
+    wrap: Option<bool>,
+    sense: Sense,
+}
+
+impl Default for RichText {
+    fn default() -> Self {
+        Self {
+            spans: vec![],
+            wrap: None,
+            sense: Sense::focusable_noninteractive(),
+        }
+    }
+}
+
+impl RichText {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add some text to the end. Only the style of the [`Label`] is used,
+    /// not e.g. its [`Label::wrap`] or [`Label::sense`].
+    pub fn append(mut self, span: impl Into<Label>) -> Self {
+        self.spans.push(span.into());
+        self
+    }
+
+    /// If `true`, the text will wrap at the `max_width`.
+    /// By default this is the same as for [`Label::wrap`].
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = Some(wrap);
+        self
+    }
+
+    /// Make the text respond to clicks and/or drags, like [`Label::sense`].
+    pub fn sense(mut self, sense: Sense) -> Self {
+        self.sense = sense;
+        self
+    }
+}
+
+/// A row of words, each one `(span index, x offset, galley)`.
+#[derive(Default)]
+struct Row {
+    words: Vec<(usize, f32, Arc<Galley>)>,
+    width: f32,
+    height: f32,
+    /// Started by wrapping, rather than by a newline.
+    wrapped: bool,
+}
+
+impl Row {
+    fn push(&mut self, span_index: usize, galley: Arc<Galley>) {
+        self.height = self.height.max(galley.size.y);
+        let x = self.width;
+        self.width += galley.size.x;
+        self.words.push((span_index, x, galley));
+    }
+}
+
+/// Start a new row, because the next word doesn't fit on the last one.
+fn wrap_row(rows: &mut Vec<Row>) -> &mut Row {
+    rows.push(Row {
+        wrapped: true,
+        ..Default::default()
+    });
+    rows.last_mut().unwrap()
+}
+
+impl Widget for RichText {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self { spans, wrap, sense } = self;
+
+        let wrap_width = if super::label::should_wrap(ui, wrap) {
+            ui.available_width()
+        } else {
+            f32::INFINITY
+        };
+
+        // Lay out each word on its own, and put as many as fit on each row:
+        let text_styles: Vec<TextStyle> = spans
+            .iter()
+            .map(|span| span.text_style_or_default(ui.style()))
+            .collect();
+        let layout = |span_index: usize, text: String| {
+            ui.fonts().layout_no_wrap(text_styles[span_index], text)
+        };
+        let mut rows = vec![Row::default()];
+        for token in tokens(&spans) {
+            let mut row = rows.last_mut().unwrap();
+            match token {
+                Token::Newline(span_index) => {
+                    row.height = row
+                        .height
+                        .max(ui.fonts().row_height(text_styles[span_index]));
+                    rows.push(Row::default());
+                }
+                Token::Space(span_index, space) => {
+                    let galley = layout(span_index, space.to_owned());
+                    if row.width + galley.size.x > wrap_width || row.wrapped && row.words.is_empty()
+                    {
+                        continue; // no space at the end or start of a wrapped row
+                    }
+                    row.push(span_index, galley);
+                }
+                Token::Word(parts) => {
+                    let galleys: Vec<(usize, Arc<Galley>)> = parts
+                        .iter()
+                        .map(|&(span_index, part)| {
+                            (span_index, layout(span_index, part.to_owned()))
+                        })
+                        .collect();
+                    let width: f32 = galleys.iter().map(|(_, galley)| galley.size.x).sum();
+                    if width > wrap_width {
+                        // Too wide for a row of its own, so break it between characters:
+                        for &(span_index, part) in &parts {
+                            for c in part.chars() {
+                                let galley = layout(span_index, c.to_string());
+                                if row.width > 0.0 && row.width + galley.size.x > wrap_width {
+                                    row = wrap_row(&mut rows);
+                                }
+                                row.push(span_index, galley);
+                            }
+                        }
+                        continue;
+                    }
+                    if row.width > 0.0 && row.width + width > wrap_width {
+                        row = wrap_row(&mut rows);
+                    }
+                    for (span_index, galley) in galleys {
+                        row.push(span_index, galley);
+                    }
+                }
+            }
+        }
+
+        let size = vec2(
+            rows.iter().map(|row| row.width).fold(0.0, f32::max),
+            rows.iter().map(|row| row.height).sum(),
+        );
+        let (rect, response) = ui.allocate_exact_size(size, sense);
+        response.widget_info(|| {
+            let text: String = spans.iter().map(|span| span.text()).collect();
+            WidgetInfo::labeled(WidgetType::Label, &text)
+        });
+
+        let response_color = ui.style().interact(&response).text_color();
+        let mut y = rect.top();
+        for row in rows {
+            for (span_index, x, galley) in row.words {
+                // Put the bottom of all words in a row on the same line:
+                let pos = pos2(rect.left() + x, y + row.height - galley.size.y);
+                spans[span_index].paint_galley_impl(
+                    ui,
+                    pos,
+                    galley,
+                    response.has_focus(),
+                    response_color,
+                );
+            }
+            y += row.height;
+        }
+
+        response
+    }
+}
+
+/// A piece of the text, see [`tokens`].
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Newline(usize),
+    /// A run of spaces within one span: `(span index, spaces)`.
+    Space(usize, &'a str),
+    /// A word, which may continue over several spans: `(span index, part of the word)`.
+    Word(Vec<(usize, &'a str)>),
+}
+
+/// Split the text of all spans into words, runs of spaces and newlines.
+/// Words are not split where one span ends and the next one starts, so e.g. a comma in its
+/// own span stays on the same row as the word before it.
+fn tokens(spans: &[Label]) -> Vec<Token<'_>> {
+    let mut tokens = vec![];
+    for (span_index, span) in spans.iter().enumerate() {
+        for word in words(&span.text) {
+            if word == "\n" {
+                tokens.push(Token::Newline(span_index));
+            } else if word.chars().all(char::is_whitespace) {
+                tokens.push(Token::Space(span_index, word));
+            } else if let Some(Token::Word(parts)) = tokens.last_mut() {
+                parts.push((span_index, word));
+            } else {
+                tokens.push(Token::Word(vec![(span_index, word)]));
+            }
+        }
+    }
+    tokens
+}
+
+/// Split text into words, runs of spaces and newlines.
+fn words(text: &str) -> Vec<&str> {
+    let kind = |c: char| {
+        if c == '\n' {
+            0
+        } else if c.is_whitespace() {
+            1
+        } else {
+            2
+        }
+    };
+
+    let mut words = vec![];
+    let mut start = 0;
+    let mut last_kind = None;
+    for (index, c) in text.char_indices() {
+        let kind = kind(c);
+        if index > start && (last_kind != Some(kind) || kind == 0) {
+            words.push(&text[start..index]);
+            start = index;
+        }
+        last_kind = Some(kind);
+    }
+    if start < text.len() {
+        words.push(&text[start..]);
+    }
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_continue_over_spans() {
+        let spans = vec![Label::new("one two"), Label::new(", three")];
+        assert_eq!(
+            tokens(&spans),
+            vec![
+                Token::Word(vec![(0, "one")]),
+                Token::Space(0, " "),
+                Token::Word(vec![(0, "two"), (1, ",")]),
+                Token::Space(1, " "),
+                Token::Word(vec![(1, "three")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn break_too_long_word() {
+        let mut ui = Ui::__test();
+        let mut ui = ui.child_ui(
+            Rect::from_min_size(Pos2::ZERO, vec2(50.0, 1000.0)),
+            Layout::default(),
+        );
+        let row_height = ui.fonts().row_height(TextStyle::Body);
+        let response = ui.add(RichText::new().append("a").append("b".repeat(100)));
+        assert!(response.rect.width() <= 50.0);
+        assert!(response.rect.height() > row_height);
+    }
+}
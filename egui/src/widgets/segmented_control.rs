@@ -0,0 +1,195 @@
+use crate::*;
+
+/// A row of joined buttons where one of them is selected, like a group of radio buttons.
+///
+/// Works with any value you can compare, like [`Ui::radio_value`].
+/// The control is one focus stop; press the left and right arrows to change the selection.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// #[derive(PartialEq)]
+/// enum View { List, Grid, Columns }
+/// let mut view = View::List;
+///
+/// ui.add(
+///     egui::SegmentedControl::new(&mut view)
+///         .option(View::List, "List")
+///         .option(View::Grid, "Grid")
+///         .option(View::Columns, "Columns"),
+/// );
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct SegmentedControl<'a, Value> {
+    current_value: &'a mut Value,
+    options: Vec<(Value, String)>,
+}
+
+impl<'a, Value: PartialEq> SegmentedControl<'a, Value> {
+    pub fn new(current_value: &'a mut Value) -> Self {
+        Self {
+            current_value,
+            options: vec![],
+        }
+    }
+
+    /// Add a segment which selects `value`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn option(mut self, value: Value, text: impl ToString) -> Self {
+        self.options.push((value, text.to_string()));
+        self
+    }
+}
+
+impl<'a, Value: PartialEq> Widget for SegmentedControl<'a, Value> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            current_value,
+            options,
+        } = self;
+
+        let button_padding = ui.spacing().button_padding;
+        let galleys: Vec<_> = options
+            .iter()
+            .map(|(_, text)| ui.fonts().layout_no_wrap(TextStyle::Button, text.clone()))
+            .collect();
+        let height = galleys
+            .iter()
+            .map(|galley| galley.size.y + 2.0 * button_padding.y)
+            .fold(ui.spacing().interact_size.y, f32::max);
+        let width: f32 = galleys
+            .iter()
+            .map(|galley| galley.size.x + 2.0 * button_padding.x)
+            .sum();
+
+        let (rect, mut response) = ui.allocate_exact_size(vec2(width, height), Sense::click());
+
+        let mut segment_rects = Vec::with_capacity(galleys.len());
+        let mut left = rect.left();
+        for galley in &galleys {
+            let right = left + galley.size.x + 2.0 * button_padding.x;
+            segment_rects.push(Rect::from_x_y_ranges(left..=right, rect.y_range()));
+            left = right;
+        }
+        let segment_at = |pos: Option<Pos2>| -> Option<usize> {
+            let pos = pos?;
+            segment_rects.iter().position(|rect| rect.contains(pos))
+        };
+
+        let selected = options
+            .iter()
+            .position(|(value, _)| *value == *current_value);
+        let mut new_selection = None;
+        if response.clicked() {
+            new_selection = segment_at(response.interact_pointer_pos());
+        }
+        if response.has_focus() && !options.is_empty() {
+            let input = ui.input();
+            let last = options.len() - 1;
+            if input.key_pressed(Key::ArrowLeft) {
+                new_selection = Some(selected.map_or(0, |index| index.saturating_sub(1)));
+            } else if input.key_pressed(Key::ArrowRight) {
+                new_selection = Some(selected.map_or(0, |index| (index + 1).min(last)));
+            }
+        }
+        response.widget_info(|| {
+            let text = selected.map_or("", |index| galleys[index].text.as_str());
+            WidgetInfo::labeled(WidgetType::RadioButton, text)
+        });
+
+        if ui.clip_rect().intersects(rect) {
+            let widgets = &ui.visuals().widgets;
+            let frame_visuals = if response.has_focus() {
+                &widgets.active
+            } else {
+                &widgets.inactive
+            };
+            let corner_radius = frame_visuals.corner_radius;
+            ui.painter().rect(
+                rect,
+                corner_radius,
+                frame_visuals.bg_fill,
+                frame_visuals.bg_stroke,
+            );
+
+            let hovered = segment_at(response.hover_pos());
+            let shown_selection = new_selection.or(selected);
+            let last = galleys.len().saturating_sub(1);
+            for (index, (galley, segment_rect)) in
+                galleys.into_iter().zip(&segment_rects).enumerate()
+            {
+                let is_selected = shown_selection == Some(index);
+                let is_hovered = hovered == Some(index);
+                let mut visuals = if is_hovered && response.is_pointer_button_down_on() {
+                    widgets.active
+                } else if is_hovered {
+                    widgets.hovered
+                } else {
+                    widgets.inactive
+                };
+                if is_selected {
+                    visuals.bg_fill = ui.visuals().selection.bg_fill;
+                    visuals.fg_stroke = ui.visuals().selection.stroke;
+                }
+
+                if is_selected || is_hovered {
+                    let fill_rect = segment_rect.shrink(frame_visuals.bg_stroke.width);
+                    paint_segment_fill(
+                        ui.painter(),
+                        fill_rect,
+                        corner_radius,
+                        index,
+                        last,
+                        visuals.bg_fill,
+                    );
+                }
+                if index > 0 {
+                    ui.painter().line_segment(
+                        [segment_rect.left_top(), segment_rect.left_bottom()],
+                        frame_visuals.bg_stroke,
+                    );
+                }
+
+                let text_pos = Align2::CENTER_CENTER
+                    .align_size_within_rect(galley.size, *segment_rect)
+                    .min;
+                let text_color = ui
+                    .visuals()
+                    .override_text_color
+                    .unwrap_or_else(|| visuals.text_color());
+                ui.painter().galley(text_pos, galley, text_color);
+            }
+        }
+
+        if let Some(new_selection) = new_selection {
+            if selected != Some(new_selection) {
+                if let Some((value, _)) = options.into_iter().nth(new_selection) {
+                    *current_value = value;
+                    response.mark_changed();
+                }
+            }
+        }
+        response
+    }
+}
+
+/// Fill a segment, with rounded corners only on the outer ends of the control.
+fn paint_segment_fill(
+    painter: &Painter,
+    rect: Rect,
+    corner_radius: f32,
+    index: usize,
+    last: usize,
+    fill: Color32,
+) {
+    painter.rect_filled(rect, corner_radius, fill);
+    // Square off the corners facing the neighbouring segments:
+    let half_width = 0.5 * rect.width();
+    if index > 0 {
+        let left_half = Rect::from_min_size(rect.min, vec2(half_width, rect.height()));
+        painter.rect_filled(left_half, 0.0, fill);
+    }
+    if index < last {
+        let right_half = Rect::from_min_max(pos2(rect.center().x, rect.top()), rect.max);
+        painter.rect_filled(right_half, 0.0, fill);
+    }
+}
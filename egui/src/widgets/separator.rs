@@ -19,13 +19,16 @@ pub struct Separator {
 impl Default for Separator {
     fn default() -> Self {
         Self {
-            spacing: 6.0,
+            spacing: Self::DEFAULT_SPACING,
             is_horizontal_line: None,
         }
     }
 }
 
 impl Separator {
+    /// The space taken up by a separator, unless changed with [`Self::spacing`].
+    pub(crate) const DEFAULT_SPACING: f32 = 6.0;
+
     #[deprecated = "Use Separator::default() instead"]
     pub fn new() -> Self {
         Self::default()
@@ -9,6 +9,9 @@ use std::ops::RangeInclusive;
 /// for the borrow checker.
 type GetSetValue<'a> = Box<dyn 'a + FnMut(Option<f64>) -> f64>;
 
+/// One direction of a custom [`Slider`] mapping, see [`Slider::custom_mapping`].
+type Mapping<'a> = Box<dyn 'a + Fn(f64) -> f64>;
+
 fn get(get_set_value: &mut GetSetValue<'_>) -> f64 {
     (get_set_value)(None)
 }
@@ -19,9 +22,66 @@ fn set(get_set_value: &mut GetSetValue<'_>, value: f64) {
 
 // ----------------------------------------------------------------------------
 
+/// The shape of a [`Slider`]: how far along the slider a value is.
+///
+/// Each curve maps the slider position to a fraction of the slider range,
+/// both going from zero to one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SliderCurve {
+    /// Evenly spread values.
+    Linear,
+    /// The fraction of the range is the position raised to this power.
+    /// Above one gives more precision at the start of the range, e.g. `2.0` or `3.0`.
+    Power(f64),
+    /// Values grow exponentially with the position; the number decides how steeply.
+    /// Unlike [`Slider::logarithmic`], this works for ranges that start at zero.
+    Exponential(f64),
+    /// For gain and volume controls: the position is linear in decibels,
+    /// with the end of the slider range at 0 dB and the start at this many decibels below it
+    /// (e.g. `60.0`). The very start of the slider is silence.
+    Decibel(f64),
+}
+
+impl SliderCurve {
+    /// From slider position to fraction of the range.
+    fn fraction_from_position(self, t: f64) -> f64 {
+        match self {
+            SliderCurve::Power(power) => t.powf(power),
+            SliderCurve::Exponential(k) if k != 0.0 => (k * t).exp_m1() / k.exp_m1(),
+            SliderCurve::Linear | SliderCurve::Exponential(_) => t,
+            SliderCurve::Decibel(range_db) => {
+                if t <= 0.0 {
+                    0.0
+                } else {
+                    10.0_f64.powf((t - 1.0) * range_db / 20.0)
+                }
+            }
+        }
+    }
+
+    /// From fraction of the range to slider position.
+    fn position_from_fraction(self, fraction: f64) -> f64 {
+        let t = match self {
+            SliderCurve::Power(power) => fraction.powf(1.0 / power),
+            SliderCurve::Exponential(k) if k != 0.0 => (fraction * k.exp_m1()).ln_1p() / k,
+            SliderCurve::Linear | SliderCurve::Exponential(_) => fraction,
+            SliderCurve::Decibel(range_db) => {
+                if fraction <= 0.0 {
+                    0.0
+                } else {
+                    1.0 + 20.0 * fraction.log10() / range_db
+                }
+            }
+        };
+        t.clamp(0.0, 1.0)
+    }
+}
+
 #[derive(Clone)]
 struct SliderSpec {
     logarithmic: bool,
+    /// Ignored for logarithmic sliders.
+    curve: SliderCurve,
     /// For logarithmic sliders, the smallest positive value we are interested in.
     /// 1 for integer sliders, maybe 1e-6 for others.
     smallest_positive: f64,
@@ -64,6 +124,11 @@ pub struct Slider<'a> {
     text_color: Option<Color32>,
     min_decimals: usize,
     max_decimals: Option<usize>,
+    /// From position (zero to one) to value and back.
+    custom_mapping: Option<(Mapping<'a>, Mapping<'a>)>,
+    step: Option<f64>,
+    ticks: Option<f64>,
+    tick_labels: bool,
 }
 
 macro_rules! impl_integer_constructor {
@@ -140,6 +205,7 @@ impl<'a> Slider<'a> {
             range,
             spec: SliderSpec {
                 logarithmic: false,
+                curve: SliderCurve::Linear,
                 smallest_positive: 1e-6,
                 largest_finite: f64::INFINITY,
             },
@@ -152,6 +218,10 @@ impl<'a> Slider<'a> {
             text_color: None,
             min_decimals: 0,
             max_decimals: None,
+            custom_mapping: None,
+            step: None,
+            ticks: None,
+            tick_labels: false,
         }
     }
 
@@ -210,6 +280,50 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Change how values are spread along the slider, e.g. for volume or frequency controls.
+    /// Ignored by logarithmic sliders.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut gain: f32 = 1.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut gain, 0.0..=1.0)
+    ///         .curve(egui::SliderCurve::Decibel(60.0))
+    ///         .text("Volume"),
+    /// );
+    /// ```
+    pub fn curve(mut self, curve: SliderCurve) -> Self {
+        self.spec.curve = curve;
+        self
+    }
+
+    /// Use your own mapping between the position along the slider
+    /// (from zero at the start to one at the end) and the value.
+    ///
+    /// `value_from_position` and `position_from_value` should be each other's inverse,
+    /// and map zero and one to the ends of the slider range.
+    /// This takes precedence over [`Self::logarithmic`] and [`Self::curve`].
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut frequency: f64 = 440.0;
+    /// // Musical pitch: every twelfth of the slider is a semitone.
+    /// ui.add(
+    ///     egui::Slider::new(&mut frequency, 220.0..=880.0).custom_mapping(
+    ///         |position| 220.0 * 2.0_f64.powf(2.0 * position),
+    ///         |value| (value / 220.0).log2() / 2.0,
+    ///     ),
+    /// );
+    /// ```
+    pub fn custom_mapping(
+        mut self,
+        value_from_position: impl 'a + Fn(f64) -> f64,
+        position_from_value: impl 'a + Fn(f64) -> f64,
+    ) -> Self {
+        self.custom_mapping = Some((Box::new(value_from_position), Box::new(position_from_value)));
+        self
+    }
+
     /// If set to `true`, all incoming and outgoing values will be clamped to the slider range.
     /// Default: `false`.
     pub fn clamp_to_range(mut self, clamp_to_range: bool) -> Self {
@@ -217,8 +331,40 @@ impl<'a> Slider<'a> {
         self
     }
 
+    /// Only allow values that are a multiple of `step` away from the start of the range.
+    /// The value snaps to these stops while dragging, and the arrow keys move one step at a time.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut quality: f32 = 50.0;
+    /// ui.add(
+    ///     egui::Slider::new(&mut quality, 0.0..=100.0)
+    ///         .step_by(25.0)
+    ///         .ticks(25.0)
+    ///         .tick_labels(true),
+    /// );
+    /// ```
+    pub fn step_by(mut self, step: f64) -> Self {
+        self.step = if step > 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// Paint a tick mark along the slider every `every` units, starting at the start of the range.
+    /// Often the same as [`Self::step_by`].
+    pub fn ticks(mut self, every: f64) -> Self {
+        self.ticks = if every > 0.0 { Some(every) } else { None };
+        self
+    }
+
+    /// Show the value of each tick (see [`Self::ticks`]) under the slider. Default: `false`.
+    pub fn tick_labels(mut self, tick_labels: bool) -> Self {
+        self.tick_labels = tick_labels;
+        self
+    }
+
     /// Turn smart aim on/off. Default is ON.
     /// There is almost no point in turning this off.
+    /// Smart aim is not used for sliders with a [`Self::step_by`].
     pub fn smart_aim(mut self, smart_aim: bool) -> Self {
         self.smart_aim = smart_aim;
         self
@@ -277,6 +423,10 @@ impl<'a> Slider<'a> {
     }
 
     fn set_value(&mut self, mut value: f64) {
+        if let Some(step) = self.step {
+            let start = self.range.start().min(*self.range.end());
+            value = start + ((value - start) / step).round() * step;
+        }
         if self.clamp_to_range {
             let start = *self.range.start();
             let end = *self.range.end();
@@ -300,14 +450,24 @@ impl<'a> Slider<'a> {
         self.range.clone()
     }
 
+    fn use_smart_aim(&self) -> bool {
+        self.smart_aim && self.step.is_none()
+    }
+
     /// For instance, `x` is the mouse position and `x_range` is the physical location of the slider on the screen.
     fn value_from_x(&self, x: f32, x_range: RangeInclusive<f32>) -> f64 {
         let normalized = remap_clamp(x, x_range, 0.0..=1.0) as f64;
-        value_from_normalized(normalized, self.range(), &self.spec)
+        match &self.custom_mapping {
+            Some((value_from_position, _)) => value_from_position(normalized),
+            None => value_from_normalized(normalized, self.range(), &self.spec),
+        }
     }
 
     fn x_from_value(&self, value: f64, x_range: RangeInclusive<f32>) -> f32 {
-        let normalized = normalized_from_value(value, self.range(), &self.spec);
+        let normalized = match &self.custom_mapping {
+            Some((_, position_from_value)) => position_from_value(value).clamp(0.0, 1.0),
+            None => normalized_from_value(value, self.range(), &self.spec),
+        };
         lerp(x_range, normalized as f32)
     }
 }
@@ -323,19 +483,36 @@ fn x_range(rect: &Rect) -> RangeInclusive<f32> {
 
 impl<'a> Slider<'a> {
     /// Just the slider, no text
-    #[allow(clippy::unused_self)]
     fn allocate_slider_space(&self, ui: &mut Ui, height: f32) -> Response {
-        let desired_size = vec2(ui.spacing().slider_width, height);
+        let desired_size = vec2(
+            ui.spacing().slider_width,
+            height + self.tick_labels_height(ui),
+        );
         ui.allocate_response(desired_size, Sense::click_and_drag())
     }
 
+    fn tick_labels_height(&self, ui: &Ui) -> f32 {
+        if self.tick_labels && self.ticks.is_some() {
+            ui.fonts().row_height(TextStyle::Small)
+        } else {
+            0.0
+        }
+    }
+
+    /// The part of the slider with the rail and handle, i.e. above any tick labels.
+    fn track_rect(&self, ui: &Ui, slider_rect: Rect) -> Rect {
+        let mut rect = slider_rect;
+        rect.max.y -= self.tick_labels_height(ui);
+        rect
+    }
+
     /// Just the slider, no text
     fn slider_ui(&mut self, ui: &mut Ui, response: &Response) {
-        let rect = &response.rect;
+        let rect = &self.track_rect(ui, response.rect);
         let x_range = x_range(rect);
 
         if let Some(pointer_pos) = response.interact_pointer_pos() {
-            let new_value = if self.smart_aim {
+            let new_value = if self.use_smart_aim() {
                 let aim_radius = ui.input().aim_radius();
                 emath::smart_aim::best_in_range_f64(
                     self.value_from_x(pointer_pos.x - aim_radius, x_range.clone()),
@@ -354,11 +531,20 @@ impl<'a> Slider<'a> {
             let kb_step = ui.input().num_presses(Key::ArrowRight) as f32
                 - ui.input().num_presses(Key::ArrowLeft) as f32;
 
-            if kb_step != 0.0 {
+            if let (Some(step), true) = (self.step, kb_step != 0.0) {
+                // One step per key press:
+                let direction = if self.range.start() <= self.range.end() {
+                    1.0
+                } else {
+                    -1.0
+                };
+                let new_value = self.get_value() + direction * kb_step as f64 * step;
+                self.set_value(new_value);
+            } else if kb_step != 0.0 {
                 let prev_value = self.get_value();
                 let prev_x = self.x_from_value(prev_value, x_range.clone());
                 let new_x = prev_x + kb_step;
-                let new_value = if self.smart_aim {
+                let new_value = if self.use_smart_aim() {
                     let aim_radius = ui.input().aim_radius();
                     emath::smart_aim::best_in_range_f64(
                         self.value_from_x(new_x - aim_radius, x_range.clone()),
@@ -380,7 +566,7 @@ impl<'a> Slider<'a> {
                 pos2(rect.left(), rect.center().y - rail_radius),
                 pos2(rect.right(), rect.center().y + rail_radius),
             );
-            let marker_center_x = self.x_from_value(value, x_range);
+            let marker_center_x = self.x_from_value(value, x_range.clone());
 
             let visuals = ui.style().interact(response);
             ui.painter().add(Shape::Rect {
@@ -395,6 +581,10 @@ impl<'a> Slider<'a> {
                 // stroke: ui.visuals().widgets.inactive.bg_stroke,
             });
 
+            if let Some(every) = self.ticks {
+                self.paint_ticks(ui, rect, &rail_rect, every, x_range);
+            }
+
             ui.painter().add(Shape::Circle {
                 center: pos2(marker_center_x, rail_rect.center().y),
                 radius: handle_radius(rect) + visuals.expansion,
@@ -404,6 +594,52 @@ impl<'a> Slider<'a> {
         }
     }
 
+    fn paint_ticks(
+        &self,
+        ui: &Ui,
+        rect: &Rect,
+        rail_rect: &Rect,
+        every: f64,
+        x_range: RangeInclusive<f32>,
+    ) {
+        let (start, end) = (*self.range.start(), *self.range.end());
+        let (min, max) = (start.min(end), start.max(end));
+        if !min.is_finite() || !max.is_finite() {
+            return;
+        }
+        let count = ((max - min) / every + 1e-6).floor() as usize + 1;
+        if count as f32 > 0.5 * (x_range.end() - x_range.start()) {
+            return; // Too dense to be of any use
+        }
+
+        let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        let text_color = ui.visuals().weak_text_color();
+        let max_decimals = self.max_decimals.unwrap_or(6).max(self.min_decimals);
+        for i in 0..count {
+            let value = min + i as f64 * every;
+            let x = self.x_from_value(value, x_range.clone());
+            ui.painter().line_segment(
+                [pos2(x, rail_rect.bottom() + 1.0), pos2(x, rect.bottom())],
+                stroke,
+            );
+            if self.tick_labels {
+                let text = format!(
+                    "{}{}{}",
+                    self.prefix,
+                    emath::format_with_decimals_in_range(value, self.min_decimals..=max_decimals),
+                    self.suffix
+                );
+                ui.painter().text(
+                    pos2(x, rect.bottom()),
+                    Align2::CENTER_TOP,
+                    text,
+                    TextStyle::Small,
+                    text_color,
+                );
+            }
+        }
+    }
+
     fn label_ui(&mut self, ui: &mut Ui) {
         if !self.text.is_empty() {
             let text_color = self.text_color.unwrap_or_else(|| ui.visuals().text_color());
@@ -454,7 +690,7 @@ impl<'a> Widget for Slider<'a> {
             self.slider_ui(ui, &slider_response);
 
             if self.show_value {
-                let x_range = x_range(&slider_response.rect);
+                let x_range = x_range(&self.track_rect(ui, slider_response.rect));
                 self.value_ui(ui, x_range);
             }
 
@@ -470,6 +706,419 @@ impl<'a> Widget for Slider<'a> {
     }
 }
 
+// ----------------------------------------------------------------------------
+
+/// Which part of a [`RangeSlider`] is being dragged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum RangeSliderPart {
+    Start,
+    End,
+    /// The segment between the handles, grabbed at this x with the handles at these x.
+    Middle {
+        grab_x: f32,
+        start_x: f32,
+        end_x: f32,
+    },
+}
+
+/// Control a range of numbers with a horizontal slider with two handles.
+///
+/// Drag a handle to move one end of the range,
+/// or drag the segment between the handles to move the whole range.
+/// With the keyboard focus, the left and right arrow keys move the part that was dragged last,
+/// or else the start handle.
+///
+/// Has the same options as [`Slider`], which apply to both ends of the range.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut price = 10.0..=50.0;
+/// ui.add(egui::RangeSlider::new(&mut price, 0.0..=100.0).suffix(" $").text("Price"));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct RangeSlider<'a> {
+    value: &'a mut RangeInclusive<f64>,
+    range: RangeInclusive<f64>,
+    spec: SliderSpec,
+    clamp_to_range: bool,
+    smart_aim: bool,
+    show_value: bool,
+    prefix: String,
+    suffix: String,
+    text: String,
+    text_color: Option<Color32>,
+    min_decimals: usize,
+    max_decimals: Option<usize>,
+}
+
+impl<'a> RangeSlider<'a> {
+    /// `range` is the values you get at the far edges of the slider, and must go from low to high.
+    pub fn new(value: &'a mut RangeInclusive<f64>, range: RangeInclusive<f64>) -> Self {
+        Self {
+            value,
+            range,
+            spec: SliderSpec {
+                logarithmic: false,
+                curve: SliderCurve::Linear,
+                smallest_positive: 1e-6,
+                largest_finite: f64::INFINITY,
+            },
+            clamp_to_range: false,
+            smart_aim: true,
+            show_value: true,
+            prefix: Default::default(),
+            suffix: Default::default(),
+            text: Default::default(),
+            text_color: None,
+            min_decimals: 0,
+            max_decimals: None,
+        }
+    }
+
+    /// Control wether or not the slider shows the start and end values.
+    /// Default: `true`.
+    pub fn show_value(mut self, show_value: bool) -> Self {
+        self.show_value = show_value;
+        self
+    }
+
+    /// Show a prefix before the numbers, e.g. "x: "
+    pub fn prefix(mut self, prefix: impl ToString) -> Self {
+        self.prefix = prefix.to_string();
+        self
+    }
+
+    /// Add a suffix to the numbers, this can be e.g. a unit ("°" or " m")
+    pub fn suffix(mut self, suffix: impl ToString) -> Self {
+        self.suffix = suffix.to_string();
+        self
+    }
+
+    /// Show a text next to the slider (e.g. explaining what the slider controls).
+    pub fn text(mut self, text: impl ToString) -> Self {
+        self.text = text.to_string();
+        self
+    }
+
+    pub fn text_color(mut self, text_color: Color32) -> Self {
+        self.text_color = Some(text_color);
+        self
+    }
+
+    /// See [`Slider::logarithmic`].
+    pub fn logarithmic(mut self, logarithmic: bool) -> Self {
+        self.spec.logarithmic = logarithmic;
+        self
+    }
+
+    /// See [`Slider::smallest_positive`].
+    pub fn smallest_positive(mut self, smallest_positive: f64) -> Self {
+        self.spec.smallest_positive = smallest_positive;
+        self
+    }
+
+    /// See [`Slider::largest_finite`].
+    pub fn largest_finite(mut self, largest_finite: f64) -> Self {
+        self.spec.largest_finite = largest_finite;
+        self
+    }
+
+    /// See [`Slider::curve`].
+    pub fn curve(mut self, curve: SliderCurve) -> Self {
+        self.spec.curve = curve;
+        self
+    }
+
+    /// If set to `true`, both ends of the range will be clamped to the slider range.
+    /// Default: `false`.
+    pub fn clamp_to_range(mut self, clamp_to_range: bool) -> Self {
+        self.clamp_to_range = clamp_to_range;
+        self
+    }
+
+    /// Turn smart aim on/off. Default is ON.
+    pub fn smart_aim(mut self, smart_aim: bool) -> Self {
+        self.smart_aim = smart_aim;
+        self
+    }
+
+    /// See [`Slider::min_decimals`].
+    pub fn min_decimals(mut self, min_decimals: usize) -> Self {
+        self.min_decimals = min_decimals;
+        self
+    }
+
+    /// See [`Slider::max_decimals`].
+    pub fn max_decimals(mut self, max_decimals: usize) -> Self {
+        self.max_decimals = Some(max_decimals);
+        self
+    }
+
+    /// See [`Slider::fixed_decimals`].
+    pub fn fixed_decimals(mut self, num_decimals: usize) -> Self {
+        self.min_decimals = num_decimals;
+        self.max_decimals = Some(num_decimals);
+        self
+    }
+
+    /// Pick whole numbers, see [`Slider::integer`].
+    pub fn integer(self) -> Self {
+        self.fixed_decimals(0).smallest_positive(1.0)
+    }
+
+    fn clamp_range(&self) -> RangeInclusive<f64> {
+        if self.clamp_to_range {
+            self.range.clone()
+        } else {
+            f64::NEG_INFINITY..=f64::INFINITY
+        }
+    }
+
+    /// Sets the range, keeping it clamped, rounded and in order.
+    fn set_value(&mut self, start: f64, end: f64) {
+        let clamp_range = self.clamp_range();
+        let clamp = |mut value: f64| {
+            value = value.clamp(*clamp_range.start(), *clamp_range.end());
+            if let Some(max_decimals) = self.max_decimals {
+                value = emath::round_to_decimals(value, max_decimals);
+            }
+            value
+        };
+        let (start, end) = (clamp(start), clamp(end));
+        *self.value = start.min(end)..=end;
+    }
+
+    fn value_from_x(&self, x: f32, x_range: RangeInclusive<f32>) -> f64 {
+        let normalized = remap_clamp(x, x_range, 0.0..=1.0) as f64;
+        value_from_normalized(normalized, self.range.clone(), &self.spec)
+    }
+
+    fn x_from_value(&self, value: f64, x_range: RangeInclusive<f32>) -> f32 {
+        let normalized = normalized_from_value(value, self.range.clone(), &self.spec);
+        lerp(x_range, normalized as f32)
+    }
+
+    /// The value at `x`, with smart aim if enabled.
+    fn aimed_value_from_x(&self, ui: &Ui, x: f32, x_range: RangeInclusive<f32>) -> f64 {
+        if self.smart_aim {
+            let aim_radius = ui.input().aim_radius();
+            emath::smart_aim::best_in_range_f64(
+                self.value_from_x(x - aim_radius, x_range.clone()),
+                self.value_from_x(x + aim_radius, x_range),
+            )
+        } else {
+            self.value_from_x(x, x_range)
+        }
+    }
+
+    /// Move the whole range so that it starts at `start_x`, keeping its width.
+    fn move_range_to(&mut self, ui: &Ui, start_x: f32, x_range: RangeInclusive<f32>) {
+        let (start, end) = (*self.value.start(), *self.value.end());
+        let width = end - start;
+        // Only aim the start, so that smart aim doesn't change the width:
+        let mut new_start = self.aimed_value_from_x(ui, start_x, x_range);
+        let (min, max) = (*self.range.start(), *self.range.end() - width);
+        if min <= max {
+            new_start = new_start.clamp(min, max);
+        }
+        self.set_value(new_start, new_start + width);
+    }
+
+    /// Just the slider, no text
+    fn slider_ui(&mut self, ui: &mut Ui, response: &Response) {
+        let rect = &response.rect;
+        let x_range = x_range(rect);
+        let (start, end) = (*self.value.start(), *self.value.end());
+        let start_x = self.x_from_value(start, x_range.clone());
+        let end_x = self.x_from_value(end, x_range.clone());
+
+        if let Some(pointer_pos) = response.interact_pointer_pos() {
+            let part = if response.dragged() && !response.drag_started() {
+                ui.memory()
+                    .id_data_temp
+                    .get::<RangeSliderPart>(&response.id)
+                    .copied()
+            } else {
+                None
+            };
+            let part = part.unwrap_or_else(|| {
+                let x = pointer_pos.x;
+                let handle_radius = handle_radius(rect);
+                if (x - start_x).abs() > handle_radius
+                    && (x - end_x).abs() > handle_radius
+                    && start_x < x
+                    && x < end_x
+                {
+                    RangeSliderPart::Middle {
+                        grab_x: x,
+                        start_x,
+                        end_x,
+                    }
+                } else if (x - start_x).abs() < (x - end_x).abs() || x < start_x {
+                    RangeSliderPart::Start
+                } else {
+                    RangeSliderPart::End
+                }
+            });
+            ui.memory().id_data_temp.insert(response.id, part);
+
+            match part {
+                RangeSliderPart::Start => {
+                    let new_start = self.aimed_value_from_x(ui, pointer_pos.x, x_range.clone());
+                    self.set_value(new_start.min(end), end);
+                }
+                RangeSliderPart::End => {
+                    let new_end = self.aimed_value_from_x(ui, pointer_pos.x, x_range.clone());
+                    self.set_value(start, new_end.max(start));
+                }
+                RangeSliderPart::Middle {
+                    grab_x,
+                    start_x,
+                    end_x,
+                } => {
+                    // Move both handles, but keep them on the slider:
+                    let delta = (pointer_pos.x - grab_x)
+                        .at_least(x_range.start() - start_x)
+                        .at_most(x_range.end() - end_x);
+                    self.move_range_to(ui, start_x + delta, x_range.clone());
+                }
+            }
+        }
+
+        if response.has_focus() {
+            let kb_step = ui.input().num_presses(Key::ArrowRight) as f32
+                - ui.input().num_presses(Key::ArrowLeft) as f32;
+            if kb_step != 0.0 {
+                let part = ui
+                    .memory()
+                    .id_data_temp
+                    .get::<RangeSliderPart>(&response.id)
+                    .copied()
+                    .unwrap_or(RangeSliderPart::Start);
+                match part {
+                    RangeSliderPart::Start => {
+                        let new_start =
+                            self.aimed_value_from_x(ui, start_x + kb_step, x_range.clone());
+                        self.set_value(new_start.min(end), end);
+                    }
+                    RangeSliderPart::End => {
+                        let new_end = self.aimed_value_from_x(ui, end_x + kb_step, x_range.clone());
+                        self.set_value(start, new_end.max(start));
+                    }
+                    RangeSliderPart::Middle { .. } => {
+                        let new_start_x = (start_x + kb_step)
+                            .at_least(*x_range.start())
+                            .at_most(x_range.end() - (end_x - start_x));
+                        self.move_range_to(ui, new_start_x, x_range.clone());
+                    }
+                }
+            }
+        }
+
+        response.widget_info(|| WidgetInfo::labeled(WidgetType::Slider, &self.text));
+
+        // Paint it:
+        {
+            let start_x = self.x_from_value(*self.value.start(), x_range.clone());
+            let end_x = self.x_from_value(*self.value.end(), x_range);
+
+            let rail_radius = ui.painter().round_to_pixel((rect.height() / 8.0).max(2.0));
+            let rail_rect = Rect::from_min_max(
+                pos2(rect.left(), rect.center().y - rail_radius),
+                pos2(rect.right(), rect.center().y + rail_radius),
+            );
+            let selected_rect = Rect::from_x_y_ranges(start_x..=end_x, rail_rect.y_range());
+
+            let visuals = ui.style().interact(response);
+            ui.painter().rect_filled(
+                rail_rect,
+                rail_radius,
+                ui.visuals().widgets.inactive.bg_fill,
+            );
+            ui.painter()
+                .rect_filled(selected_rect, rail_radius, ui.visuals().selection.bg_fill);
+
+            for &x in &[start_x, end_x] {
+                ui.painter().add(Shape::Circle {
+                    center: pos2(x, rail_rect.center().y),
+                    radius: handle_radius(rect) + visuals.expansion,
+                    fill: visuals.bg_fill,
+                    stroke: visuals.fg_stroke,
+                });
+            }
+        }
+    }
+
+    fn value_ui(&mut self, ui: &mut Ui, x_range: RangeInclusive<f32>) {
+        let (mut start, mut end) = (*self.value.start(), *self.value.end());
+        let clamp_range = self.clamp_range();
+
+        // delta(value) / delta(points), at the given value:
+        let speed = |value: f64| {
+            let x = self.x_from_value(value, x_range.clone());
+            self.value_from_x(x + 0.5, x_range.clone())
+                - self.value_from_x(x - 0.5, x_range.clone())
+        };
+        let (start_speed, end_speed) = (speed(start), speed(end));
+
+        ui.add(
+            DragValue::new(&mut start)
+                .speed(start_speed)
+                .clamp_range(*clamp_range.start()..=end)
+                .min_decimals(self.min_decimals)
+                .max_decimals_opt(self.max_decimals)
+                .suffix(self.suffix.clone())
+                .prefix(self.prefix.clone()),
+        );
+        ui.label("–");
+        ui.add(
+            DragValue::new(&mut end)
+                .speed(end_speed)
+                .clamp_range(start..=*clamp_range.end())
+                .min_decimals(self.min_decimals)
+                .max_decimals_opt(self.max_decimals)
+                .suffix(self.suffix.clone())
+                .prefix(self.prefix.clone()),
+        );
+        if start != *self.value.start() || end != *self.value.end() {
+            self.set_value(start, end);
+        }
+    }
+}
+
+impl<'a> Widget for RangeSlider<'a> {
+    fn ui(mut self, ui: &mut Ui) -> Response {
+        let text_style = TextStyle::Button;
+        let height = ui
+            .fonts()
+            .row_height(text_style)
+            .at_least(ui.spacing().interact_size.y);
+
+        let old_value = self.value.clone();
+
+        let inner_response = ui.horizontal(|ui| {
+            let desired_size = vec2(ui.spacing().slider_width, height);
+            let slider_response = ui.allocate_response(desired_size, Sense::click_and_drag());
+            self.slider_ui(ui, &slider_response);
+
+            if self.show_value {
+                let x_range = x_range(&slider_response.rect);
+                self.value_ui(ui, x_range);
+            }
+
+            if !self.text.is_empty() {
+                let text_color = self.text_color.unwrap_or_else(|| ui.visuals().text_color());
+                ui.add(Label::new(&self.text).wrap(false).text_color(text_color));
+            }
+            slider_response
+        });
+
+        let mut response = inner_response.inner | inner_response.response;
+        response.changed = *self.value != old_value;
+        response
+    }
+}
+
 // ----------------------------------------------------------------------------
 // Helpers for converting slider range to/from normalized [0-1] range.
 // Always clamps.
@@ -527,7 +1176,10 @@ fn value_from_normalized(normalized: f64, range: RangeInclusive<f64>, spec: &Sli
             min.is_finite() && max.is_finite(),
             "You should use a logarithmic range"
         );
-        lerp(range, normalized.clamp(0.0, 1.0))
+        let fraction = spec
+            .curve
+            .fraction_from_position(normalized.clamp(0.0, 1.0));
+        lerp(range, fraction)
     }
 }
 
@@ -576,7 +1228,8 @@ fn normalized_from_value(value: f64, range: RangeInclusive<f64>, spec: &SliderSp
             min.is_finite() && max.is_finite(),
             "You should use a logarithmic range"
         );
-        remap_clamp(value, range, 0.0..=1.0)
+        let fraction = remap_clamp(value, range, 0.0..=1.0);
+        spec.curve.position_from_fraction(fraction)
     }
 }
 
@@ -623,3 +1276,47 @@ fn logaritmic_zero_cutoff(min: f64, max: f64) -> f64 {
     crate::egui_assert!(0.0 <= cutoff && cutoff <= 1.0);
     cutoff
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dragging_range_keeps_its_width() {
+        let mut ctx = CtxRef::default();
+        let mut value = 10.0..=73.0;
+        let mut run = |ctx: &mut CtxRef, events: Vec<Event>| {
+            ctx.begin_frame(RawInput {
+                events,
+                ..Default::default()
+            });
+            let mut rect = Rect::NOTHING;
+            CentralPanel::default().show(ctx, |ui| {
+                let slider = RangeSlider::new(&mut value, 0.0..=100.0).show_value(false);
+                rect = ui.add(slider).rect;
+            });
+            let _ = ctx.end_frame();
+            (rect, value.clone())
+        };
+        let button = |pos: Pos2, pressed: bool| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        let (rect, _) = run(&mut ctx, vec![]);
+        let grab = pos2(lerp(rect.x_range(), 0.4), rect.center().y);
+        run(
+            &mut ctx,
+            vec![Event::PointerMoved(grab), button(grab, true)],
+        );
+        let mut value = 10.0..=73.0;
+        for i in 1..=20 {
+            let pos = grab + vec2(i as f32 * 0.7, 0.0);
+            value = run(&mut ctx, vec![Event::PointerMoved(pos)]).1;
+            assert_eq!(value.end() - value.start(), 63.0);
+        }
+        assert!(*value.start() > 10.0);
+    }
+}
@@ -0,0 +1,73 @@
+use crate::*;
+
+/// A spinning arc, to show that something is loading.
+///
+/// Keeps requesting repaints for as long as it is visible.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// ui.horizontal(|ui| {
+///     ui.add(egui::Spinner::new());
+///     ui.label("Loading…");
+/// });
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Spinner {
+    size: Option<f32>,
+    color: Option<Color32>,
+}
+
+impl Spinner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The width and height of the spinner. Default: the height of a line of text.
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Default: the text color.
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+impl Widget for Spinner {
+    fn ui(self, ui: &mut Ui) -> Response {
+        use std::f32::consts::TAU;
+
+        let Spinner { size, color } = self;
+        let size = size.unwrap_or_else(|| ui.spacing().interact_size.y);
+        let color = color.unwrap_or_else(|| ui.visuals().widgets.noninteractive.fg_stroke.color);
+
+        let (rect, response) = ui.allocate_exact_size(Vec2::splat(size), Sense::hover());
+        if ui.clip_rect().intersects(rect) {
+            ui.ctx().request_repaint();
+
+            let time = ui.input().time;
+            let stroke = Stroke::new(0.15 * size, color);
+            let radius = 0.5 * (size - stroke.width);
+            let start_angle = (time * 4.0 % TAU as f64) as f32;
+            // The arc grows and shrinks while it rotates:
+            let sweep = remap(
+                (time * 2.0).sin() as f32,
+                -1.0..=1.0,
+                0.15 * TAU..=0.75 * TAU,
+            );
+
+            let n_points = 24;
+            let points: Vec<Pos2> = (0..=n_points)
+                .map(|i| {
+                    let angle = start_angle + sweep * i as f32 / n_points as f32;
+                    rect.center() + radius * Vec2::angled(angle)
+                })
+                .collect();
+            ui.painter().add(Shape::line(points, stroke));
+        }
+        response
+    }
+}
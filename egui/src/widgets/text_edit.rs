@@ -155,6 +155,64 @@ impl TextBuffer for String {
     }
 }
 
+type SuggestionBox<'t> = Box<dyn 't + Fn(&str) -> Vec<String>>;
+
+/// Gives the suggestions for [`TextEdit::suggestions`].
+struct SuggestionFn<'t>(SuggestionBox<'t>);
+
+impl<'t> std::fmt::Debug for SuggestionFn<'t> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SuggestionFn")
+    }
+}
+
+/// The suggestions shown under a [`TextEdit`] while it has focus.
+#[derive(Clone, Debug, Default)]
+struct AutocompleteState {
+    /// The suggestions shown in the popup.
+    shown: Vec<String>,
+    /// Moved with the arrow keys.
+    highlighted: Option<usize>,
+    /// Hidden after accepting a suggestion, until the text is edited.
+    dismissed: bool,
+    /// The text last frame, to know when the user edits it.
+    text: String,
+}
+
+impl AutocompleteState {
+    fn is_open(&self) -> bool {
+        !self.dismissed && !self.shown.is_empty()
+    }
+
+    /// Tab accepts the highlighted (or first) suggestion, enter only a highlighted one.
+    fn accepts(&self, key: Key) -> bool {
+        self.is_open() && (key == Key::Tab || key == Key::Enter && self.highlighted.is_some())
+    }
+
+    fn step(&mut self, down: bool) {
+        let last = self.shown.len().saturating_sub(1);
+        self.highlighted = match (self.highlighted, down) {
+            (None, true) => Some(0),
+            (None, false) => Some(last),
+            (Some(index), true) => Some((index + 1).min(last)),
+            (Some(index), false) => index.checked_sub(1),
+        };
+    }
+
+    fn accept(&mut self, index: usize) -> Option<String> {
+        let accepted = self.shown.get(index).cloned();
+        if let Some(accepted) = &accepted {
+            self.text = accepted.clone();
+            self.dismissed = true;
+            self.highlighted = None;
+        }
+        accepted
+    }
+}
+
+/// Space between the text and the frame.
+const MARGIN: Vec2 = Vec2::new(4.0, 2.0);
+
 /// A text region that the user can edit the contents of.
 ///
 /// See also [`Ui::text_edit_singleline`] and  [`Ui::text_edit_multiline`].
@@ -191,6 +249,8 @@ pub struct TextEdit<'t, S: TextBuffer = String> {
     text_style: Option<TextStyle>,
     text_color: Option<Color32>,
     password: bool,
+    reveal_button: bool,
+    suggestions: Option<SuggestionFn<'t>>,
     frame: bool,
     multiline: bool,
     enabled: bool,
@@ -223,6 +283,8 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             text_style: None,
             text_color: None,
             password: false,
+            reveal_button: false,
+            suggestions: None,
             frame: true,
             multiline: false,
             enabled: true,
@@ -242,6 +304,8 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             text_style: None,
             text_color: None,
             password: false,
+            reveal_button: false,
+            suggestions: None,
             frame: true,
             multiline: true,
             enabled: true,
@@ -279,11 +343,46 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
     }
 
     /// If true, hide the letters from view and prevent copying from the field.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut password = String::new();
+    /// ui.add(egui::TextEdit::singleline(&mut password).password(true).reveal_button(true));
+    /// ```
     pub fn password(mut self, password: bool) -> Self {
         self.password = password;
         self
     }
 
+    /// Show suggestions in a popup under a single-line text field while it has focus.
+    ///
+    /// `suggestions` is called with the current text, and returns the completions to show.
+    /// The arrow keys move between them, and tab or enter replaces the text with one.
+    ///
+    /// ```
+    /// # let ui = &mut egui::Ui::__test();
+    /// # let mut command = String::new();
+    /// let commands = ["open", "save", "save as", "quit"];
+    /// ui.add(egui::TextEdit::singleline(&mut command).suggestions(|text| {
+    ///     commands
+    ///         .iter()
+    ///         .filter(|command| !text.is_empty() && command.starts_with(text))
+    ///         .map(|command| command.to_string())
+    ///         .collect()
+    /// }));
+    /// ```
+    pub fn suggestions(mut self, suggestions: impl 't + Fn(&str) -> Vec<String>) -> Self {
+        self.suggestions = Some(SuggestionFn(Box::new(suggestions)));
+        self
+    }
+
+    /// Put an eye button in a password field, that shows the text while it is held down.
+    /// Copying stays disabled. Only used together with [`Self::password`]. Default: `false`.
+    pub fn reveal_button(mut self, reveal_button: bool) -> Self {
+        self.reveal_button = reveal_button;
+        self
+    }
+
     pub fn text_style(mut self, text_style: TextStyle) -> Self {
         self.text_style = Some(text_style);
         self
@@ -337,15 +436,68 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 }
 
 impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
-    fn ui(self, ui: &mut Ui) -> Response {
+    fn ui(mut self, ui: &mut Ui) -> Response {
         let frame = self.frame;
         let where_to_put_background = ui.painter().add(Shape::Noop);
 
-        let margin = Vec2::new(4.0, 2.0);
-        let max_rect = ui.available_rect_before_wrap().shrink2(margin);
+        let reveal_button = self.password && self.reveal_button;
+        let reveal_id = ui.auto_id_with("reveal_password");
+        let reveal_width = if reveal_button {
+            ui.spacing().interact_size.y
+        } else {
+            0.0
+        };
+        let revealed = reveal_button
+            && ui
+                .memory()
+                .id_data_temp
+                .get::<bool>(&reveal_id)
+                .copied()
+                .unwrap_or(false);
+        if reveal_button {
+            let desired_width = self
+                .desired_width
+                .unwrap_or_else(|| ui.spacing().text_edit_width);
+            self.desired_width = Some((desired_width - reveal_width).at_least(0.0));
+        }
+
+        let margin = MARGIN;
+        let mut max_rect = ui.available_rect_before_wrap().shrink2(margin);
+        max_rect.max.x = (max_rect.max.x - reveal_width).at_least(max_rect.min.x);
         let mut content_ui = ui.child_ui(max_rect, *ui.layout());
-        let response = self.content_ui(&mut content_ui);
-        let frame_rect = response.rect.expand2(margin);
+        let response = self.content_ui(&mut content_ui, revealed);
+        let mut content_rect = response.rect;
+
+        if reveal_button {
+            let rect = Rect::from_min_size(
+                content_rect.right_top(),
+                vec2(reveal_width, content_rect.height()),
+            );
+            content_rect = content_rect.union(rect);
+            let eye_response = ui
+                .interact(rect, reveal_id, Sense::click())
+                .on_hover_text("Hold to show the password");
+            let is_held = eye_response.is_pointer_button_down_on();
+            if is_held != revealed {
+                ui.ctx().request_repaint();
+            }
+            ui.memory().id_data_temp.insert(reveal_id, is_held);
+
+            let color = if is_held || eye_response.hovered() {
+                ui.visuals().strong_text_color()
+            } else {
+                ui.visuals().weak_text_color()
+            };
+            ui.painter().text(
+                rect.center(),
+                Align2::CENTER_CENTER,
+                "👁",
+                TextStyle::Button,
+                color,
+            );
+        }
+
+        let frame_rect = content_rect.expand2(margin);
         let response = response | ui.allocate_rect(frame_rect, Sense::hover());
 
         if frame {
@@ -376,7 +528,8 @@ impl<'t, S: TextBuffer> Widget for TextEdit<'t, S> {
 }
 
 impl<'t, S: TextBuffer> TextEdit<'t, S> {
-    fn content_ui(self, ui: &mut Ui) -> Response {
+    /// `revealed`: show the text of a password field, but still prevent copying it.
+    fn content_ui(self, ui: &mut Ui, revealed: bool) -> Response {
         let TextEdit {
             text,
             hint_text,
@@ -385,6 +538,8 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
             text_style,
             text_color,
             password,
+            reveal_button: _,
+            suggestions,
             frame: _,
             multiline,
             enabled,
@@ -400,7 +555,7 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         let available_width = ui.available_width();
 
         let make_galley = |ui: &Ui, text: &str| {
-            let text = if password {
+            let text = if password && !revealed {
                 std::iter::repeat(epaint::text::PASSWORD_REPLACEMENT_CHAR)
                     .take(text.chars().count())
                     .collect::<String>()
@@ -447,6 +602,30 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         };
         let mut response = ui.interact(rect, id, sense);
 
+        let suggestions_id = id.with("suggestions");
+        let suggestions = suggestions.filter(|_| !multiline);
+        if suggestions.is_some() && !ui.memory().has_focus(id) {
+            // Clicking a suggestion is a click outside of the text, but shouldn't take away the focus:
+            let suggestions_open = matches!(
+                ui.memory().id_data_temp.get::<AutocompleteState>(&suggestions_id),
+                Some(autocomplete) if autocomplete.is_open()
+            );
+            let popup_layer = Area::new(suggestions_id).layer();
+            let popup_rect = ui
+                .memory()
+                .areas
+                .get(popup_layer.id)
+                .map(|state| state.rect());
+            let pointer = &ui.input().pointer;
+            if let (true, Some(popup_rect), Some(pos)) =
+                (suggestions_open, popup_rect, pointer.interact_pos())
+            {
+                if pointer.any_click() && popup_rect.contains(pos) {
+                    ui.memory().request_focus(id);
+                }
+            }
+        }
+
         if enabled {
             if let Some(pointer_pos) = ui.input().pointer.interact_pos() {
                 // TODO: triple-click to select whole paragraph
@@ -497,7 +676,19 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
         }
 
         if ui.memory().has_focus(id) && enabled {
-            ui.memory().lock_focus(id, lock_focus);
+            let mut autocomplete = suggestions.as_ref().map(|_| {
+                ui.memory()
+                    .id_data_temp
+                    .get_or_default::<AutocompleteState>(suggestions_id)
+                    .clone()
+            });
+            // Keep tab for accepting a suggestion:
+            let suggestions_open =
+                matches!(&autocomplete, Some(autocomplete) if autocomplete.is_open());
+            let highlighted_before = autocomplete
+                .as_ref()
+                .and_then(|autocomplete| autocomplete.highlighted);
+            ui.memory().lock_focus(id, lock_focus || suggestions_open);
 
             let mut cursorp = state
                 .cursorp
@@ -524,6 +715,24 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
 
             for event in &ui.input().events {
                 let did_mutate_text = match event {
+                    Event::Key {
+                        key, pressed: true, ..
+                    } if suggestions_open && (*key == Key::ArrowDown || *key == Key::ArrowUp) => {
+                        if let Some(autocomplete) = &mut autocomplete {
+                            autocomplete.step(*key == Key::ArrowDown);
+                        }
+                        None
+                    }
+                    Event::Key {
+                        key, pressed: true, ..
+                    } if matches!(&autocomplete, Some(autocomplete) if autocomplete.accepts(*key)) => {
+                        autocomplete
+                            .as_mut()
+                            .and_then(|autocomplete| {
+                                autocomplete.accept(autocomplete.highlighted.unwrap_or(0))
+                            })
+                            .map(|accepted| CCursorPair::one(replace_text(text, &accepted)))
+                    }
                     Event::Copy => {
                         if cursorp.is_empty() {
                             copy_if_not_password(ui, text.as_ref().to_owned());
@@ -666,6 +875,42 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
                     };
                 }
             }
+
+            if let (Some(autocomplete), Some(suggestions)) = (&mut autocomplete, &suggestions) {
+                if text.as_ref() != autocomplete.text {
+                    // Edited by the user:
+                    autocomplete.text = text.as_ref().to_owned();
+                    autocomplete.dismissed = false;
+                    autocomplete.highlighted = None;
+                }
+                autocomplete.shown = if autocomplete.dismissed {
+                    vec![]
+                } else {
+                    (suggestions.0)(text.as_ref())
+                };
+                if let Some(highlighted) = autocomplete.highlighted {
+                    if highlighted >= autocomplete.shown.len() {
+                        autocomplete.highlighted = None;
+                    }
+                }
+
+                if autocomplete.is_open() {
+                    let frame_rect = response.rect.expand2(MARGIN);
+                    let scroll = autocomplete.highlighted != highlighted_before;
+                    let clicked =
+                        suggestions_popup(ui, suggestions_id, frame_rect, autocomplete, scroll);
+                    if let Some(accepted) = clicked.and_then(|index| autocomplete.accept(index)) {
+                        let ccursor = replace_text(text, &accepted);
+                        response.mark_changed();
+                        galley = make_galley(ui, text.as_ref());
+                        cursorp = CursorPair::one(galley.from_ccursor(ccursor));
+                    }
+                }
+                ui.memory()
+                    .id_data_temp
+                    .insert(suggestions_id, autocomplete.clone());
+            }
+
             state.cursorp = Some(cursorp);
 
             state
@@ -707,6 +952,10 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
                 .galley(response.rect.min, galley, hint_text_color);
         }
 
+        if suggestions.is_some() && !ui.memory().has_focus(id) {
+            ui.memory().id_data_temp.remove(&suggestions_id);
+        }
+
         ui.memory().id_data.insert(id, state);
 
         response.widget_info(|| WidgetInfo::text_edit(&*text));
@@ -714,6 +963,50 @@ impl<'t, S: TextBuffer> TextEdit<'t, S> {
     }
 }
 
+/// The popup under a [`TextEdit`] with its suggestions.
+/// Returns the index of the suggestion the user clicked, if any.
+fn suggestions_popup(
+    ui: &Ui,
+    id: Id,
+    frame_rect: Rect,
+    autocomplete: &AutocompleteState,
+    scroll_to_highlighted: bool,
+) -> Option<usize> {
+    let mut clicked = None;
+    Area::new(id)
+        .order(Order::Foreground)
+        .fixed_pos(frame_rect.left_bottom())
+        .show(ui.ctx(), |ui| {
+            let frame = Frame::popup(ui.style());
+            let width = frame_rect.width() - 2.0 * frame.margin.x;
+            frame.show(ui, |ui| {
+                ui.set_width(width);
+                ScrollArea::from_max_height(128.0).show(ui, |ui| {
+                    ui.with_layout(Layout::top_down_justified(Align::LEFT), |ui| {
+                        for (index, suggestion) in autocomplete.shown.iter().enumerate() {
+                            let highlighted = autocomplete.highlighted == Some(index);
+                            let response = ui.selectable_label(highlighted, suggestion);
+                            if highlighted && scroll_to_highlighted {
+                                response.scroll_to_me(Align::Center);
+                            }
+                            if response.clicked() {
+                                clicked = Some(index);
+                            }
+                        }
+                    });
+                });
+            });
+        });
+    clicked
+}
+
+/// Replace all of `text` with `new_text`, and return the cursor at the end of it.
+fn replace_text<S: TextBuffer>(text: &mut S, new_text: &str) -> CCursor {
+    let len = text.as_ref().chars().count();
+    text.delete_char_range(0..len);
+    CCursor::new(text.insert_text(new_text, 0))
+}
+
 // ----------------------------------------------------------------------------
 
 fn paint_cursor_selection(ui: &mut Ui, pos: Pos2, galley: &Galley, cursorp: &CursorPair) {
@@ -1134,3 +1427,52 @@ fn decrease_identation<S: TextBuffer>(ccursor: &mut CCursor, text: &mut S) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn click_suggestion() {
+        let mut ctx = CtxRef::default();
+        let mut text = "ap".to_owned();
+        let id = Id::new("fruit");
+        let mut run = |ctx: &mut CtxRef, events: Vec<Event>| {
+            ctx.begin_frame(RawInput {
+                events,
+                ..Default::default()
+            });
+            CentralPanel::default().show(ctx, |ui| {
+                ui.add(TextEdit::singleline(&mut text).id(id).suggestions(|text| {
+                    vec!["apple".to_owned(), "apricot".to_owned()]
+                        .into_iter()
+                        .filter(|fruit| fruit.starts_with(text))
+                        .collect()
+                }));
+            });
+            let _ = ctx.end_frame();
+            text.clone()
+        };
+        let button = |pos: Pos2, pressed: bool| Event::PointerButton {
+            pos,
+            button: PointerButton::Primary,
+            pressed,
+            modifiers: Default::default(),
+        };
+
+        run(&mut ctx, vec![]);
+        ctx.memory().request_focus(id);
+        run(&mut ctx, vec![]);
+        run(&mut ctx, vec![]);
+        let popup_layer = Area::new(id.with("suggestions")).layer();
+        let popup = ctx.memory().areas.get(popup_layer.id).unwrap().rect();
+        let apricot = pos2(popup.center().x, lerp(popup.y_range(), 0.7));
+        run(
+            &mut ctx,
+            vec![Event::PointerMoved(apricot), button(apricot, true)],
+        );
+        let text = run(&mut ctx, vec![button(apricot, false)]);
+        assert_eq!(text, "apricot");
+        assert!(ctx.memory().has_focus(id));
+    }
+}
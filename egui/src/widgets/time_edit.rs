@@ -0,0 +1,251 @@
+use std::time::Duration;
+
+use crate::*;
+
+/// One of the fields of a [`TimeEdit`].
+#[derive(Clone, Copy)]
+struct Segment {
+    /// Milliseconds per step of this segment.
+    unit: u64,
+    /// The number of steps before the next larger segment, if it is shown.
+    limit: Option<u64>,
+    digits: usize,
+}
+
+/// Edit a [`Duration`], or a time of day, with separate fields for the hours,
+/// minutes, seconds and (optionally) milliseconds.
+///
+/// Type a new value into a field, press the up and down arrows,
+/// or scroll while hovering a field to step it.
+/// Steps carry over to the other fields, e.g. one second more than `0:59` is `1:00`.
+/// A field shows its text in red while it isn't a valid number.
+///
+/// ```
+/// # let ui = &mut egui::Ui::__test();
+/// # let mut alarm = std::time::Duration::from_secs(7 * 3600);
+/// # let mut fade = std::time::Duration::from_millis(1500);
+/// ui.add(egui::TimeEdit::new(&mut alarm).time_of_day(true));
+/// ui.add(egui::TimeEdit::new(&mut fade).hours(false).milliseconds(true));
+/// ```
+#[must_use = "You should put this widget in an ui with `ui.add(widget);`"]
+pub struct TimeEdit<'a> {
+    value: &'a mut Duration,
+    hours: bool,
+    milliseconds: bool,
+    time_of_day: bool,
+    max: Option<Duration>,
+}
+
+impl<'a> TimeEdit<'a> {
+    pub fn new(value: &'a mut Duration) -> Self {
+        Self {
+            value,
+            hours: true,
+            milliseconds: false,
+            time_of_day: false,
+            max: None,
+        }
+    }
+
+    /// Show a field for the hours. Without it, the minutes go above 59. Default: `true`.
+    pub fn hours(mut self, hours: bool) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    /// Show a field for the milliseconds. Default: `false`.
+    pub fn milliseconds(mut self, milliseconds: bool) -> Self {
+        self.milliseconds = milliseconds;
+        self
+    }
+
+    /// Edit a time of day: keep the value below 24 hours, and wrap around midnight when stepping.
+    /// Default: `false`.
+    pub fn time_of_day(mut self, time_of_day: bool) -> Self {
+        self.time_of_day = time_of_day;
+        self
+    }
+
+    /// Don't go above this duration. Default: no limit.
+    pub fn max(mut self, max: Duration) -> Self {
+        self.max = Some(max);
+        self
+    }
+}
+
+const DAY_MS: u64 = 24 * 3600 * 1000;
+
+impl<'a> Widget for TimeEdit<'a> {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let TimeEdit {
+            value,
+            hours,
+            milliseconds,
+            time_of_day,
+            max,
+        } = self;
+
+        let mut segments = vec![];
+        if hours {
+            segments.push(Segment {
+                unit: 3600 * 1000,
+                limit: None,
+                digits: 2,
+            });
+        }
+        segments.push(Segment {
+            unit: 60 * 1000,
+            limit: None,
+            digits: 2,
+        });
+        segments.push(Segment {
+            unit: 1000,
+            limit: Some(60),
+            digits: 2,
+        });
+        if milliseconds {
+            segments.push(Segment {
+                unit: 1,
+                limit: Some(1000),
+                digits: 3,
+            });
+        }
+        if hours {
+            segments[1].limit = Some(60);
+        }
+        if time_of_day {
+            segments[0].limit = Some(DAY_MS / segments[0].unit);
+        }
+
+        // Sub-millisecond precision is kept as is:
+        let sub_milli = Duration::from_nanos(value.subsec_nanos() as u64 % 1_000_000);
+        let old_ms = value.as_millis() as u64;
+        let max_ms = match (max, time_of_day) {
+            (Some(max), true) => (max.as_millis() as u64).min(DAY_MS - 1),
+            (Some(max), false) => max.as_millis() as u64,
+            (None, true) => DAY_MS - 1,
+            (None, false) => u64::MAX,
+        };
+
+        let id = ui.auto_id_with("time_edit");
+        let mut total_ms = old_ms.min(max_ms);
+
+        let mut response = ui
+            .horizontal(|ui| {
+                ui.spacing_mut().item_spacing.x = 0.0;
+                for (index, segment) in segments.iter().enumerate() {
+                    if index > 0 {
+                        ui.label(if segment.unit == 1 { "." } else { ":" });
+                    }
+                    let steps = segment_ui(ui, id.with(index), *segment, total_ms / segment.unit);
+                    if let Some(steps) = steps {
+                        total_ms = step(total_ms, steps, segment.unit, max_ms, time_of_day);
+                    }
+                }
+            })
+            .response;
+
+        if total_ms != old_ms {
+            *value = Duration::from_millis(total_ms) + sub_milli;
+            response.mark_changed();
+        }
+        response
+    }
+}
+
+/// Add `steps` of `unit` milliseconds to `total_ms`, keeping it within `0..=max_ms`.
+fn step(total_ms: u64, steps: i64, unit: u64, max_ms: u64, wrap: bool) -> u64 {
+    let new_ms = total_ms as i128 + steps as i128 * unit as i128;
+    if wrap {
+        new_ms.rem_euclid(max_ms as i128 + 1) as u64
+    } else {
+        new_ms.max(0).min(max_ms as i128) as u64
+    }
+}
+
+/// The text field of one segment, showing `count` units (and more, for the first segment).
+/// Returns how many units to add, if the user changed it.
+fn segment_ui(ui: &mut Ui, id: Id, segment: Segment, count: u64) -> Option<i64> {
+    let Segment {
+        unit: _,
+        limit,
+        digits,
+    } = segment;
+    let shown = match limit {
+        Some(limit) => count % limit,
+        None => count,
+    };
+    let formatted = format!("{:0digits$}", shown, digits = digits);
+
+    let has_focus = ui.memory().has_focus(id);
+    let mut text = if has_focus {
+        ui.memory()
+            .id_data_temp
+            .get::<String>(&id)
+            .cloned()
+            .unwrap_or_else(|| formatted.clone())
+    } else {
+        formatted.clone()
+    };
+    let parse = |text: &str| -> Option<u64> {
+        match (text.trim().parse(), limit) {
+            (Ok(parsed), Some(limit)) if parsed >= limit => None,
+            (Ok(parsed), _) => Some(parsed),
+            (Err(_), _) => None,
+        }
+    };
+
+    let text_color = if parse(&text).is_none() {
+        Some(Color32::RED)
+    } else {
+        None
+    };
+    let width = ui.fonts().glyph_width(TextStyle::Monospace, '0') * digits.max(text.len()) as f32;
+    let response = ui.add(
+        TextEdit::singleline(&mut text)
+            .id(id)
+            .text_style(TextStyle::Monospace)
+            .text_color_opt(text_color)
+            .desired_width(width),
+    );
+
+    let mut steps = 0;
+    if response.has_focus() {
+        let input = ui.input();
+        steps += input.num_presses(Key::ArrowUp) as i64 - input.num_presses(Key::ArrowDown) as i64;
+    }
+    if response.hovered() {
+        steps += scroll_steps(ui, id);
+    }
+
+    if steps != 0 {
+        ui.memory().id_data_temp.remove(&id); // show the stepped value
+        Some(steps)
+    } else if response.changed() {
+        let parsed = parse(&text);
+        ui.memory().id_data_temp.insert(id, text);
+        parsed.map(|parsed| parsed as i64 - shown as i64)
+    } else {
+        if !response.has_focus() {
+            // Discard invalid text when leaving the field:
+            ui.memory().id_data_temp.remove(&id);
+        }
+        None
+    }
+}
+
+/// Turn the scrolling over a segment into steps, and keep it from scrolling anything else.
+fn scroll_steps(ui: &Ui, id: Id) -> i64 {
+    let scroll_id = id.with("scroll");
+    let delta = std::mem::take(&mut ui.ctx().frame_state().scroll_delta.y);
+    if delta == 0.0 {
+        return 0;
+    }
+    let step_size = ui.spacing().interact_size.y;
+    let mut memory = ui.memory();
+    let scrolled = memory.id_data_temp.get_mut_or_default::<f32>(scroll_id);
+    *scrolled += delta;
+    let steps = (*scrolled / step_size).trunc();
+    *scrolled -= steps * step_size;
+    steps as i64
+}
@@ -300,6 +300,13 @@ fn show_menu_bar(ui: &mut Ui) {
             if ui.button("Organize windows").clicked() {
                 ui.ctx().memory().reset_areas();
             }
+            if ui
+                .button("Reset layout")
+                .on_hover_text("Put all windows back at their default positions and sizes")
+                .clicked()
+            {
+                ui.ctx().reset_window_layout();
+            }
             if ui
                 .button("Clear egui memory")
                 .on_hover_text("Forget scroll, collapsing headers etc")
@@ -1,4 +1,4 @@
-use egui::plot::{Curve, Plot, Value};
+use egui::plot::{Corner, Curve, Legend, Plot, Value};
 use egui::*;
 use std::f64::consts::TAU;
 
@@ -10,6 +10,8 @@ pub struct PlotDemo {
     circle_center: Pos2,
     square: bool,
     legend: bool,
+    legend_position: Corner,
+    legend_outside: bool,
     proportional: bool,
 }
 
@@ -22,6 +24,8 @@ impl Default for PlotDemo {
             circle_center: Pos2::new(0.0, 0.0),
             square: false,
             legend: true,
+            legend_position: Corner::LeftTop,
+            legend_outside: false,
             proportional: true,
         }
     }
@@ -57,6 +61,8 @@ impl PlotDemo {
             circle_center,
             square,
             legend,
+            legend_position,
+            legend_outside,
             proportional,
         } = self;
 
@@ -95,6 +101,19 @@ impl PlotDemo {
             });
         });
 
+        ui.horizontal(|ui| {
+            ui.set_enabled(*legend);
+            egui::ComboBox::from_label("Legend position")
+                .selected_text(format!("{:?}", legend_position))
+                .show_ui(ui, |ui| {
+                    for position in Corner::all() {
+                        let text = format!("{:?}", position);
+                        ui.selectable_value(legend_position, position, text);
+                    }
+                });
+            ui.checkbox(legend_outside, "outside");
+        });
+
         ui.label("Pan by dragging, or scroll (+ shift = horizontal).");
         if cfg!(target_arch = "wasm32") {
             ui.label("Zoom with ctrl / ⌘ + mouse wheel, or with pinch gesture.");
@@ -103,6 +122,7 @@ impl PlotDemo {
         } else {
             ui.label("Zoom with ctrl + scroll.");
         }
+        ui.label("Zoom to a region by dragging with the right mouse button.");
         ui.label("Reset view with double-click.");
     }
 
@@ -157,8 +177,16 @@ impl super::View for PlotDemo {
             .curve(self.circle())
             .curve(self.sin())
             .curve(self.thingy())
-            .show_legend(self.legend)
             .min_size(Vec2::new(200.0, 200.0));
+        if self.legend {
+            plot = plot.legend(
+                Legend::default()
+                    .position(self.legend_position)
+                    .outside(self.legend_outside),
+            );
+        } else {
+            plot = plot.show_legend(false);
+        }
         if self.square {
             plot = plot.view_aspect(1.0);
         }
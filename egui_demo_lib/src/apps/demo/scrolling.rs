@@ -78,11 +78,11 @@ impl super::View for Scrolling {
 
         let mut scroll_area = ScrollArea::from_max_height(200.0);
         if go_to_scroll_offset {
-            scroll_area = scroll_area.scroll_offset(self.offset);
+            scroll_area = scroll_area.scroll_offset(vec2(0.0, self.offset));
         }
 
         ui.separator();
-        let (current_scroll, max_scroll) = scroll_area.show(ui, |ui| {
+        let output = scroll_area.show(ui, |ui| {
             if scroll_top {
                 ui.scroll_to_cursor(Align::TOP);
             }
@@ -101,18 +101,13 @@ impl super::View for Scrolling {
             if scroll_bottom {
                 ui.scroll_to_cursor(Align::BOTTOM);
             }
-
-            let margin = ui.visuals().clip_rect_margin;
-
-            let current_scroll = ui.clip_rect().top() - ui.min_rect().top() + margin;
-            let max_scroll = ui.min_rect().height() - ui.clip_rect().height() + 2.0 * margin;
-            (current_scroll, max_scroll)
         });
         ui.separator();
 
+        let max_scroll = (output.content_size.y - output.inner_rect.height()).at_least(0.0);
         ui.label(format!(
             "Scroll offset: {:.0}/{:.0} px",
-            current_scroll, max_scroll
+            output.offset.y, max_scroll
         ));
 
         ui.separator();
@@ -204,7 +204,7 @@ impl WidgetGallery {
     }
 }
 
-fn example_plot() -> egui::plot::Plot {
+fn example_plot() -> egui::plot::Plot<'static> {
     let n = 128;
     let curve = egui::plot::Curve::from_values_iter((0..=n).map(|i| {
         use std::f64::consts::TAU;
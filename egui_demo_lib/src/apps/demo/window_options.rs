@@ -5,6 +5,11 @@ pub struct WindowOptions {
     title_bar: bool,
     closable: bool,
     collapsible: bool,
+    minimize_button: bool,
+    maximize_button: bool,
+    modal: bool,
+    snap: bool,
+    opacity: f32,
     resizable: bool,
     scroll: bool,
     disabled_time: f64,
@@ -21,6 +26,11 @@ impl Default for WindowOptions {
             title_bar: true,
             closable: true,
             collapsible: true,
+            minimize_button: false,
+            maximize_button: false,
+            modal: false,
+            snap: false,
+            opacity: 1.0,
             resizable: true,
             scroll: false,
             disabled_time: f64::NEG_INFINITY,
@@ -42,6 +52,11 @@ impl super::Demo for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            minimize_button,
+            maximize_button,
+            modal,
+            snap,
+            opacity,
             resizable,
             scroll,
             disabled_time,
@@ -60,6 +75,11 @@ impl super::Demo for WindowOptions {
             .id(egui::Id::new("demo_window_options")) // required since we change the title
             .resizable(resizable)
             .collapsible(collapsible)
+            .minimize_button(minimize_button)
+            .maximize_button(maximize_button)
+            .modal(modal)
+            .snap_distance(if snap { 12.0 } else { 0.0 })
+            .opacity(opacity)
             .title_bar(title_bar)
             .scroll(scroll)
             .enabled(enabled);
@@ -80,6 +100,11 @@ impl super::View for WindowOptions {
             title_bar,
             closable,
             collapsible,
+            minimize_button,
+            maximize_button,
+            modal,
+            snap,
+            opacity,
             resizable,
             scroll,
             disabled_time,
@@ -95,6 +120,11 @@ impl super::View for WindowOptions {
         ui.checkbox(title_bar, "title_bar");
         ui.checkbox(closable, "closable");
         ui.checkbox(collapsible, "collapsible");
+        ui.checkbox(minimize_button, "minimize_button");
+        ui.checkbox(maximize_button, "maximize_button");
+        ui.checkbox(modal, "modal");
+        ui.checkbox(snap, "snap to edges");
+        ui.add(egui::Slider::new(opacity, 0.0..=1.0).text("opacity"));
         ui.checkbox(resizable, "resizable");
         ui.checkbox(scroll, "scroll");
 
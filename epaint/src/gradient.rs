@@ -0,0 +1,109 @@
+use super::*;
+use emath::{NumExt, Pos2, Rect, Vec2};
+
+/// A linear color gradient, used to fill a rounded rectangle.
+///
+/// The colors are interpolated in linear space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "persistence", derive(serde::Deserialize, serde::Serialize))]
+pub struct Gradient {
+    /// The color at the start of the gradient.
+    pub from: Color32,
+
+    /// The color at the end of the gradient.
+    pub to: Color32,
+
+    /// The direction the gradient goes in, from `from` to `to`.
+    pub direction: Vec2,
+}
+
+impl Gradient {
+    /// From `top` to `bottom`.
+    pub fn vertical(top: Color32, bottom: Color32) -> Self {
+        Self {
+            from: top,
+            to: bottom,
+            direction: Vec2::DOWN,
+        }
+    }
+
+    /// From `left` to `right`.
+    pub fn horizontal(left: Color32, right: Color32) -> Self {
+        Self {
+            from: left,
+            to: right,
+            direction: Vec2::RIGHT,
+        }
+    }
+
+    /// A gradient at the given angle, in radians.
+    /// An angle of zero goes left to right, and `TAU / 4` goes top to bottom.
+    pub fn linear(angle: f32, from: Color32, to: Color32) -> Self {
+        Self {
+            from,
+            to,
+            direction: Vec2::angled(angle),
+        }
+    }
+
+    /// The color of the gradient at `pos`, when spanning the whole of `rect`.
+    pub fn color_at(&self, rect: Rect, pos: Pos2) -> Color32 {
+        let dir = self.direction.normalized();
+        let project = |p: Pos2| p.x * dir.x + p.y * dir.y;
+        let corners = [
+            rect.left_top(),
+            rect.right_top(),
+            rect.left_bottom(),
+            rect.right_bottom(),
+        ];
+        let min = corners
+            .iter()
+            .map(|&p| project(p))
+            .fold(f32::INFINITY, f32::min);
+        let max = corners
+            .iter()
+            .map(|&p| project(p))
+            .fold(f32::NEG_INFINITY, f32::max);
+        let t = if max > min {
+            ((project(pos) - min) / (max - min))
+                .at_least(0.0)
+                .at_most(1.0)
+        } else {
+            0.0
+        };
+        let color = Rgba::from(self.from) * (1.0 - t) + Rgba::from(self.to) * t;
+        color.into()
+    }
+
+    pub fn multiply_with_opacity(self, opacity: f32) -> Self {
+        Self {
+            from: self.from.linear_multiply(opacity),
+            to: self.to.linear_multiply(opacity),
+            ..self
+        }
+    }
+
+    /// A rounded rectangle filled with this gradient.
+    pub fn tessellate(&self, rect: Rect, corner_radius: f32) -> Mesh {
+        use crate::tessellator::*;
+        let paint_rect = PaintRect {
+            rect,
+            corner_radius,
+            fill: Color32::WHITE,
+            stroke: Default::default(),
+        };
+        let mut tessellator = Tessellator::from_options(TessellationOptions {
+            anti_alias: true,
+            ..Default::default()
+        });
+        let mut mesh = Mesh::default();
+        tessellator.tessellate_rect(&paint_rect, &mut mesh);
+
+        // The anti-aliasing fades the white towards transparent, so keep that coverage:
+        for vertex in &mut mesh.vertices {
+            let coverage = vertex.color.a() as f32 / 255.0;
+            vertex.color = self.color_at(rect, vertex.pos).linear_multiply(coverage);
+        }
+        mesh
+    }
+}
@@ -78,6 +78,7 @@
 #![allow(clippy::manual_range_contains)]
 
 pub mod color;
+mod gradient;
 mod mesh;
 pub mod mutex;
 mod shadow;
@@ -91,6 +92,7 @@ mod texture_atlas;
 
 pub use {
     color::{Color32, Rgba},
+    gradient::Gradient,
     mesh::{Mesh, Mesh16, Vertex},
     shadow::Shadow,
     shape::Shape,
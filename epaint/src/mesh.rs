@@ -232,6 +232,13 @@ impl Mesh {
             v.pos += delta;
         }
     }
+
+    /// Rotate by some angle around an origin, in-place
+    pub fn rotate(&mut self, rot: Rot2, origin: Pos2) {
+        for v in &mut self.vertices {
+            v.pos = origin + rot * (v.pos - origin);
+        }
+    }
 }
 
 // ----------------------------------------------------------------------------
@@ -11,6 +11,11 @@ pub struct Shadow {
 
     /// Color of the opaque center of the shadow.
     pub color: Color32,
+
+    /// How far the shadow is moved from the rect, e.g. down and to the right
+    /// to make the rect look lifted.
+    #[cfg_attr(feature = "persistence", serde(default))]
+    pub offset: emath::Vec2,
 }
 
 impl Shadow {
@@ -19,6 +24,7 @@ impl Shadow {
         Self {
             extrusion: 8.0,
             color: Color32::from_black_alpha(64),
+            offset: emath::Vec2::ZERO,
         }
     }
 
@@ -27,6 +33,7 @@ impl Shadow {
         Self {
             extrusion: 32.0,
             color: Color32::from_black_alpha(96),
+            offset: emath::Vec2::ZERO,
         }
     }
 
@@ -35,17 +42,28 @@ impl Shadow {
         Self {
             extrusion: 32.0,
             color: Color32::from_black_alpha(40),
+            offset: emath::Vec2::ZERO,
         }
     }
 
+    /// Move the shadow by `offset`.
+    pub fn with_offset(mut self, offset: emath::Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
     pub fn tessellate(&self, rect: emath::Rect, corner_radius: f32) -> Mesh {
         // tessellator.clip_rect = clip_rect; // TODO: culling
 
-        let Self { extrusion, color } = *self;
+        let Self {
+            extrusion,
+            color,
+            offset,
+        } = *self;
 
         use crate::tessellator::*;
         let rect = PaintRect {
-            rect: rect.expand(0.5 * extrusion),
+            rect: rect.translate(offset).expand(0.5 * extrusion),
             corner_radius: corner_radius + 0.5 * extrusion,
             fill: color,
             stroke: Default::default(),
@@ -215,4 +215,35 @@ impl Shape {
             }
         }
     }
+
+    /// Make the shape more transparent, in-place.
+    ///
+    /// `opacity` should be in the range [0, 1], where `0.0` is fully transparent.
+    pub fn multiply_with_opacity(&mut self, opacity: f32) {
+        match self {
+            Shape::Noop => {}
+            Shape::Vec(shapes) => {
+                for shape in shapes {
+                    shape.multiply_with_opacity(opacity);
+                }
+            }
+            Shape::Circle { fill, stroke, .. }
+            | Shape::Path { fill, stroke, .. }
+            | Shape::Rect { fill, stroke, .. } => {
+                *fill = fill.linear_multiply(opacity);
+                stroke.color = stroke.color.linear_multiply(opacity);
+            }
+            Shape::LineSegment { stroke, .. } => {
+                stroke.color = stroke.color.linear_multiply(opacity);
+            }
+            Shape::Text { color, .. } => {
+                *color = color.linear_multiply(opacity);
+            }
+            Shape::Mesh(mesh) => {
+                for vertex in &mut mesh.vertices {
+                    vertex.color = vertex.color.linear_multiply(opacity);
+                }
+            }
+        }
+    }
 }